@@ -0,0 +1,148 @@
+//! Allocation throughput of `FastArena` versus `ShardedArena` as writer
+//! threads are added, and of `FastArena`'s packed readiness bits versus
+//! one flag per slot.
+//!
+//! ```sh
+//! cargo run --release --example scaling
+//! ```
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use fast_bump::{FastArena, ShardedArena};
+
+const PER_THREAD: usize = 1 << 18;
+
+fn time(threads: usize, alloc: impl Fn(u64) + Sync) -> Duration {
+    let start = Instant::now();
+    std::thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| (0..PER_THREAD as u64).for_each(&alloc));
+        }
+    });
+    start.elapsed()
+}
+
+/// Per-slot readiness flags, as set and tested by the publication protocol.
+trait ReadyFlags: Sync {
+    fn with_capacity(cap: usize) -> Self;
+    fn set(&self, slot: usize);
+    fn is_set(&self, slot: usize) -> bool;
+}
+
+/// One bit per slot, 64 slots to a word: `FastArena`'s layout.
+struct Bits(Box<[AtomicU64]>);
+
+impl ReadyFlags for Bits {
+    fn with_capacity(cap: usize) -> Self {
+        Self((0..cap.div_ceil(64)).map(|_| AtomicU64::new(0)).collect())
+    }
+
+    fn set(&self, slot: usize) {
+        self.0[slot / 64].fetch_or(1 << (slot % 64), Ordering::Release);
+    }
+
+    fn is_set(&self, slot: usize) -> bool {
+        self.0[slot / 64].load(Ordering::Acquire) & (1 << (slot % 64)) != 0
+    }
+}
+
+/// One `AtomicBool` per slot: the layout before the bitset.
+struct Bools(Box<[AtomicBool]>);
+
+impl ReadyFlags for Bools {
+    fn with_capacity(cap: usize) -> Self {
+        Self((0..cap).map(|_| AtomicBool::new(false)).collect())
+    }
+
+    fn set(&self, slot: usize) {
+        self.0[slot].store(true, Ordering::Release);
+    }
+
+    fn is_set(&self, slot: usize) -> bool {
+        self.0[slot].load(Ordering::Acquire)
+    }
+}
+
+/// `FastArena`'s reserve, mark ready, advance `published` sequence,
+/// without the values, over either flag layout.
+struct Publication<F> {
+    cursor: AtomicUsize,
+    published: AtomicUsize,
+    flags: F,
+}
+
+impl<F: ReadyFlags> Publication<F> {
+    fn with_capacity(cap: usize) -> Self {
+        Self {
+            cursor: AtomicUsize::new(0),
+            published: AtomicUsize::new(0),
+            flags: F::with_capacity(cap),
+        }
+    }
+
+    fn alloc(&self) {
+        let slot = self.cursor.fetch_add(1, Ordering::Relaxed);
+        self.flags.set(slot);
+        loop {
+            let p = self.published.load(Ordering::Acquire);
+            if p > slot {
+                break;
+            }
+            if !self.flags.is_set(p) {
+                std::thread::yield_now();
+                continue;
+            }
+            let _ = self.published.compare_exchange_weak(
+                p,
+                p + 1,
+                Ordering::Release,
+                Ordering::Relaxed,
+            );
+        }
+    }
+}
+
+fn main() {
+    let max = std::thread::available_parallelism()
+        .map_or(4, usize::from)
+        .max(4);
+    println!("threads  FastArena  ShardedArena  (ns per alloc)");
+    let mut threads = 1;
+    while threads <= max {
+        let total = threads * PER_THREAD;
+        let fast = FastArena::with_capacity(total);
+        let fast_time = time(threads, |v| {
+            fast.alloc(v);
+        });
+        let sharded = ShardedArena::new(threads, PER_THREAD);
+        let sharded_time = time(threads, |v| {
+            sharded.alloc(v);
+        });
+        let per = |d: Duration| d.as_nanos() / total as u128;
+        println!(
+            "{threads:>7}  {:>9}  {:>12}",
+            per(fast_time),
+            per(sharded_time)
+        );
+        threads *= 2;
+    }
+
+    println!();
+    println!("threads  bitset  per-slot flags  (ns per alloc)");
+    let mut threads = 1;
+    while threads <= max {
+        let total = threads * PER_THREAD;
+        let bits = Publication::<Bits>::with_capacity(total);
+        let bits_time = time(threads, |_| bits.alloc());
+        let bools = Publication::<Bools>::with_capacity(total);
+        let bools_time = time(threads, |_| bools.alloc());
+        let per = |d: Duration| d.as_nanos() / total as u128;
+        println!(
+            "{threads:>7}  {:>6}  {:>14}",
+            per(bits_time),
+            per(bools_time)
+        );
+        threads *= 2;
+    }
+}
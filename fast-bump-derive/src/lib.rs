@@ -0,0 +1,110 @@
+//! Derive macros for [`fast-bump`](https://docs.rs/fast-bump).
+//!
+//! Use through the `derive` feature of `fast-bump`, which re-exports them.
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, parse_macro_input};
+
+/// Derives `fast_bump::Soa`, storing each field of the struct in its own
+/// column inside a `SoaArena`.
+///
+/// Generates `<Name>Columns` with one accessor per field returning the
+/// column as a slice (`xs()` for a field `xs`) and a `_mut` variant.
+/// Only structs with named fields are supported.
+#[proc_macro_derive(Soa)]
+pub fn derive_soa(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "Soa can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "Soa requires a struct with named fields",
+        ));
+    };
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let columns = format_ident!("{name}Columns");
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let idents: Vec<_> = fields
+        .named
+        .iter()
+        .filter_map(|f| f.ident.as_ref())
+        .collect();
+    let types: Vec<_> = fields.named.iter().map(|f| &f.ty).collect();
+    let mut_idents: Vec<_> = idents.iter().map(|i| format_ident!("{i}_mut")).collect();
+    let struct_doc = format!("Column storage of [`{name}`], generated by `#[derive(Soa)]`.");
+    let getter_docs: Vec<_> = idents
+        .iter()
+        .map(|i| format!("Returns the `{i}` column."))
+        .collect();
+    let mut_docs: Vec<_> = idents
+        .iter()
+        .map(|i| format!("Returns the `{i}` column mutably."))
+        .collect();
+
+    Ok(quote! {
+        #[doc = #struct_doc]
+        #vis struct #columns #impl_generics #where_clause {
+            #(#idents: ::std::vec::Vec<#types>,)*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::std::default::Default for #columns #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#idents: ::std::vec::Vec::new(),)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #columns #ty_generics #where_clause {
+            #(
+                #[doc = #getter_docs]
+                #[must_use]
+                #vis fn #idents(&self) -> &[#types] {
+                    &self.#idents
+                }
+
+                #[doc = #mut_docs]
+                #[must_use]
+                #vis fn #mut_idents(&mut self) -> &mut [#types] {
+                    &mut self.#idents
+                }
+            )*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::fast_bump::Soa for #name #ty_generics #where_clause {
+            type Columns = #columns #ty_generics;
+
+            fn push(columns: &mut Self::Columns, value: Self) {
+                let Self { #(#idents),* } = value;
+                #(columns.#idents.push(#idents);)*
+            }
+
+            fn truncate(columns: &mut Self::Columns, len: usize) {
+                #(columns.#idents.truncate(len);)*
+            }
+
+            fn reserve(columns: &mut Self::Columns, additional: usize) {
+                #(columns.#idents.reserve(additional);)*
+            }
+        }
+    })
+}
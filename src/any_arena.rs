@@ -0,0 +1,190 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use crate::{Arena, Checkpoint, Error, Idx};
+
+/// Type-erased view of one inner [`Arena<T>`].
+trait ErasedArena: Any {
+    fn len(&self) -> usize;
+    fn rollback_to(&mut self, len: usize);
+    fn reset(&mut self);
+}
+
+impl<T: 'static> ErasedArena for Arena<T> {
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    fn rollback_to(&mut self, len: usize) {
+        self.rollback(Checkpoint::from_len(len));
+    }
+
+    fn reset(&mut self) {
+        Self::reset(self);
+    }
+}
+
+/// Heterogeneous arena holding one [`Arena<T>`] per type.
+///
+/// The inner arena for `T` is created on the first `alloc::<T>`, so a
+/// compiler pass can take a single `&mut AnyArena` instead of one arena
+/// per node type. [`checkpoint`](AnyArena::checkpoint),
+/// [`rollback`](AnyArena::rollback) and [`reset`](AnyArena::reset) cover
+/// all inner arenas at once.
+///
+/// ```
+/// use fast_bump::AnyArena;
+///
+/// let mut any = AnyArena::new();
+/// let n = any.alloc(42u32);
+/// let s = any.alloc("name");
+/// assert_eq!(*any.get(n), 42);
+/// assert_eq!(*any.get(s), "name");
+/// ```
+#[derive(Default)]
+pub struct AnyArena {
+    arenas: HashMap<TypeId, Box<dyn ErasedArena>>,
+}
+
+/// Saved allocation state of every inner arena of an [`AnyArena`].
+///
+/// Created by [`AnyArena::checkpoint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnyCheckpoint {
+    lens: HashMap<TypeId, usize>,
+}
+
+impl AnyArena {
+    /// Creates an empty arena.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocates a value in the arena for `T`, creating it if needed.
+    pub fn alloc<T: 'static>(&mut self, value: T) -> Idx<T> {
+        self.arena_mut::<T>().alloc(value)
+    }
+
+    /// Returns a reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds, including when nothing of type `T`
+    /// was allocated.
+    #[must_use]
+    pub fn get<T: 'static>(&self, idx: Idx<T>) -> &T {
+        self.try_get(idx).unwrap_or_else(|| {
+            panic!(
+                "index {} out of bounds for {}",
+                idx.into_raw(),
+                std::any::type_name::<T>()
+            )
+        })
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if the index
+    /// is out of bounds.
+    #[must_use]
+    pub fn try_get<T: 'static>(&self, idx: Idx<T>) -> Option<&T> {
+        self.arena::<T>()?.try_get(idx)
+    }
+
+    /// Returns a mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds, including when nothing of type `T`
+    /// was allocated.
+    #[must_use]
+    pub fn get_mut<T: 'static>(&mut self, idx: Idx<T>) -> &mut T {
+        self.try_get_mut(idx).unwrap_or_else(|| {
+            panic!(
+                "index {} out of bounds for {}",
+                idx.into_raw(),
+                std::any::type_name::<T>()
+            )
+        })
+    }
+
+    /// Returns a mutable reference to the value at `idx`, or `None` if the
+    /// index is out of bounds.
+    #[must_use]
+    pub fn try_get_mut<T: 'static>(&mut self, idx: Idx<T>) -> Option<&mut T> {
+        let arena = self.arenas.get_mut(&TypeId::of::<T>())?;
+        (arena.as_mut() as &mut dyn Any)
+            .downcast_mut::<Arena<T>>()?
+            .try_get_mut(idx)
+    }
+
+    /// Returns the inner arena for `T`, if anything of that type was
+    /// allocated.
+    #[must_use]
+    pub fn arena<T: 'static>(&self) -> Option<&Arena<T>> {
+        let arena = self.arenas.get(&TypeId::of::<T>())?;
+        (arena.as_ref() as &dyn Any).downcast_ref()
+    }
+
+    /// Returns the inner arena for `T`, creating it if needed.
+    #[allow(clippy::missing_panics_doc)] // the downcast cannot fail
+    pub fn arena_mut<T: 'static>(&mut self) -> &mut Arena<T> {
+        let arena = self
+            .arenas
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(Arena::<T>::new()));
+        (arena.as_mut() as &mut dyn Any)
+            .downcast_mut()
+            .expect("inner arena stored under its own TypeId")
+    }
+
+    /// Returns the number of values across all inner arenas.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.arenas.values().map(|arena| arena.len()).sum()
+    }
+
+    /// Returns `true` if no inner arena holds a value.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.arenas.values().all(|arena| arena.len() == 0)
+    }
+
+    /// Captures the length of every inner arena.
+    #[must_use]
+    pub fn checkpoint(&self) -> AnyCheckpoint {
+        AnyCheckpoint {
+            lens: self
+                .arenas
+                .iter()
+                .map(|(&ty, arena)| (ty, arena.len()))
+                .collect(),
+        }
+    }
+
+    /// Rolls every inner arena back to `cp`, dropping values allocated after
+    /// it. Arenas created after the checkpoint are emptied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an inner arena is shorter than recorded in `cp`; no arena
+    /// is rolled back then.
+    pub fn rollback(&mut self, cp: &AnyCheckpoint) {
+        for (ty, &checkpoint) in &cp.lens {
+            let len = self.arenas.get(ty).map_or(0, |arena| arena.len());
+            if checkpoint > len {
+                let err = Error::CheckpointBeyondLen { checkpoint, len };
+                panic!("{err}");
+            }
+        }
+        for (ty, arena) in &mut self.arenas {
+            arena.rollback_to(cp.lens.get(ty).copied().unwrap_or(0));
+        }
+    }
+
+    /// Removes all values from every inner arena, keeping their memory.
+    pub fn reset(&mut self) {
+        for arena in self.arenas.values_mut() {
+            arena.reset();
+        }
+    }
+}
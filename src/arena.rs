@@ -1,4 +1,15 @@
-use crate::{Checkpoint, Idx, IterIndexed, IterIndexedMut};
+use std::hash::{BuildHasher, Hash};
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr::NonNull;
+
+use crate::items::Items;
+use crate::stats::StatsCounters;
+use crate::trace;
+use crate::{
+    ArenaAllocError, ArenaLimit, ArenaObserver, ArenaStats, Checkpoint, DrainIndexed, DropOrder,
+    Error, FastArena, FrozenArena, GrowthPolicy, Idx, IdxIter, IdxRange, IdxRemap, IterIndexed,
+    IterIndexedMut, UninitSlot, ValueIndex,
+};
 
 /// Single-thread typed arena allocator.
 ///
@@ -8,33 +19,205 @@ use crate::{Checkpoint, Idx, IterIndexed, IterIndexedMut};
 ///
 /// For thread-safe concurrent allocation, see [`SharedArena`](crate::SharedArena).
 pub struct Arena<T> {
-    items: Vec<T>,
+    items: Items<T>,
+    /// Bumped whenever existing items are removed, reordered or borrowed
+    /// mutably, so a [`ValueIndex`] can tell that its hashes are stale.
+    edits: usize,
+    stats: StatsCounters,
+    observer: Option<Box<dyn ArenaObserver>>,
+    limit: Option<ArenaLimit>,
+    /// `limit` in values, or `usize::MAX` without a limit.
+    max_items: usize,
+    growth: GrowthPolicy,
 }
 
 impl<T> Arena<T> {
     /// Creates an empty arena.
+    ///
+    /// Being `const`, it can initialize a `static`; see [`StaticArena`]
+    /// for a global arena that can also be allocated into.
+    ///
+    /// [`StaticArena`]: crate::StaticArena
     #[must_use]
     pub const fn new() -> Self {
-        Self { items: Vec::new() }
+        Self {
+            items: Items::from_vec(Vec::new()),
+            edits: 0,
+            stats: StatsCounters::new(),
+            observer: None,
+            limit: None,
+            max_items: usize::MAX,
+            growth: GrowthPolicy::Double,
+        }
     }
 
     /// Creates an arena with pre-allocated capacity for `capacity` items.
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            items: Vec::with_capacity(capacity),
+            items: Items::from_vec(Vec::with_capacity(capacity)),
+            edits: 0,
+            stats: StatsCounters::new(),
+            observer: None,
+            limit: None,
+            max_items: usize::MAX,
+            growth: GrowthPolicy::Double,
+        }
+    }
+
+    /// Creates an arena with pre-allocated capacity for `capacity` items,
+    /// returning an error instead of aborting if the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaAllocError`] if `capacity` items overflow the address
+    /// space or the allocator fails.
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, ArenaAllocError> {
+        let mut arena = Self::new();
+        arena
+            .items
+            .try_reserve_exact(capacity)
+            .map_err(|_| ArenaAllocError::for_capacity::<T>(capacity))?;
+        Ok(arena)
+    }
+
+    /// Creates an empty arena that refuses to grow past `limit`.
+    ///
+    /// [`try_alloc`](Arena::try_alloc) returns [`Error::Limit`] once the
+    /// budget is used up, and [`alloc`](Arena::alloc) panics.
+    #[must_use]
+    pub const fn with_limit(limit: ArenaLimit) -> Self {
+        Self {
+            items: Items::from_vec(Vec::new()),
+            edits: 0,
+            stats: StatsCounters::new(),
+            observer: None,
+            limit: Some(limit),
+            max_items: limit.max_items::<T>(),
+            growth: GrowthPolicy::Double,
         }
     }
 
+    /// Creates an arena that zeroes slot memory once it is freed.
+    ///
+    /// Memory is scrubbed with non-elidable writes after destructors run on
+    /// [`rollback`](Arena::rollback), [`reset`](Arena::reset), and drop, and
+    /// whenever the storage is reallocated. The iterators returned by
+    /// [`drain`](Arena::drain) and `into_iter` zero the storage when
+    /// dropped. [`into_vec`](Arena::into_vec), [`take_items`](Arena::take_items)
+    /// and [`freeze`](Arena::freeze) move the items to fresh storage and
+    /// zero the arena's; the values they hand out are no longer scrubbed.
+    ///
+    /// Requires the `zeroize` feature.
+    #[cfg(feature = "zeroize")]
+    #[must_use]
+    pub fn with_capacity_zeroizing(capacity: usize) -> Self {
+        let mut arena = Self::with_capacity(capacity);
+        arena.items.set_zeroize(true);
+        arena
+    }
+
+    /// Returns the budget set with [`with_limit`](Arena::with_limit).
+    #[must_use]
+    pub const fn limit(&self) -> Option<ArenaLimit> {
+        self.limit
+    }
+
     /// Allocates a value in the arena, returning its stable index.
     ///
     /// O(1) amortized (backed by [`Vec::push`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena's [limit](Arena::with_limit) is reached.
     pub fn alloc(&mut self, value: T) -> Idx<T> {
         let index = self.items.len();
+        if index >= self.max_items {
+            self.limit_exceeded();
+        }
+        let capacity = self.items.capacity();
+        self.make_room(1);
         self.items.push(value);
+        self.note_alloc(index, 1, capacity);
         Idx::from_raw(index)
     }
 
+    /// Allocates a value and returns its index together with a mutable
+    /// reference to it, for initializing or linking the new item without a
+    /// second lookup.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena's [limit](Arena::with_limit) is reached.
+    pub fn alloc_mut(&mut self, value: T) -> (Idx<T>, &mut T) {
+        let idx = self.alloc(value);
+        let value = self.items.last_mut().expect("value was just pushed");
+        (idx, value)
+    }
+
+    /// Allocates a value unless the arena's [limit](Arena::with_limit) is
+    /// reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Limit`] if the arena already holds as many values as
+    /// its limit allows. `value` is dropped.
+    pub fn try_alloc(&mut self, value: T) -> Result<Idx<T>, Error> {
+        if self.items.len() >= self.max_items {
+            return Err(self.limit_error());
+        }
+        Ok(self.alloc(value))
+    }
+
+    /// Allocates the value returned by `f`, or returns its error with the
+    /// arena unchanged.
+    ///
+    /// The slot is reserved before `f` runs, so a value that fits lands in
+    /// place without a temporary at the call site.
+    ///
+    /// ```
+    /// use fast_bump::Arena;
+    ///
+    /// let mut arena = Arena::new();
+    /// let n = arena.alloc_try(|| "42".parse::<u32>()).unwrap();
+    /// assert!(arena.alloc_try(|| "x".parse::<u32>()).is_err());
+    /// assert_eq!(arena.as_slice(), [42]);
+    /// assert_eq!(arena[n], 42);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena's [limit](Arena::with_limit) is reached, before
+    /// `f` runs.
+    pub fn alloc_try<E>(&mut self, f: impl FnOnce() -> Result<T, E>) -> Result<Idx<T>, E> {
+        let slot = self.alloc_uninit();
+        Ok(slot.write(f()?))
+    }
+
+    /// Reserves an uninitialized slot at the end of the arena.
+    ///
+    /// Fill it through [`UninitSlot::as_uninit`] (e.g. from a deserializer
+    /// or an FFI call) and commit it with [`UninitSlot::assume_init`]. The
+    /// arena is unchanged if the slot is dropped without being committed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena's [limit](Arena::with_limit) is reached.
+    pub fn alloc_uninit(&mut self) -> UninitSlot<'_, T> {
+        if self.items.len() >= self.max_items {
+            self.limit_exceeded();
+        }
+        let capacity = self.items.capacity();
+        self.make_room(1);
+        self.items.reserve(1);
+        self.note_growth(capacity);
+        UninitSlot::new(&mut self.items, self.observer.as_deref())
+    }
+
     /// Returns a reference to the value at `idx`.
     ///
     /// # Panics
@@ -52,25 +235,41 @@ impl<T> Arena<T> {
     /// Panics if `idx` is out of bounds (stale after rollback/reset).
     #[must_use]
     pub fn get_mut(&mut self, idx: Idx<T>) -> &mut T {
-        &mut self.items[idx.into_raw()]
+        &mut self.edit()[idx.into_raw()]
     }
 
     /// Returns the number of allocated items.
     #[must_use]
     pub const fn len(&self) -> usize {
-        self.items.len()
+        self.items.vec().len()
     }
 
     /// Returns `true` if the arena contains no items.
     #[must_use]
     pub const fn is_empty(&self) -> bool {
-        self.items.is_empty()
+        self.items.vec().is_empty()
+    }
+
+    /// Returns the index the next [`alloc`](Arena::alloc) will return.
+    #[must_use]
+    pub const fn next_idx(&self) -> Idx<T> {
+        Idx::from_raw(self.items.vec().len())
+    }
+
+    /// Returns the index of the most recently allocated item, or `None`
+    /// if the arena is empty.
+    #[must_use]
+    pub const fn last_idx(&self) -> Option<Idx<T>> {
+        match self.items.vec().len() {
+            0 => None,
+            len => Some(Idx::from_raw(len - 1)),
+        }
     }
 
     /// Returns the current capacity in items.
     #[must_use]
     pub const fn capacity(&self) -> usize {
-        self.items.capacity()
+        self.items.vec().capacity()
     }
 
     /// Saves the current allocation state.
@@ -79,7 +278,7 @@ impl<T> Arena<T> {
     /// made after this point.
     #[must_use]
     pub const fn checkpoint(&self) -> Checkpoint<T> {
-        Checkpoint::from_len(self.items.len())
+        Checkpoint::from_len(self.items.vec().len())
     }
 
     /// Rolls back to a previous checkpoint, dropping all values
@@ -91,20 +290,169 @@ impl<T> Arena<T> {
     ///
     /// Panics if `cp` points beyond the current length.
     pub fn rollback(&mut self, cp: Checkpoint<T>) {
-        assert!(
-            cp.len() <= self.items.len(),
-            "checkpoint {} beyond current length {}",
-            cp.len(),
-            self.items.len(),
-        );
-        self.items.truncate(cp.len());
+        if let Err(err) = self.try_rollback(cp) {
+            panic!("{err}");
+        }
+    }
+
+    /// Rolls back to `cp` like [`rollback`](Arena::rollback), returning an
+    /// error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CheckpointBeyondLen`] if `cp` points beyond the
+    /// current length; the arena is left unchanged.
+    pub fn try_rollback(&mut self, cp: Checkpoint<T>) -> Result<(), Error> {
+        if cp.len() > self.items.len() {
+            return Err(Error::CheckpointBeyondLen {
+                checkpoint: cp.len(),
+                len: self.items.len(),
+            });
+        }
+        let old_len = self.items.len();
+        self.note_rollback(old_len, cp.len());
+        self.truncate_items(cp.len());
+        self.scrub_freed(old_len);
+        Ok(())
+    }
+
+    /// Reports a rollback from `old_len` to `len` to the stats, the
+    /// observer and tracing.
+    fn note_rollback(&mut self, old_len: usize, len: usize) {
+        self.stats.shrink(old_len, len);
+        self.stats.rollback(old_len, len);
+        if let Some(observer) = &self.observer {
+            observer.on_rollback(old_len, len);
+        }
+        trace::rollback::<T>(self.addr(), old_len, len);
+    }
+
+    /// Allocates a clone of the value at `idx` in `dest`, returning its
+    /// index there.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds or `dest`'s
+    /// [limit](Arena::with_limit) is reached.
+    pub fn transfer(&self, idx: Idx<T>, dest: &mut Self) -> Idx<T>
+    where
+        T: Clone,
+    {
+        dest.alloc(self[idx].clone())
+    }
+
+    /// Allocates clones of the values in `range` in `dest`, returning
+    /// their indices there.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or the values would exceed
+    /// `dest`'s [limit](Arena::with_limit); none of them are allocated
+    /// then.
+    pub fn transfer_range(&self, range: impl Into<IdxRange<T>>, dest: &mut Self) -> IdxRange<T>
+    where
+        T: Clone,
+    {
+        let values = self.slice(range);
+        dest.alloc_n(values.len(), |items, _| items.extend_from_slice(values))
+    }
+
+    /// Moves every value allocated since `cp` into `dest`, in order,
+    /// returning their indices there.
+    ///
+    /// Promotes the results of a pass from a scratch arena without cloning
+    /// them. For this arena it counts as a rollback to `cp`, except that
+    /// the values are moved instead of dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cp` points beyond the current length, or the values
+    /// would exceed `dest`'s [limit](Arena::with_limit); nothing is moved
+    /// then.
+    pub fn transfer_tail(&mut self, cp: Checkpoint<T>, dest: &mut Self) -> IdxRange<T> {
+        let (start, old_len) = (cp.len(), self.items.len());
+        if start > old_len {
+            let err = Error::CheckpointBeyondLen {
+                checkpoint: start,
+                len: old_len,
+            };
+            panic!("{err}");
+        }
+        let moved = dest.alloc_n(old_len - start, |items, _| {
+            items.extend(self.edit().drain(start..));
+        });
+        self.note_rollback(old_len, start);
+        self.scrub_freed(old_len);
+        moved
+    }
+
+    /// Rolls back to `cp` like [`rollback`](Arena::rollback), first passing
+    /// each value to be dropped to `f`, in allocation order.
+    ///
+    /// Lets the caller log, recycle resources, or detach external state
+    /// with access to its own context, which `Drop` does not have.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cp` points beyond the current length.
+    pub fn rollback_with(&mut self, cp: Checkpoint<T>, f: impl FnMut(&mut T)) {
+        if let Some(discarded) = self.items.get_mut(cp.len()..) {
+            discarded.iter_mut().for_each(f);
+        }
+        self.rollback(cp);
+    }
+
+    /// Removes all items like [`reset`](Arena::reset), first passing each
+    /// to `f`, in allocation order.
+    pub fn reset_with(&mut self, f: impl FnMut(&mut T)) {
+        self.items.iter_mut().for_each(f);
+        self.reset();
+    }
+
+    /// Shortens the arena to `len` items like [`rollback`](Arena::rollback),
+    /// for callers that kept a raw length instead of a [`Checkpoint`].
+    ///
+    /// No-op if `len` is not below the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.items.len() {
+            self.rollback(Checkpoint::from_len(len));
+        }
+    }
+
+    /// Drops the item at `idx` and every item allocated after it, as a
+    /// rollback to just before `idx` was allocated.
+    ///
+    /// No-op if `idx` is not [valid](Arena::is_valid).
+    #[doc(alias = "reset_from")]
+    pub fn truncate_to(&mut self, idx: Idx<T>) {
+        self.truncate(idx.into_raw());
     }
 
     /// Removes all items, running their destructors.
     ///
     /// Retains allocated memory for reuse.
     pub fn reset(&mut self) {
-        self.items.clear();
+        self.stats.reset();
+        self.stats.shrink(self.items.len(), 0);
+        if let Some(observer) = &self.observer {
+            observer.on_reset(self.items.len());
+        }
+        trace::reset::<T>(self.addr(), self.items.len(), self.items.capacity());
+        let old_len = self.items.len();
+        self.truncate_items(0);
+        self.scrub_freed(old_len);
+    }
+
+    /// Returns a contiguous slice of all allocated items.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns a mutable slice of all allocated items.
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.edit()
     }
 
     /// Returns an iterator over all allocated items.
@@ -114,7 +462,7 @@ impl<T> Arena<T> {
 
     /// Returns a mutable iterator over all allocated items.
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
-        self.items.iter_mut()
+        self.edit().iter_mut()
     }
 
     /// Allocates multiple values from an iterator, returning the index
@@ -123,9 +471,14 @@ impl<T> Arena<T> {
     /// Returns `None` if the iterator is empty.
     ///
     /// O(n) where n = items yielded by the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items would exceed the arena's
+    /// [limit](Arena::with_limit). None of them are kept.
     pub fn alloc_extend(&mut self, iter: impl IntoIterator<Item = T>) -> Option<Idx<T>> {
         let start = self.items.len();
-        self.items.extend(iter);
+        self.extend_tracked(iter);
         if self.items.len() > start {
             Some(Idx::from_raw(start))
         } else {
@@ -133,13 +486,178 @@ impl<T> Arena<T> {
         }
     }
 
+    /// Allocates values from an iterator of results like
+    /// [`alloc_extend`](Arena::alloc_extend), stopping at the first error.
+    ///
+    /// On error, the values allocated so far are rolled back, so the arena
+    /// is left as it was.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error yielded by the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items would exceed the arena's
+    /// [limit](Arena::with_limit). None of them are kept.
+    pub fn alloc_extend_try<E>(
+        &mut self,
+        iter: impl IntoIterator<Item = Result<T, E>>,
+    ) -> Result<Option<Idx<T>>, E> {
+        let cp = self.checkpoint();
+        let mut error = None;
+        let start = self.alloc_extend(
+            iter.into_iter()
+                .map_while(|item| item.map_err(|err| error = Some(err)).ok()),
+        );
+        if let Some(err) = error {
+            self.rollback(cp);
+            return Err(err);
+        }
+        Ok(start)
+    }
+
+    /// Allocates `n` clones of `value` in one go, through `Vec`'s fill
+    /// path instead of `n` separate [`alloc`](Arena::alloc) calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items would exceed the arena's
+    /// [limit](Arena::with_limit). None of them are allocated.
+    pub fn alloc_fill(&mut self, n: usize, value: T) -> IdxRange<T>
+    where
+        T: Clone,
+    {
+        self.alloc_n(n, |items, len| items.resize(len, value))
+    }
+
+    /// Allocates `n` default values in one go, like
+    /// [`alloc_fill`](Arena::alloc_fill).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items would exceed the arena's
+    /// [limit](Arena::with_limit). None of them are allocated.
+    pub fn alloc_default_n(&mut self, n: usize) -> IdxRange<T>
+    where
+        T: Default,
+    {
+        self.alloc_n(n, |items, len| items.resize_with(len, T::default))
+    }
+
+    /// Resizes the arena to `len` items, filling new slots with values
+    /// returned by `f`, or dropping the items past `len` like
+    /// [`rollback`](Arena::rollback).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds the arena's [limit](Arena::with_limit).
+    pub fn resize_with(&mut self, len: usize, f: impl FnMut() -> T) {
+        match len.checked_sub(self.items.len()) {
+            Some(n) => {
+                self.alloc_n(n, |items, len| items.resize_with(len, f));
+            }
+            None => self.rollback(Checkpoint::from_len(len)),
+        }
+    }
+
+    /// Appends `n` values with `fill`, which resizes the storage to the
+    /// length it is passed.
+    fn alloc_n(&mut self, n: usize, fill: impl FnOnce(&mut Vec<T>, usize)) -> IdxRange<T> {
+        let start = self.items.len();
+        if n > self.max_items - start {
+            self.limit_exceeded();
+        }
+        let capacity = self.items.capacity();
+        self.make_room(n);
+        fill(&mut self.items, start + n);
+        self.note_alloc(start, n, capacity);
+        IdxRange::from_raw(start, start + n)
+    }
+
+    /// Returns the items in `range`, e.g. `arena.slice(a..b)` for a block
+    /// of values allocated one after another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or `start > end`.
+    #[must_use]
+    pub fn slice(&self, range: impl Into<IdxRange<T>>) -> &[T] {
+        &self.items[range.into().into_raw()]
+    }
+
+    /// Returns the items in `range` mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or `start > end`.
+    #[must_use]
+    pub fn slice_mut(&mut self, range: impl Into<IdxRange<T>>) -> &mut [T] {
+        &mut self.edit()[range.into().into_raw()]
+    }
+
+    /// Splits the items at `idx` into the items before it and the items
+    /// from it on, both mutable.
+    ///
+    /// Lets a pass rewrite the values allocated after some point, such as
+    /// the [`next_idx`](Arena::next_idx) recorded before them, while
+    /// reading the older ones.
+    ///
+    /// ```
+    /// use fast_bump::Arena;
+    ///
+    /// let mut arena: Arena<i32> = (1..=3).collect();
+    /// let boundary = arena.next_idx();
+    /// arena.extend([10, 20]);
+    /// let (old, new) = arena.split_at_mut(boundary);
+    /// let base: i32 = old.iter().sum();
+    /// new.iter_mut().for_each(|v| *v += base);
+    /// assert_eq!(arena.as_slice(), [1, 2, 3, 16, 26]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is beyond the current length.
+    #[must_use]
+    pub fn split_at_mut(&mut self, idx: Idx<T>) -> (&mut [T], &mut [T]) {
+        self.edit().split_at_mut(idx.into_raw())
+    }
+
+    /// Returns the items in `range`, or `None` if it is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end`.
+    #[must_use]
+    pub fn try_slice(&self, range: impl Into<IdxRange<T>>) -> Option<&[T]> {
+        self.items.get(range.into().into_raw())
+    }
+
+    /// Returns the items from `start` up to but excluding `end`, or `None`
+    /// unless `start <= end <= len`.
+    ///
+    /// Never panics, for sequences stored as a pair of indices that may
+    /// come from untrusted or stale data.
+    #[must_use]
+    pub fn get_span(&self, start: Idx<T>, end: Idx<T>) -> Option<&[T]> {
+        self.items.get(start.into_raw()..end.into_raw())
+    }
+
     /// Returns `true` if `idx` points to a valid item in this arena.
     ///
     /// An index becomes invalid after [`rollback`](Arena::rollback) or
     /// [`reset`](Arena::reset) removes the item it pointed to.
     #[must_use]
     pub const fn is_valid(&self, idx: Idx<T>) -> bool {
-        idx.into_raw() < self.items.len()
+        idx.into_raw() < self.items.vec().len()
+    }
+
+    /// Returns `true` if `idx` refers to an item in this arena; the same
+    /// check as [`is_valid`](Arena::is_valid), for indices computed with
+    /// [`Idx::checked_add`].
+    #[must_use]
+    pub const fn contains_idx(&self, idx: Idx<T>) -> bool {
+        self.is_valid(idx)
     }
 
     /// Returns a reference to the value at `idx`, or `None` if the
@@ -153,16 +671,97 @@ impl<T> Arena<T> {
     /// if the index is out of bounds.
     #[must_use]
     pub fn try_get_mut(&mut self, idx: Idx<T>) -> Option<&mut T> {
-        self.items.get_mut(idx.into_raw())
+        self.edit().get_mut(idx.into_raw())
+    }
+
+    /// Returns a reference to the value at `idx`, or an error describing
+    /// why it cannot be resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::StaleIndex`] if `idx` is out of bounds.
+    pub fn try_index(&self, idx: Idx<T>) -> Result<&T, Error> {
+        let (index, len) = (idx.into_raw(), self.items.len());
+        self.items
+            .get(idx.into_raw())
+            .ok_or(Error::StaleIndex { index, len })
+    }
+
+    /// Returns a mutable reference to the value at `idx`, or an error
+    /// describing why it cannot be resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::StaleIndex`] if `idx` is out of bounds.
+    pub fn try_index_mut(&mut self, idx: Idx<T>) -> Result<&mut T, Error> {
+        let (index, len) = (idx.into_raw(), self.items.len());
+        self.edit()
+            .get_mut(idx.into_raw())
+            .ok_or(Error::StaleIndex { index, len })
+    }
+
+    /// Returns a reference to the value at `idx` without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `idx` must be in bounds: `idx.into_raw() < self.len()`. An index
+    /// made stale by rollback or reset is undefined behavior.
+    #[must_use]
+    pub unsafe fn get_unchecked(&self, idx: Idx<T>) -> &T {
+        // SAFETY: in bounds per the caller's contract.
+        unsafe { self.items.get_unchecked(idx.into_raw()) }
+    }
+
+    /// Returns a mutable reference to the value at `idx` without bounds
+    /// checking.
+    ///
+    /// # Safety
+    ///
+    /// `idx` must be in bounds: `idx.into_raw() < self.len()`.
+    #[must_use]
+    pub unsafe fn get_unchecked_mut(&mut self, idx: Idx<T>) -> &mut T {
+        // SAFETY: in bounds per the caller's contract.
+        unsafe { self.edit().get_unchecked_mut(idx.into_raw()) }
     }
 
     /// Removes all items, returning an iterator that yields them
     /// in allocation order.
     ///
     /// The arena is empty after the iterator is consumed or dropped.
-    /// Capacity is retained.
-    pub fn drain(&mut self) -> std::vec::Drain<'_, T> {
-        self.items.drain(..)
+    /// Capacity is retained; a zeroizing arena zeroes it when the iterator
+    /// is dropped.
+    pub fn drain(&mut self) -> ArenaDrain<'_, T> {
+        self.stats.shrink(self.items.len(), 0);
+        let scrub = self.scrub_on_drop();
+        ArenaDrain {
+            inner: ManuallyDrop::new(self.edit().drain(..)),
+            scrub,
+        }
+    }
+
+    /// Takes the items out as a `Vec<T>` without copying, leaving the arena
+    /// empty with no capacity.
+    ///
+    /// Unlike [`drain`](Arena::drain), the storage leaves with the items,
+    /// so it can be handed to APIs that want an owned `Vec`. Indices map to
+    /// positions in the vector. A zeroizing arena moves the items to fresh
+    /// storage and zeroes its own first; the returned vector is not
+    /// zeroizing.
+    #[must_use]
+    pub fn take_items(&mut self) -> Vec<T> {
+        self.stats.shrink(self.items.len(), 0);
+        self.edits = self.edits.wrapping_add(1);
+        self.take_scrubbed()
+    }
+
+    /// Removes all items, returning an iterator that yields each with the
+    /// index it had, in allocation order.
+    ///
+    /// Lets values be moved elsewhere while correlating them with indices
+    /// recorded before the drain. Behaves like [`drain`](Arena::drain)
+    /// otherwise.
+    pub fn drain_indexed(&mut self) -> DrainIndexed<ArenaDrain<'_, T>> {
+        DrainIndexed::new(self.drain())
     }
 
     /// Returns an iterator yielding `(Idx<T>, &T)` pairs in allocation order.
@@ -174,18 +773,551 @@ impl<T> Arena<T> {
     /// Returns a mutable iterator yielding `(Idx<T>, &mut T)` pairs in
     /// allocation order.
     pub fn iter_indexed_mut(&mut self) -> IterIndexedMut<'_, T> {
-        IterIndexedMut::new(self.items.iter_mut().enumerate())
+        IterIndexedMut::new(self.edit().iter_mut().enumerate())
+    }
+
+    /// Returns an iterator over the indices of all items in allocation
+    /// order.
+    ///
+    /// The iterator does not borrow the arena, so the arena can be mutated
+    /// while walking a snapshot of the current ids.
+    #[must_use]
+    pub fn keys(&self) -> IdxIter<T> {
+        IdxRange::from_raw(0, self.items.len()).into_iter()
+    }
+
+    /// Returns the index of `value` if it refers to an item stored in this
+    /// arena.
+    ///
+    /// O(1): computed from the address of `value`. Returns `None` for
+    /// references outside the arena and for zero-sized `T`.
+    #[must_use]
+    pub fn idx_of(&self, value: &T) -> Option<Idx<T>> {
+        crate::idx::idx_of_in(&self.items, value)
+    }
+
+    /// Binary searches the items with a comparator function.
+    ///
+    /// The items must be sorted with respect to `f`, e.g. because they were
+    /// allocated in order. See [`slice::binary_search_by`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the index where a matching item could be inserted
+    /// if no item matches.
+    pub fn binary_search_by(
+        &self,
+        f: impl FnMut(&T) -> std::cmp::Ordering,
+    ) -> Result<Idx<T>, Idx<T>> {
+        self.items
+            .binary_search_by(f)
+            .map(Idx::from_raw)
+            .map_err(Idx::from_raw)
+    }
+
+    /// Binary searches the items with a key extraction function.
+    ///
+    /// Same contract as [`binary_search_by`](Self::binary_search_by). See
+    /// [`slice::binary_search_by_key`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the insertion index if no item matches `key`.
+    pub fn binary_search_by_key<B: Ord>(
+        &self,
+        key: &B,
+        f: impl FnMut(&T) -> B,
+    ) -> Result<Idx<T>, Idx<T>> {
+        self.items
+            .binary_search_by_key(key, f)
+            .map(Idx::from_raw)
+            .map_err(Idx::from_raw)
+    }
+
+    /// Returns the index of the first item matching `pred`, in
+    /// allocation order.
+    pub fn find_idx(&self, pred: impl FnMut(&T) -> bool) -> Option<Idx<T>> {
+        self.items.iter().position(pred).map(Idx::from_raw)
+    }
+
+    /// Returns the index of the last item matching `pred`, searching
+    /// from the most recent allocation backwards.
+    pub fn rfind_idx(&self, pred: impl FnMut(&T) -> bool) -> Option<Idx<T>> {
+        self.items.iter().rposition(pred).map(Idx::from_raw)
+    }
+
+    /// Returns `true` if an item equals `value`.
+    ///
+    /// O(n) linear scan, meant for small arenas used as registries.
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.items.contains(value)
+    }
+
+    /// Returns the index of the first item equal to `value`.
+    ///
+    /// O(n) linear scan; see [`Interner`](crate::Interner) for hashed
+    /// lookups.
+    #[must_use]
+    pub fn idx_of_value(&self, value: &T) -> Option<Idx<T>>
+    where
+        T: PartialEq,
+    {
+        self.find_idx(|item| item == value)
+    }
+
+    /// Allocates the items of `iter`, returning the index of an equal
+    /// item instead of allocating a duplicate — whether it was already in
+    /// the arena or appeared earlier in `iter`.
+    ///
+    /// Hashes every existing item first; to reuse that work across calls,
+    /// keep a [`ValueIndex`](crate::ValueIndex) and call
+    /// [`extend_unique_with`](Arena::extend_unique_with).
+    ///
+    /// ```
+    /// use fast_bump::Arena;
+    ///
+    /// let mut arena = Arena::new();
+    /// let a = arena.alloc("a");
+    /// let ids = arena.extend_unique(["b", "a", "b"]);
+    /// assert_eq!(ids[1], a);
+    /// assert_eq!(ids[0], ids[2]);
+    /// assert_eq!(arena.len(), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a new item would exceed the arena's
+    /// [limit](Arena::with_limit). Items allocated before it are kept.
+    pub fn extend_unique(&mut self, iter: impl IntoIterator<Item = T>) -> Vec<Idx<T>>
+    where
+        T: Eq + Hash,
+    {
+        self.extend_unique_with(iter, &mut ValueIndex::new())
+    }
+
+    /// Like [`extend_unique`](Arena::extend_unique), but looks duplicates
+    /// up in a caller-kept `index`, which only hashes items added since
+    /// its last use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a new item would exceed the arena's
+    /// [limit](Arena::with_limit). Items allocated before it are kept.
+    pub fn extend_unique_with<S: BuildHasher>(
+        &mut self,
+        iter: impl IntoIterator<Item = T>,
+        index: &mut ValueIndex<T, S>,
+    ) -> Vec<Idx<T>>
+    where
+        T: Eq + Hash,
+    {
+        index.sync(self);
+        iter.into_iter()
+            .map(|value| {
+                let hash = index.hash(&value);
+                index.find(&self.items, hash, &value).unwrap_or_else(|| {
+                    let idx = self.alloc(value);
+                    index.push(hash);
+                    idx
+                })
+            })
+            .collect()
+    }
+
+    /// Sorts the items with a comparator, returning the permutation
+    /// applied to their indices.
+    ///
+    /// Stable: equal items keep their relative order. Existing `Idx<T>`
+    /// handles point at different values after sorting; translate them with
+    /// [`IdxRemap::remap`].
+    ///
+    /// O(n log n).
+    pub fn sort_by(&mut self, mut cmp: impl FnMut(&T, &T) -> std::cmp::Ordering) -> IdxRemap<T> {
+        let mut order: Vec<usize> = (0..self.items.len()).collect();
+        order.sort_by(|&a, &b| cmp(&self.items[a], &self.items[b]));
+        IdxRemap::from_new_of_old(crate::remap::apply_order(self.edit(), &order))
+    }
+
+    /// Sorts the items with a comparator without preserving the order of
+    /// equal items, returning the permutation applied to their indices.
+    ///
+    /// See [`sort_by`](Arena::sort_by).
+    pub fn sort_unstable_by(
+        &mut self,
+        mut cmp: impl FnMut(&T, &T) -> std::cmp::Ordering,
+    ) -> IdxRemap<T> {
+        let mut order: Vec<usize> = (0..self.items.len()).collect();
+        order.sort_unstable_by(|&a, &b| cmp(&self.items[a], &self.items[b]));
+        IdxRemap::from_new_of_old(crate::remap::apply_order(self.edit(), &order))
+    }
+
+    /// Keeps only the items for which `f` returns `true`, compacting the
+    /// rest of the storage, and returns the mapping from old to new
+    /// indices.
+    ///
+    /// Kept items keep their relative order. Removed items are dropped in
+    /// the arena's [`DropOrder`] once all of them are found. O(n).
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) -> IdxRemap<T> {
+        let old_len = self.items.len();
+        let mut new_of_old = Vec::with_capacity(old_len);
+        let mut kept = 0;
+        let removed: Vec<T> = self
+            .edit()
+            .extract_if(.., |item| {
+                let keep = f(item);
+                new_of_old.push(if keep { kept } else { crate::remap::REMOVED });
+                kept += usize::from(keep);
+                !keep
+            })
+            .collect();
+        crate::items::drop_in_order(removed, self.items.drop_order(), self.zeroizing());
+        self.stats.shrink(old_len, kept);
+        self.scrub_freed(old_len);
+        IdxRemap::from_new_of_old(new_of_old)
     }
 
-    /// Reserves capacity for at least `additional` more items.
+    /// Moves every item of `other` to the end of this arena, leaving
+    /// `other` empty, and returns the mapping from `other`'s indices to
+    /// the new ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items would exceed this arena's
+    /// [limit](Arena::with_limit); `other` is left untouched then.
+    pub fn append(&mut self, other: &mut Self) -> IdxRemap<T> {
+        let moved = other.len();
+        let range = self.alloc_n(moved, |items, _| items.append(&mut other.take_items()));
+        IdxRemap::shifted(moved, range.start().into_raw())
+    }
+
+    /// Returns the indices of the items ordered by the key `f` extracts,
+    /// without moving any item.
+    ///
+    /// Stable: items with equal keys keep their allocation order. Unlike
+    /// [`sort_by`](Arena::sort_by), existing `Idx<T>` handles stay valid.
+    ///
+    /// ```
+    /// use fast_bump::Arena;
+    ///
+    /// let mut arena = Arena::new();
+    /// let b = arena.alloc("b");
+    /// let a = arena.alloc("a");
+    /// assert_eq!(arena.sorted_indices_by_key(|s| *s), [a, b]);
+    /// assert_eq!(arena[b], "b");
+    /// ```
+    ///
+    /// O(n log n) calls to `f`.
+    #[must_use]
+    pub fn sorted_indices_by_key<K: Ord>(&self, mut f: impl FnMut(&T) -> K) -> Vec<Idx<T>> {
+        let mut order: Vec<Idx<T>> = self.keys().collect();
+        order.sort_by(|&a, &b| f(&self.items[a.into_raw()]).cmp(&f(&self.items[b.into_raw()])));
+        order
+    }
+
+    /// Returns an iterator yielding `(Idx<T>, &T)` in the order of
+    /// [`sorted_indices_by_key`](Arena::sorted_indices_by_key).
+    pub fn iter_sorted_by_key<K: Ord>(
+        &self,
+        f: impl FnMut(&T) -> K,
+    ) -> impl ExactSizeIterator<Item = (Idx<T>, &T)> {
+        self.sorted_indices_by_key(f)
+            .into_iter()
+            .map(|idx| (idx, &self.items[idx.into_raw()]))
+    }
+
+    /// Reserves capacity for at least `additional` more items, growing per
+    /// the arena's [growth policy](Arena::set_growth_policy).
     pub fn reserve(&mut self, additional: usize) {
+        let capacity = self.items.capacity();
+        self.make_room(additional);
         self.items.reserve(additional);
+        self.note_growth(capacity);
+    }
+
+    /// Reserves capacity for at least `additional` more items, returning an
+    /// error instead of panicking or aborting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaAllocError`] if the capacity overflows or the
+    /// allocator fails. The arena is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), ArenaAllocError> {
+        let capacity = self.items.capacity();
+        self.try_make_room(additional)?;
+        self.items.try_reserve(additional).map_err(|_| {
+            ArenaAllocError::for_capacity::<T>(self.items.len().saturating_add(additional))
+        })?;
+        self.note_growth(capacity);
+        Ok(())
+    }
+
+    /// Returns the policy used when the arena runs out of capacity.
+    #[must_use]
+    pub const fn growth_policy(&self) -> GrowthPolicy {
+        self.growth
+    }
+
+    /// Sets how the arena grows when `alloc`, `extend` or `reserve` run out
+    /// of capacity. The default, [`GrowthPolicy::Double`], leaves growth to
+    /// `Vec`.
+    pub const fn set_growth_policy(&mut self, policy: GrowthPolicy) {
+        self.growth = policy;
+    }
+
+    /// Returns the order in which discarded values are dropped.
+    #[must_use]
+    pub const fn drop_order(&self) -> DropOrder {
+        self.items.drop_order()
+    }
+
+    /// Sets the order in which `rollback`, `reset`, `truncate` and drop run
+    /// destructors. The default is [`DropOrder::Forward`].
+    pub const fn set_drop_order(&mut self, order: DropOrder) {
+        self.items.set_drop_order(order);
     }
 
     /// Shrinks the backing storage to fit the current number of items.
     pub fn shrink_to_fit(&mut self) {
+        if self.zeroizing() {
+            self.relocate(self.items.len());
+        }
         self.items.shrink_to_fit();
     }
+
+    /// Starts or stops collecting [`RollbackStats`](crate::RollbackStats)
+    /// into [`stats`](Arena::stats); stopping discards them.
+    ///
+    /// Off by default. Only rollbacks, resets and drains do extra work.
+    pub fn set_rollback_stats(&mut self, enabled: bool) {
+        self.stats.set_rollback_stats(enabled);
+    }
+
+    /// Returns allocation statistics over the arena's lifetime.
+    #[must_use]
+    pub fn stats(&self) -> ArenaStats {
+        self.stats
+            .snapshot::<T>(self.items.len(), self.items.capacity(), self.memory_usage())
+    }
+
+    /// Returns the heap memory held by the arena, in bytes:
+    /// `capacity * size_of::<T>()`.
+    ///
+    /// Does not include memory owned by the values themselves.
+    #[must_use]
+    pub const fn memory_usage(&self) -> usize {
+        self.items.vec().capacity() * size_of::<T>()
+    }
+
+    /// Installs `observer`, replacing any previous one.
+    pub fn set_observer(&mut self, observer: impl ArenaObserver + 'static) {
+        self.observer = Some(Box::new(observer));
+    }
+
+    /// Removes the installed observer.
+    pub fn clear_observer(&mut self) {
+        self.observer = None;
+    }
+
+    /// Appends `iter`, recording the allocation and any reallocation.
+    fn extend_tracked(&mut self, iter: impl IntoIterator<Item = T>) {
+        let start = self.items.len();
+        let capacity = self.items.capacity();
+        // One value past the budget is enough to know it is exceeded; the
+        // rest of the input is left unconsumed.
+        let budget = self.max_items.saturating_sub(start).saturating_add(1);
+        let iter = iter.into_iter().take(budget);
+        if self.controls_growth() {
+            self.make_room(iter.size_hint().0);
+            for value in iter {
+                self.make_room(1);
+                self.items.push(value);
+            }
+        } else {
+            self.items.extend(iter);
+        }
+        if self.items.len() > self.max_items {
+            self.truncate_items(start);
+            self.limit_exceeded();
+        }
+        self.note_alloc(start, self.items.len() - start, capacity);
+    }
+
+    /// Records `count` values allocated at `start`, and a reallocation if the
+    /// capacity changed from `old_capacity`.
+    fn note_alloc(&mut self, start: usize, count: usize, old_capacity: usize) {
+        self.note_growth(old_capacity);
+        if let Some(observer) = &self.observer
+            && count > 0
+        {
+            observer.on_alloc(start, count);
+        }
+    }
+
+    /// Records a reallocation if the capacity grew past `old_capacity`.
+    fn note_growth(&mut self, old_capacity: usize) {
+        let capacity = self.items.capacity();
+        if capacity > old_capacity {
+            self.stats.grow();
+            if let Some(observer) = &self.observer {
+                observer.on_grow(old_capacity, capacity);
+            }
+            trace::grow::<T>(self.addr(), old_capacity, capacity);
+        }
+    }
+
+    fn limit_error(&self) -> Error {
+        Error::Limit {
+            limit: self.limit.unwrap_or(ArenaLimit::Items(self.max_items)),
+        }
+    }
+
+    #[cold]
+    fn limit_exceeded(&self) -> ! {
+        panic!("{}", self.limit_error());
+    }
+
+    const fn zeroizing(&self) -> bool {
+        cfg!(feature = "zeroize") && self.items.zeroize()
+    }
+
+    /// Returns `true` if growth cannot be left to `Vec`'s own amortized
+    /// doubling: a zeroizing arena must scrub the storage it leaves behind,
+    /// and a non-default policy picks its own capacities.
+    fn controls_growth(&self) -> bool {
+        self.zeroizing() || self.growth != GrowthPolicy::Double
+    }
+
+    /// Makes room for `additional` more values when the arena
+    /// [controls its growth](Arena::controls_growth).
+    fn make_room(&mut self, additional: usize) {
+        if let Err(err) = self.try_make_room(additional) {
+            err.handle();
+        }
+    }
+
+    fn try_make_room(&mut self, additional: usize) -> Result<(), ArenaAllocError> {
+        let len = self.items.len();
+        let capacity = self.items.capacity();
+        if capacity - len >= additional || !self.controls_growth() {
+            return Ok(());
+        }
+        let needed = len
+            .checked_add(additional)
+            .ok_or(ArenaAllocError::CapacityOverflow)?;
+        let target = self.growth.next_capacity(capacity, needed);
+        if self.zeroizing() {
+            self.try_relocate(target)
+        } else {
+            self.items
+                .try_reserve_exact(target - len)
+                .map_err(|_| ArenaAllocError::for_capacity::<T>(target))
+        }
+    }
+
+    /// Moves the values to storage of `capacity` and scrubs the old storage.
+    fn relocate(&mut self, capacity: usize) {
+        if let Err(err) = self.try_relocate(capacity) {
+            err.handle();
+        }
+    }
+
+    fn try_relocate(&mut self, capacity: usize) -> Result<(), ArenaAllocError> {
+        let mut fresh = Vec::new();
+        fresh
+            .try_reserve_exact(capacity)
+            .map_err(|_| ArenaAllocError::for_capacity::<T>(capacity))?;
+        fresh.append(&mut self.items);
+        crate::scrub::zero(self.items.spare_capacity_mut());
+        *self.items = fresh;
+        Ok(())
+    }
+
+    /// Drops the items past `len` in the arena's [`DropOrder`].
+    fn truncate_items(&mut self, len: usize) {
+        if len < self.items.len() {
+            self.edit().truncate_in_order(len);
+        }
+    }
+
+    /// Returns the items for removing, reordering or changing existing
+    /// ones, recording the edit.
+    const fn edit(&mut self) -> &mut Items<T> {
+        self.edits = self.edits.wrapping_add(1);
+        &mut self.items
+    }
+
+    /// Returns a stamp that changes whenever existing items are removed,
+    /// reordered or borrowed mutably.
+    pub(crate) const fn edits(&self) -> usize {
+        self.edits
+    }
+
+    /// Scrubs memory freed by shrinking from `old_len`: a zeroizing arena
+    /// zeroes all spare capacity, other arenas poison the freed slots in
+    /// debug builds.
+    fn scrub_freed(&mut self, old_len: usize) {
+        let freed = old_len - self.items.len();
+        if self.zeroizing() {
+            crate::scrub::zero(self.items.spare_capacity_mut());
+        } else {
+            crate::scrub::poison(&mut self.items.spare_capacity_mut()[..freed]);
+        }
+    }
+
+    /// Address identifying this arena in trace events.
+    fn addr(&self) -> usize {
+        std::ptr::from_ref(self).addr()
+    }
+
+    /// Converts the arena into its items as a `Vec<T>` without copying.
+    ///
+    /// Existing indices stay valid as positions in the vector. A zeroizing
+    /// arena moves the items to fresh storage and zeroes its own first.
+    #[must_use]
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.take_scrubbed()
+    }
+
+    /// Converts the arena into a boxed slice of its items.
+    ///
+    /// The items are not moved; spare capacity is released, which may
+    /// reallocate. Existing indices stay valid as positions in the slice.
+    #[must_use]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.into_vec().into_boxed_slice()
+    }
+
+    /// Converts the arena into an immutable [`FrozenArena`].
+    ///
+    /// Existing indices stay valid. Spare capacity is released.
+    #[must_use]
+    pub fn freeze(mut self) -> FrozenArena<T> {
+        FrozenArena::from_vec(self.take_scrubbed())
+    }
+
+    /// Takes the items out; a zeroizing arena first moves them to storage
+    /// of exactly their length, zeroing the storage it had.
+    fn take_scrubbed(&mut self) -> Vec<T> {
+        if self.zeroizing() {
+            self.relocate(self.items.len());
+        }
+        std::mem::take(&mut *self.items)
+    }
+
+    /// Returns the storage for an iterator to zero once it has moved every
+    /// item out, if the arena is zeroizing.
+    fn scrub_on_drop(&mut self) -> Option<(NonNull<T>, usize)> {
+        (self.zeroizing() && size_of::<T>() != 0).then(|| {
+            (
+                NonNull::new(self.items.as_mut_ptr()).expect("Vec pointers are non-null"),
+                self.items.capacity(),
+            )
+        })
+    }
 }
 
 impl<T> Default for Arena<T> {
@@ -194,6 +1326,24 @@ impl<T> Default for Arena<T> {
     }
 }
 
+impl<T> From<Vec<T>> for Arena<T> {
+    /// Takes over the vector without copying: item `i` gets
+    /// `Idx::from_raw(i)`.
+    fn from(items: Vec<T>) -> Self {
+        let mut arena = Self::new();
+        arena.items = Items::from_vec(items);
+        arena
+    }
+}
+
+impl<T> From<FastArena<T>> for Arena<T> {
+    /// Moves the published items over without copying, preserving their
+    /// indices.
+    fn from(arena: FastArena<T>) -> Self {
+        Self::from(arena.into_vec())
+    }
+}
+
 impl<T> std::ops::Index<Idx<T>> for Arena<T> {
     type Output = T;
 
@@ -208,6 +1358,32 @@ impl<T> std::ops::IndexMut<Idx<T>> for Arena<T> {
     }
 }
 
+impl<T> std::ops::Index<IdxRange<T>> for Arena<T> {
+    type Output = [T];
+
+    fn index(&self, range: IdxRange<T>) -> &[T] {
+        self.slice(range)
+    }
+}
+
+impl<T> std::ops::IndexMut<IdxRange<T>> for Arena<T> {
+    fn index_mut(&mut self, range: IdxRange<T>) -> &mut [T] {
+        self.slice_mut(range)
+    }
+}
+
+impl<T> AsRef<[T]> for Arena<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsMut<[T]> for Arena<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
 impl<'a, T> IntoIterator for &'a Arena<T> {
     type Item = &'a T;
     type IntoIter = std::slice::Iter<'a, T>;
@@ -228,23 +1404,119 @@ impl<'a, T> IntoIterator for &'a mut Arena<T> {
 
 impl<T> Extend<T> for Arena<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        self.items.extend(iter);
+        self.extend_tracked(iter);
     }
 }
 
 impl<T> std::iter::FromIterator<T> for Arena<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        Self {
-            items: iter.into_iter().collect(),
-        }
+        let mut arena = Self::new();
+        arena.items = Items::from_vec(iter.into_iter().collect());
+        arena
     }
 }
 
 impl<T> IntoIterator for Arena<T> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = ArenaIntoIter<T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.items.into_iter()
+    /// Moves the items out; a zeroizing arena zeroes its storage when the
+    /// iterator is dropped.
+    fn into_iter(mut self) -> Self::IntoIter {
+        let scrub = self.scrub_on_drop();
+        ArenaIntoIter {
+            inner: ManuallyDrop::new(std::mem::take(&mut *self.items).into_iter()),
+            scrub,
+        }
     }
 }
+
+/// Zeroes `cap` slots at `ptr` that hold no values.
+///
+/// # Safety
+///
+/// `ptr` must point at a live allocation of `cap` slots with no values.
+unsafe fn zero_storage<T>((ptr, cap): (NonNull<T>, usize)) {
+    // SAFETY: per the caller's contract; any bytes are a valid
+    // `MaybeUninit<T>`.
+    crate::scrub::zero(unsafe {
+        std::slice::from_raw_parts_mut(ptr.as_ptr().cast::<MaybeUninit<T>>(), cap)
+    });
+}
+
+/// Draining iterator over the items of an [`Arena`].
+///
+/// Created by [`Arena::drain`]. Yields the items in allocation order and
+/// drops those not yielded when dropped, leaving the arena empty. For a
+/// zeroizing arena it then zeroes the vacated storage.
+pub struct ArenaDrain<'a, T> {
+    inner: ManuallyDrop<std::vec::Drain<'a, T>>,
+    /// Storage to zero after the drain, for zeroizing arenas.
+    scrub: Option<(NonNull<T>, usize)>,
+}
+
+/// Owning iterator over the items of an [`Arena`].
+///
+/// Created by `Arena::into_iter`. Drops the items not yielded when
+/// dropped; for a zeroizing arena it zeroes the storage before releasing
+/// it.
+pub struct ArenaIntoIter<T> {
+    inner: ManuallyDrop<std::vec::IntoIter<T>>,
+    /// Storage to zero before it is released, for zeroizing arenas.
+    scrub: Option<(NonNull<T>, usize)>,
+}
+
+macro_rules! impl_owning_iter {
+    ($($iter:ty),*) => {$(
+        // SAFETY: `scrub` points into storage owned by `inner`, which is
+        // only written when the iterator is dropped.
+        unsafe impl<T: Send> Send for $iter {}
+        // SAFETY: as for `Send`; `&self` never touches `scrub`.
+        unsafe impl<T: Sync> Sync for $iter {}
+
+        impl<T> Iterator for $iter {
+            type Item = T;
+
+            fn next(&mut self) -> Option<T> {
+                self.inner.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<T> DoubleEndedIterator for $iter {
+            fn next_back(&mut self) -> Option<T> {
+                self.inner.next_back()
+            }
+        }
+
+        impl<T> ExactSizeIterator for $iter {}
+
+        impl<T> std::iter::FusedIterator for $iter {}
+
+        impl<T: std::fmt::Debug> std::fmt::Debug for $iter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_list().entries(self.inner.as_slice()).finish()
+            }
+        }
+
+        impl<T> Drop for $iter {
+            fn drop(&mut self) {
+                let Some(storage) = self.scrub else {
+                    // SAFETY: `inner` is not used again.
+                    return unsafe { ManuallyDrop::drop(&mut self.inner) };
+                };
+                self.inner.by_ref().for_each(drop);
+                // SAFETY: every value was moved out or dropped, and the
+                // storage stays allocated until `inner` is dropped below.
+                unsafe { zero_storage(storage) };
+                // SAFETY: `inner` is not used again.
+                unsafe { ManuallyDrop::drop(&mut self.inner) };
+            }
+        }
+    )*};
+}
+
+impl_owning_iter!(ArenaDrain<'_, T>, ArenaIntoIter<T>);
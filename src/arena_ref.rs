@@ -0,0 +1,78 @@
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::{FastArena, Idx};
+
+/// Self-contained handle to a value in a shared [`FastArena`].
+///
+/// Bundles an `Arc<FastArena<T>>` with an [`Idx<T>`] and derefs to the
+/// value, so it can be stored anywhere without passing the arena along.
+/// While any `ArenaRef` is alive the arena cannot be rolled back or reset
+/// (both need `&mut`), so the value stays valid.
+pub struct ArenaRef<T> {
+    arena: Arc<FastArena<T>>,
+    idx: Idx<T>,
+}
+
+impl<T> ArenaRef<T> {
+    /// Creates a handle to the published value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not published in `arena`.
+    #[must_use]
+    pub fn new(arena: Arc<FastArena<T>>, idx: Idx<T>) -> Self {
+        let _ = arena.get(idx);
+        Self { arena, idx }
+    }
+
+    /// Allocates `value` in `arena` and returns a handle to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full.
+    #[must_use]
+    pub fn alloc(arena: &Arc<FastArena<T>>, value: T) -> Self {
+        let idx = arena.alloc(value);
+        Self::new(Arc::clone(arena), idx)
+    }
+
+    /// Returns the index of the value.
+    #[must_use]
+    pub const fn idx(&self) -> Idx<T> {
+        self.idx
+    }
+
+    /// Returns the arena holding the value.
+    #[must_use]
+    pub const fn arena(&self) -> &Arc<FastArena<T>> {
+        &self.arena
+    }
+}
+
+impl<T> Deref for ArenaRef<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.arena.get(self.idx)
+    }
+}
+
+impl<T> Clone for ArenaRef<T> {
+    fn clone(&self) -> Self {
+        Self {
+            arena: Arc::clone(&self.arena),
+            idx: self.idx,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArenaRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArenaRef")
+            .field("idx", &self.idx)
+            .field("value", &**self)
+            .finish_non_exhaustive()
+    }
+}
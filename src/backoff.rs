@@ -0,0 +1,55 @@
+#[cfg(not(loom))]
+use std::time::Duration;
+
+/// Steps spent busy-spinning, doubling the spin count each time.
+#[cfg(not(loom))]
+const SPIN_LIMIT: u32 = 6;
+/// Steps spent yielding to the scheduler before parking.
+#[cfg(not(loom))]
+const YIELD_LIMIT: u32 = 10;
+/// Park duration once spinning and yielding have not helped.
+#[cfg(not(loom))]
+const PARK_TIMEOUT: Duration = Duration::from_micros(100);
+
+/// Exponential backoff for spin-wait loops.
+///
+/// Spins with exponentially growing counts, then yields the thread, then
+/// parks it briefly, so a waiter stuck behind a descheduled writer stops
+/// burning a whole core.
+pub struct Backoff {
+    step: u32,
+}
+
+impl Backoff {
+    pub const fn new() -> Self {
+        Self { step: 0 }
+    }
+
+    /// Yields to the loom scheduler: spinning would only blow up the
+    /// explored state space.
+    #[cfg(loom)]
+    pub fn snooze(&mut self) {
+        crate::sync::spin_wait();
+        self.step = self.step.saturating_add(1);
+    }
+
+    /// Waits a little longer than the previous call.
+    #[cfg(not(loom))]
+    pub fn snooze(&mut self) {
+        if self.step <= SPIN_LIMIT {
+            for _ in 0..1u32 << self.step {
+                std::hint::spin_loop();
+            }
+        } else if self.step <= YIELD_LIMIT {
+            std::thread::yield_now();
+        } else {
+            std::thread::park_timeout(PARK_TIMEOUT);
+        }
+        self.step = self.step.saturating_add(1);
+    }
+
+    /// Restarts from the shortest wait after progress was made.
+    pub const fn reset(&mut self) {
+        self.step = 0;
+    }
+}
@@ -0,0 +1,245 @@
+use std::sync::{Arc, Condvar, Mutex, PoisonError, RwLock, RwLockReadGuard};
+use std::time::{Duration, Instant};
+
+use crate::{Checkpoint, FastArena, Idx};
+
+/// State shared by a [`BoundedArena`] and its writers.
+struct Shared<T> {
+    arena: RwLock<FastArena<T>>,
+    /// Bumped whenever the owner frees slots.
+    generation: Mutex<u64>,
+    freed: Condvar,
+}
+
+/// Fixed-capacity [`FastArena`] used as a staging buffer with
+/// backpressure.
+///
+/// Producers allocate through [`BoundedWriter`]s. When the arena is full,
+/// [`BoundedWriter::alloc`] blocks until the owner frees slots with
+/// [`reset`](BoundedArena::reset) or [`rollback`](BoundedArena::rollback),
+/// and [`try_alloc`](BoundedWriter::try_alloc) hands the value back
+/// instead. The arena never grows, so memory stays bounded by the
+/// capacity it was created with.
+///
+/// Writers allocate under a shared lock and the owner frees slots under
+/// the exclusive one, so a reset waits for allocations in flight.
+///
+/// ```
+/// use fast_bump::{BoundedArena, FastArena};
+///
+/// let staging = BoundedArena::new(FastArena::with_capacity(2));
+/// let writer = staging.writer();
+/// writer.alloc(1);
+/// writer.alloc(2);
+/// assert_eq!(writer.try_alloc(3), Err(3));
+///
+/// let batch: Vec<i32> = staging.read().iter().copied().collect();
+/// staging.reset();
+/// writer.alloc(3);
+/// assert_eq!(batch, [1, 2]);
+/// ```
+pub struct BoundedArena<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedArena<T> {
+    /// Wraps `arena`; its current capacity becomes the bound.
+    #[must_use]
+    pub fn new(arena: FastArena<T>) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                arena: RwLock::new(arena),
+                generation: Mutex::new(0),
+                freed: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Returns a new writer handle.
+    #[must_use]
+    pub fn writer(&self) -> BoundedWriter<T> {
+        BoundedWriter {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+
+    /// Locks the arena for reading, e.g. to consume a batch. Writers keep
+    /// allocating meanwhile; only `reset` and `rollback` wait for it.
+    pub fn read(&self) -> RwLockReadGuard<'_, FastArena<T>> {
+        self.shared.read()
+    }
+
+    /// Returns a checkpoint of the published items.
+    #[must_use]
+    pub fn checkpoint(&self) -> Checkpoint<T> {
+        self.shared.read().checkpoint()
+    }
+
+    /// Rolls back to `cp` and wakes blocked writers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cp` points beyond the current length.
+    pub fn rollback(&self, cp: Checkpoint<T>) {
+        self.free(|arena| arena.rollback(cp));
+    }
+
+    /// Removes all items and wakes blocked writers.
+    pub fn reset(&self) {
+        self.free(FastArena::reset);
+    }
+
+    fn free(&self, f: impl FnOnce(&mut FastArena<T>)) {
+        f(&mut self
+            .shared
+            .arena
+            .write()
+            .unwrap_or_else(PoisonError::into_inner));
+        *self.shared.generation() += 1;
+        self.shared.freed.notify_all();
+    }
+
+    /// Unwraps the arena once every writer is gone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` if a [`BoundedWriter`] is still alive.
+    pub fn into_inner(self) -> Result<FastArena<T>, Self> {
+        Arc::try_unwrap(self.shared)
+            .map(|shared| {
+                shared
+                    .arena
+                    .into_inner()
+                    .unwrap_or_else(PoisonError::into_inner)
+            })
+            .map_err(|shared| Self { shared })
+    }
+}
+
+impl<T> std::fmt::Debug for BoundedArena<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arena = self.shared.read();
+        f.debug_struct("BoundedArena")
+            .field("len", &arena.len())
+            .field("capacity", &arena.capacity())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Producer handle of a [`BoundedArena`].
+///
+/// Cloneable and `Send`, so each producer thread can own one.
+pub struct BoundedWriter<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedWriter<T> {
+    /// Allocates `value`, blocking while the arena is full.
+    pub fn alloc(&self, mut value: T) -> Idx<T> {
+        loop {
+            match self.shared.try_alloc(value) {
+                Ok(idx) => return idx,
+                Err((generation, rejected)) => {
+                    value = rejected;
+                    self.shared.wait_freed(generation);
+                }
+            }
+        }
+    }
+
+    /// Allocates `value` if a slot is free.
+    ///
+    /// # Errors
+    ///
+    /// Returns `value` back if the arena is full.
+    pub fn try_alloc(&self, value: T) -> Result<Idx<T>, T> {
+        self.shared.try_alloc(value).map_err(|(_, value)| value)
+    }
+
+    /// Allocates `value`, blocking at most `timeout` while the arena is
+    /// full.
+    ///
+    /// # Errors
+    ///
+    /// Returns `value` back if no slot was freed in time.
+    pub fn alloc_timeout(&self, mut value: T, timeout: Duration) -> Result<Idx<T>, T> {
+        let Some(deadline) = Instant::now().checked_add(timeout) else {
+            // Too far out to represent: no deadline at all.
+            return Ok(self.alloc(value));
+        };
+        loop {
+            match self.shared.try_alloc(value) {
+                Ok(idx) => return Ok(idx),
+                Err((generation, rejected)) => {
+                    value = rejected;
+                    if !self.shared.wait_freed_until(generation, deadline) {
+                        return Err(value);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Locks the arena for reading.
+    pub fn read(&self) -> RwLockReadGuard<'_, FastArena<T>> {
+        self.shared.read()
+    }
+}
+
+impl<T> Clone for BoundedWriter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for BoundedWriter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BoundedWriter").finish_non_exhaustive()
+    }
+}
+
+impl<T> Shared<T> {
+    fn read(&self) -> RwLockReadGuard<'_, FastArena<T>> {
+        self.arena.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn generation(&self) -> std::sync::MutexGuard<'_, u64> {
+        self.generation
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Allocates `value`, or returns it with the generation observed while
+    /// the arena was full.
+    fn try_alloc(&self, value: T) -> Result<Idx<T>, (u64, T)> {
+        let arena = self.read();
+        arena.try_alloc_or_return(value).map_err(|(_, value)| {
+            // Read under the shared lock: the owner cannot free slots
+            // before this generation is recorded.
+            (*self.generation(), value)
+        })
+    }
+
+    /// Waits until the generation moves past `seen`.
+    fn wait_freed(&self, seen: u64) {
+        drop(
+            self.freed
+                .wait_while(self.generation(), |generation| *generation == seen)
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+    }
+
+    /// Like [`wait_freed`](Self::wait_freed), but returns `false` if
+    /// `deadline` passes first.
+    fn wait_freed_until(&self, seen: u64, deadline: Instant) -> bool {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        let (generation, result) = self
+            .freed
+            .wait_timeout_while(self.generation(), timeout, |generation| *generation == seen)
+            .unwrap_or_else(PoisonError::into_inner);
+        drop(generation);
+        !result.timed_out()
+    }
+}
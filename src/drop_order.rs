@@ -0,0 +1,16 @@
+/// Order in which an arena runs destructors when it discards values.
+///
+/// Set with [`Arena::set_drop_order`](crate::Arena::set_drop_order) or
+/// [`FastArena::set_drop_order`](crate::FastArena::set_drop_order). Applies
+/// to `rollback`, `reset`, `truncate` and dropping the arena. `Arena`
+/// defaults to [`Forward`](DropOrder::Forward), like `Vec`, and
+/// `FastArena` to [`Reverse`](DropOrder::Reverse).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DropOrder {
+    /// Drop in allocation order, oldest first, like `Vec`.
+    #[default]
+    Forward,
+    /// Drop newest first, so a value is dropped before anything it was
+    /// allocated after.
+    Reverse,
+}
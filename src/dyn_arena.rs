@@ -0,0 +1,263 @@
+use std::alloc::Layout;
+use std::ptr::NonNull;
+
+use crate::Idx;
+
+/// Size of the first chunk; later chunks double.
+const FIRST_CHUNK: usize = 1024;
+/// Minimum alignment of every chunk.
+const CHUNK_ALIGN: usize = 16;
+
+/// Arena of unsized values such as trait objects, e.g.
+/// `DynArena<dyn Handler>`.
+///
+/// Each value is moved into an untyped region and recorded as a fat
+/// pointer `*mut D`, so one arena holds many concrete types behind a
+/// common trait. The region grows in chunks that never move, which keeps
+/// every stored pointer valid until [`reset`](DynArena::reset) or drop.
+///
+/// [`alloc_dyn`](DynArena::alloc_dyn) takes the coercion from the concrete
+/// type to `D` as a closure, because unsizing a generic value is not
+/// expressible on stable Rust; `|v| v` is all it takes.
+///
+/// ```
+/// use fast_bump::DynArena;
+///
+/// trait Handler {
+///     fn handle(&self) -> String;
+/// }
+///
+/// struct Echo;
+/// struct Count(u32);
+///
+/// impl Handler for Echo {
+///     fn handle(&self) -> String {
+///         "echo".into()
+///     }
+/// }
+///
+/// impl Handler for Count {
+///     fn handle(&self) -> String {
+///         self.0.to_string()
+///     }
+/// }
+///
+/// let mut handlers: DynArena<dyn Handler> = DynArena::new();
+/// let echo = handlers.alloc_dyn(Echo, |v| v);
+/// let count = handlers.alloc_dyn(Count(3), |v| v);
+///
+/// assert_eq!(handlers[echo].handle(), "echo");
+/// assert_eq!(handlers[count].handle(), "3");
+/// ```
+pub struct DynArena<D: ?Sized> {
+    /// Fat pointers to the values, in allocation order.
+    items: Vec<NonNull<D>>,
+    /// Every chunk of the region; values are written to the last one.
+    chunks: Vec<(NonNull<u8>, Layout)>,
+    /// Address of the first free byte in the last chunk.
+    cursor: usize,
+    /// Address one past the end of the last chunk.
+    end: usize,
+}
+
+// SAFETY: every value is only reachable as `D`: shared access hands out
+// `&D`, and moving the arena moves ownership of values that are `D`.
+unsafe impl<D: ?Sized + Send> Send for DynArena<D> {}
+// SAFETY: `&DynArena<D>` only hands out `&D`.
+unsafe impl<D: ?Sized + Sync> Sync for DynArena<D> {}
+
+impl<D: ?Sized> DynArena<D> {
+    /// Creates an empty arena. Nothing is allocated until the first value.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            chunks: Vec::new(),
+            cursor: 0,
+            end: 0,
+        }
+    }
+
+    /// Moves `value` into the arena and returns its index as a `D`.
+    ///
+    /// `coerce` unsizes the stored value, typically `|v| v`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `coerce` returns a reference to anything other than the
+    /// whole value it was given.
+    pub fn alloc_dyn<V>(&mut self, value: V, coerce: impl FnOnce(&mut V) -> &mut D) -> Idx<D> {
+        let slot = self.bump(Layout::new::<V>()).cast::<V>();
+        // SAFETY: `bump` returned memory sized and aligned for `V` that
+        // nothing else refers to.
+        unsafe { slot.write(value) };
+        // SAFETY: the slot was just initialized and is exclusively ours.
+        let dyn_ref = coerce(unsafe { &mut *slot.as_ptr() });
+        let same_value = std::ptr::from_mut(dyn_ref).cast::<u8>() == slot.as_ptr().cast::<u8>()
+            && size_of_val(dyn_ref) == size_of::<V>();
+        if !same_value {
+            // SAFETY: the value is initialized and not recorded anywhere.
+            unsafe { slot.drop_in_place() };
+            panic!("`coerce` must return the value it was given");
+        }
+        let ptr = NonNull::from(dyn_ref);
+        self.items.push(ptr);
+        Idx::from_raw(self.items.len() - 1)
+    }
+
+    /// Returns memory for `layout` in the current chunk, starting a new
+    /// chunk if it does not fit.
+    fn bump(&mut self, layout: Layout) -> NonNull<u8> {
+        if layout.size() == 0 {
+            // Zero-sized values need an aligned address, not memory.
+            return NonNull::without_provenance(
+                std::num::NonZeroUsize::new(layout.align()).expect("alignment is non-zero"),
+            );
+        }
+        let start = self.cursor.next_multiple_of(layout.align());
+        if start
+            .checked_add(layout.size())
+            .is_none_or(|end| end > self.end)
+        {
+            self.new_chunk(layout);
+            return self.bump(layout);
+        }
+        self.cursor = start + layout.size();
+        let (base, _) = self.chunks.last().expect("a chunk holds the cursor");
+        // SAFETY: `start..start + size` lies within the last chunk.
+        unsafe { base.add(start - base.addr().get()) }
+    }
+
+    #[cold]
+    fn new_chunk(&mut self, needed: Layout) {
+        let previous = self
+            .chunks
+            .last()
+            .map_or(FIRST_CHUNK / 2, |(_, l)| l.size());
+        let align = needed.align().max(CHUNK_ALIGN);
+        let size = previous
+            .saturating_mul(2)
+            .max(needed.size().next_multiple_of(align));
+        let layout = Layout::from_size_align(size, align).expect("chunk layout overflow");
+        // SAFETY: `layout` has a non-zero size.
+        let base = NonNull::new(unsafe { std::alloc::alloc(layout) })
+            .unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+        self.chunks.push((base, layout));
+        self.cursor = base.addr().get();
+        self.end = self.cursor + size;
+    }
+
+    /// Returns a reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[must_use]
+    pub fn get(&self, idx: Idx<D>) -> &D {
+        self.try_get(idx).expect("index out of bounds")
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if it is out
+    /// of bounds.
+    #[must_use]
+    pub fn try_get(&self, idx: Idx<D>) -> Option<&D> {
+        let ptr = self.items.get(idx.into_raw())?;
+        // SAFETY: recorded pointers refer to live values until reset or
+        // drop, and `&self` rules out mutable access.
+        Some(unsafe { ptr.as_ref() })
+    }
+
+    /// Returns a mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, idx: Idx<D>) -> &mut D {
+        let mut ptr = self.items[idx.into_raw()];
+        // SAFETY: as in `try_get`, with `&mut self` for exclusive access.
+        unsafe { ptr.as_mut() }
+    }
+
+    /// Returns the number of values.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the arena holds no values.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns an iterator over the values in allocation order.
+    pub fn iter(&self) -> impl Iterator<Item = &D> {
+        // SAFETY: as in `try_get`.
+        self.items.iter().map(|ptr| unsafe { ptr.as_ref() })
+    }
+
+    /// Drops every value and keeps the largest chunk for reuse.
+    pub fn reset(&mut self) {
+        self.drop_values();
+        if let Some(last) = self.chunks.pop() {
+            self.free_chunks();
+            self.cursor = last.0.addr().get();
+            self.end = self.cursor + last.1.size();
+            self.chunks.push(last);
+        }
+    }
+
+    fn drop_values(&mut self) {
+        for ptr in std::mem::take(&mut self.items) {
+            // SAFETY: each recorded value is live and dropped exactly once;
+            // the list was taken, so a panicking destructor leaks the rest
+            // instead of dropping them twice.
+            unsafe { ptr.drop_in_place() };
+        }
+    }
+
+    fn free_chunks(&mut self) {
+        for (base, layout) in self.chunks.drain(..) {
+            // SAFETY: the chunk was allocated with `layout` and holds no
+            // live values.
+            unsafe { std::alloc::dealloc(base.as_ptr(), layout) };
+        }
+    }
+}
+
+impl<D: ?Sized> Default for DynArena<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: ?Sized> std::ops::Index<Idx<D>> for DynArena<D> {
+    type Output = D;
+
+    fn index(&self, idx: Idx<D>) -> &D {
+        self.get(idx)
+    }
+}
+
+impl<D: ?Sized> std::ops::IndexMut<Idx<D>> for DynArena<D> {
+    fn index_mut(&mut self, idx: Idx<D>) -> &mut D {
+        self.get_mut(idx)
+    }
+}
+
+impl<D: ?Sized> std::fmt::Debug for DynArena<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynArena")
+            .field("len", &self.items.len())
+            .field("chunks", &self.chunks.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<D: ?Sized> Drop for DynArena<D> {
+    fn drop(&mut self) {
+        self.drop_values();
+        self.free_chunks();
+    }
+}
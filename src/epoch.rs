@@ -0,0 +1,229 @@
+use crate::{Arena, Idx};
+
+/// [`Idx<T>`] tagged with the [`EpochArena`] epoch it was allocated in.
+///
+/// Going through the epoch arena, a tag from before the last
+/// [`reset`](EpochArena::reset) is detected as stale instead of
+/// resolving to whatever value now occupies the slot.
+pub struct EpochIdx<T> {
+    idx: Idx<T>,
+    epoch: u64,
+}
+
+impl<T> EpochIdx<T> {
+    /// Returns the untagged index.
+    #[must_use]
+    pub const fn idx(self) -> Idx<T> {
+        self.idx
+    }
+
+    /// Returns the epoch the value was allocated in.
+    #[must_use]
+    pub const fn epoch(self) -> u64 {
+        self.epoch
+    }
+}
+
+impl<T> Clone for EpochIdx<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for EpochIdx<T> {}
+
+impl<T> PartialEq for EpochIdx<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.idx == other.idx && self.epoch == other.epoch
+    }
+}
+
+impl<T> Eq for EpochIdx<T> {}
+
+impl<T> std::hash::Hash for EpochIdx<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.idx.hash(state);
+        self.epoch.hash(state);
+    }
+}
+
+impl<T> std::fmt::Debug for EpochIdx<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EpochIdx({}@{})", self.idx.into_raw(), self.epoch)
+    }
+}
+
+/// [`Arena<T>`] that detects indices outliving a [`reset`](EpochArena::reset).
+///
+/// A plain arena reuses slots after `reset`, so an old `Idx<T>` silently
+/// resolves to a newer value. This opt-in wrapper bumps an epoch on every
+/// reset and hands out [`EpochIdx<T>`] tagged with it:
+/// [`try_get`](EpochArena::try_get) returns `None` and
+/// [`get`](EpochArena::get) panics with "stale epoch" for a tag from an
+/// earlier epoch. The tag costs an extra `u64` per index.
+///
+/// There is no `rollback`: it would reuse slots within one epoch.
+///
+/// ```
+/// use fast_bump::EpochArena;
+///
+/// let mut arena = EpochArena::new();
+/// let old = arena.alloc("old");
+/// arena.reset();
+/// let new = arena.alloc("new");
+///
+/// assert_eq!(old.idx(), new.idx());
+/// assert_eq!(arena.try_get(old), None);
+/// assert_eq!(arena[new], "new");
+/// ```
+pub struct EpochArena<T> {
+    arena: Arena<T>,
+    epoch: u64,
+}
+
+impl<T> EpochArena<T> {
+    /// Creates an empty arena at epoch 0.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            arena: Arena::new(),
+            epoch: 0,
+        }
+    }
+
+    /// Creates an empty arena with capacity for `capacity` values.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            arena: Arena::with_capacity(capacity),
+            epoch: 0,
+        }
+    }
+
+    /// Allocates a value, returning its index tagged with the current
+    /// epoch.
+    pub fn alloc(&mut self, value: T) -> EpochIdx<T> {
+        EpochIdx {
+            idx: self.arena.alloc(value),
+            epoch: self.epoch,
+        }
+    }
+
+    /// Returns a reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with "stale epoch" if `idx` was allocated before the last
+    /// [`reset`](EpochArena::reset).
+    #[must_use]
+    pub fn get(&self, idx: EpochIdx<T>) -> &T {
+        self.check(idx);
+        self.arena.get(idx.idx)
+    }
+
+    /// Returns a mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with "stale epoch" if `idx` was allocated before the last
+    /// [`reset`](EpochArena::reset).
+    #[must_use]
+    pub fn get_mut(&mut self, idx: EpochIdx<T>) -> &mut T {
+        self.check(idx);
+        self.arena.get_mut(idx.idx)
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if it was
+    /// allocated before the last reset.
+    #[must_use]
+    pub fn try_get(&self, idx: EpochIdx<T>) -> Option<&T> {
+        self.is_valid(idx).then(|| self.arena.get(idx.idx))
+    }
+
+    /// Returns a mutable reference to the value at `idx`, or `None` if it
+    /// was allocated before the last reset.
+    #[must_use]
+    pub fn try_get_mut(&mut self, idx: EpochIdx<T>) -> Option<&mut T> {
+        if self.is_valid(idx) {
+            Some(self.arena.get_mut(idx.idx))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `idx` belongs to the current epoch.
+    #[must_use]
+    pub const fn is_valid(&self, idx: EpochIdx<T>) -> bool {
+        idx.epoch == self.epoch && self.arena.is_valid(idx.idx)
+    }
+
+    fn check(&self, idx: EpochIdx<T>) {
+        assert!(
+            idx.epoch == self.epoch,
+            "stale epoch: index {} from epoch {}, arena at epoch {}",
+            idx.idx.into_raw(),
+            idx.epoch,
+            self.epoch,
+        );
+    }
+
+    /// Removes all values and starts a new epoch, invalidating every index
+    /// handed out so far.
+    pub fn reset(&mut self) {
+        self.arena.reset();
+        self.epoch += 1;
+    }
+
+    /// Returns the current epoch: the number of resets so far.
+    #[must_use]
+    pub const fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the number of values in the current epoch.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Returns `true` if the current epoch holds no values.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.arena.is_empty()
+    }
+
+    /// Returns the underlying arena.
+    #[must_use]
+    pub const fn arena(&self) -> &Arena<T> {
+        &self.arena
+    }
+}
+
+impl<T> Default for EpochArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::ops::Index<EpochIdx<T>> for EpochArena<T> {
+    type Output = T;
+
+    fn index(&self, idx: EpochIdx<T>) -> &T {
+        self.get(idx)
+    }
+}
+
+impl<T> std::ops::IndexMut<EpochIdx<T>> for EpochArena<T> {
+    fn index_mut(&mut self, idx: EpochIdx<T>) -> &mut T {
+        self.get_mut(idx)
+    }
+}
+
+impl<T> std::fmt::Debug for EpochArena<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EpochArena")
+            .field("epoch", &self.epoch)
+            .field("len", &self.arena.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,120 @@
+use std::alloc::Layout;
+use std::fmt;
+
+use crate::ArenaLimit;
+
+/// Error returned by the fallible (`try_`) arena operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Every slot of a fixed-capacity arena is taken.
+    Full {
+        /// Capacity of the arena, in values.
+        capacity: usize,
+    },
+    /// The allocation would exceed the budget set with `with_limit`.
+    Limit {
+        /// The budget that would be exceeded.
+        limit: ArenaLimit,
+    },
+    /// The index does not refer to a live value, e.g. because a rollback
+    /// or reset removed it.
+    StaleIndex {
+        /// Raw value of the index.
+        index: usize,
+        /// Length of the arena when the index was used.
+        len: usize,
+    },
+    /// The checkpoint was taken when the arena held more values than it
+    /// does now.
+    CheckpointBeyondLen {
+        /// Length recorded in the checkpoint.
+        checkpoint: usize,
+        /// Current length of the arena.
+        len: usize,
+    },
+    /// Backing storage could not be obtained.
+    AllocFailed {
+        /// The underlying allocation error.
+        source: ArenaAllocError,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full { capacity } => write!(f, "arena full: capacity {capacity}"),
+            Self::Limit { limit } => write!(f, "arena limit exceeded: {limit}"),
+            Self::StaleIndex { index, len } => {
+                write!(f, "index {index} out of bounds for length {len}")
+            }
+            Self::CheckpointBeyondLen { checkpoint, len } => {
+                write!(f, "checkpoint {checkpoint} beyond current length {len}")
+            }
+            Self::AllocFailed { source } => source.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AllocFailed { source } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<ArenaAllocError> for Error {
+    fn from(source: ArenaAllocError) -> Self {
+        Self::AllocFailed { source }
+    }
+}
+
+/// Error returned when an arena cannot obtain backing storage.
+///
+/// Returned by `try_with_capacity`, `try_reserve`, and `FastArena`'s
+/// `try_grow` / `try_grow_to`, whose infallible counterparts panic or abort
+/// instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArenaAllocError {
+    /// The requested capacity exceeds `isize::MAX` bytes.
+    CapacityOverflow,
+    /// The global allocator could not provide the memory.
+    AllocFailed {
+        /// Layout of the allocation that failed.
+        layout: Layout,
+    },
+}
+
+impl ArenaAllocError {
+    /// Error for failing to allocate storage for `capacity` values of `T`.
+    pub(crate) fn for_capacity<T>(capacity: usize) -> Self {
+        Layout::array::<T>(capacity).map_or(Self::CapacityOverflow, |layout| Self::AllocFailed {
+            layout,
+        })
+    }
+
+    /// Fails the way the infallible operations do: panics on overflow,
+    /// reports allocation failure through `handle_alloc_error`.
+    pub(crate) fn handle(self) -> ! {
+        match self {
+            Self::CapacityOverflow => panic!("capacity overflow"),
+            Self::AllocFailed { layout } => std::alloc::handle_alloc_error(layout),
+        }
+    }
+}
+
+impl fmt::Display for ArenaAllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CapacityOverflow => f.write_str("capacity overflow"),
+            Self::AllocFailed { layout } => {
+                write!(f, "memory allocation of {} bytes failed", layout.size())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArenaAllocError {}
@@ -1,6 +1,19 @@
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::mem::MaybeUninit;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+use std::task::Waker;
+use std::time::{Duration, Instant};
 
-use crate::{Checkpoint, Idx};
+use crate::backoff::Backoff;
+use crate::ready_bits::ReadyBits;
+use crate::stats::StatsCounters;
+use crate::sync::{AtomicUsize, Exclusive, Ordering};
+use crate::trace;
+use crate::wait::Waiters;
+use crate::{
+    Arena, ArenaAllocError, ArenaLimit, ArenaObserver, ArenaStats, ArenaView, Checkpoint,
+    DropOrder, Error, FrozenArena, GetAsync, GrowthPolicy, Idx, IdxRange, LocalWriter, ReservedIdx,
+    SlotGuard, SlotWriter,
+};
 
 /// Concurrent typed arena with contiguous storage.
 ///
@@ -26,11 +39,21 @@ use crate::{Checkpoint, Idx};
 ///
 /// # Architecture
 ///
-/// Backed by a single contiguous allocation with per-slot readiness flags.
-/// Writers claim slots atomically, write values directly in place, then mark
-/// the slot as ready. A cooperative `advance_published` protocol makes
+/// Backed by a single contiguous allocation with packed per-slot readiness
+/// bits. Writers claim slots atomically, write values directly in place, then
+/// mark the slot as ready. A cooperative `advance_published` protocol makes
 /// completed slots visible to readers in order.
 ///
+/// # Poisoning
+///
+/// A slot that was reserved but can never be written — its constructor
+/// panicked, or a [`SlotGuard`]/[`SlotWriter`] was dropped unfinished after
+/// later slots were claimed — poisons the arena. Publication stops before
+/// that slot instead of waiting forever: readers keep the published prefix,
+/// later allocations stay invisible, and [`get`](FastArena::get) on them
+/// panics with "arena poisoned". [`rollback`](FastArena::rollback) or
+/// [`reset`](FastArena::reset) clears the poison.
+///
 /// # Comparison with `Arena<T>`
 ///
 /// | Property | `Arena<T>` | `FastArena<T>` |
@@ -39,21 +62,43 @@ use crate::{Checkpoint, Idx};
 /// | `get` latency | ~1ns | ~1ns |
 /// | `&[T]` slices | yes | yes |
 /// | `get_mut` | `&mut self` | `&mut self` |
-/// | Memory per slot | `size_of::<T>()` | `size_of::<T>()` + 1 byte |
+/// | Memory per slot | `size_of::<T>()` | `size_of::<T>()` + 1 bit |
 /// | Threading | `Send` | `Send + Sync` |
 pub struct FastArena<T> {
     /// Contiguous storage for values. Length = capacity.
     data: *mut T,
-    /// Per-slot readiness flags.
-    flags: *mut AtomicBool,
+    /// Per-slot readiness flags, one bit per slot.
+    flags: ReadyBits,
     /// Current capacity (number of slots allocated).
     cap: usize,
     /// Next slot to be reserved by `alloc`.
     cursor: AtomicUsize,
     /// Boundary: all slots `< published` are readable.
     published: AtomicUsize,
+    /// Lowest abandoned slot, or `NO_POISON`. Publication stops here.
+    poisoned: AtomicUsize,
+    /// Called with the new `published` length after it advances.
+    publish_hook: Option<PublishHook>,
+    /// Wakers of pending `get_async` futures.
+    waiters: Waiters,
+    /// Lifetime counters, updated only under `&mut self`.
+    stats: StatsCounters,
+    observer: Option<Box<dyn ArenaObserver>>,
+    limit: Option<ArenaLimit>,
+    /// `limit` in values, or `usize::MAX` without a limit. `cap` never
+    /// exceeds it.
+    max_items: usize,
+    /// Zero freed slot memory; set by `with_capacity_zeroizing`.
+    zeroize: bool,
+    growth: GrowthPolicy,
+    drop_order: DropOrder,
+    /// Alignment of `data`; at least `align_of::<T>()`.
+    align: usize,
 }
 
+/// Callback fired when [`FastArena`] publishes new slots.
+type PublishHook = Box<dyn Fn(usize) + Send + Sync + RefUnwindSafe + UnwindSafe>;
+
 // SAFETY: FastArena owns all data behind raw pointers.
 // Access to data[i] is safe when i < published (Acquire fence).
 // Writers only write to exclusively reserved slots (cursor.fetch_add).
@@ -62,6 +107,7 @@ unsafe impl<T: Send + Sync> Send for FastArena<T> {}
 unsafe impl<T: Send + Sync> Sync for FastArena<T> {}
 
 const INITIAL_CAP: usize = 64;
+const NO_POISON: usize = usize::MAX;
 
 impl<T> FastArena<T> {
     /// Creates a new arena with default initial capacity.
@@ -76,14 +122,148 @@ impl<T> FastArena<T> {
     /// allocated.
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
-        let cap = capacity.max(1);
-        let (data, flags) = alloc_storage::<T>(cap);
+        Self::with_storage(capacity.max(1), None)
+    }
+
+    /// Creates a new arena with the specified capacity, returning an error
+    /// instead of aborting if the storage cannot be allocated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaAllocError`] if `capacity` values overflow the
+    /// address space or the allocator fails.
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, ArenaAllocError> {
+        Self::try_with_storage(capacity.max(1), std::mem::align_of::<T>(), None)
+    }
+
+    /// Creates an arena that refuses to hold more than `limit` allows.
+    ///
+    /// Capacity starts at the default and [`grow`](FastArena::grow) never
+    /// goes past the budget. Once it is used up,
+    /// [`try_alloc`](FastArena::try_alloc) returns [`Error::Limit`] and
+    /// [`alloc`](FastArena::alloc) panics. A byte budget counts
+    /// `size_of::<T>()` per value, not the readiness bits.
+    #[must_use]
+    pub fn with_limit(limit: ArenaLimit) -> Self {
+        Self::with_storage(INITIAL_CAP.min(limit.max_items::<T>()), Some(limit))
+    }
+
+    /// Creates an arena whose storage starts at a multiple of `align`
+    /// bytes, e.g. 64 for cache-line or SIMD loads over
+    /// [`as_slice`](FastArena::as_slice).
+    ///
+    /// The alignment survives [`grow`](FastArena::grow). Slots keep the
+    /// stride of `T`; wrap `T` in a `#[repr(align(N))]` type to align every
+    /// slot. An `align` below `align_of::<T>()` is raised to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    #[must_use]
+    pub fn with_capacity_aligned(capacity: usize, align: usize) -> Self {
+        Self::try_with_capacity_aligned(capacity, align).unwrap_or_else(|err| err.handle())
+    }
+
+    /// Creates an arena like
+    /// [`with_capacity_aligned`](FastArena::with_capacity_aligned),
+    /// returning an error instead of aborting if the storage cannot be
+    /// allocated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaAllocError`] if `capacity` values overflow the
+    /// address space or the allocator fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    pub fn try_with_capacity_aligned(
+        capacity: usize,
+        align: usize,
+    ) -> Result<Self, ArenaAllocError> {
+        assert!(align.is_power_of_two(), "alignment must be a power of two");
+        let align = align.max(std::mem::align_of::<T>());
+        Self::try_with_storage(capacity.max(1), align, None)
+    }
+
+    /// Returns the alignment of the storage in bytes.
+    #[must_use]
+    pub const fn alignment(&self) -> usize {
+        self.align
+    }
+
+    /// Creates an arena that zeroes slot memory once it is freed.
+    ///
+    /// Memory is scrubbed with non-elidable writes after destructors run on
+    /// [`rollback`](FastArena::rollback), [`reset`](FastArena::reset),
+    /// [`drain`](FastArena::drain), and drop, and the old storage is
+    /// scrubbed when [`grow`](FastArena::grow) moves the values.
+    ///
+    /// Requires the `zeroize` feature.
+    #[cfg(feature = "zeroize")]
+    #[must_use]
+    pub fn with_capacity_zeroizing(capacity: usize) -> Self {
+        let mut arena = Self::with_capacity(capacity);
+        arena.zeroize = true;
+        arena
+    }
+
+    /// Returns the budget set with [`with_limit`](FastArena::with_limit).
+    #[must_use]
+    pub const fn limit(&self) -> Option<ArenaLimit> {
+        self.limit
+    }
+
+    fn with_storage(cap: usize, limit: Option<ArenaLimit>) -> Self {
+        Self::try_with_storage(cap, std::mem::align_of::<T>(), limit)
+            .unwrap_or_else(|err| err.handle())
+    }
+
+    fn try_with_storage(
+        cap: usize,
+        align: usize,
+        limit: Option<ArenaLimit>,
+    ) -> Result<Self, ArenaAllocError> {
+        let data = alloc_storage::<T>(cap, align)?;
+        let flags = match ReadyBits::try_new(cap) {
+            Ok(flags) => flags,
+            Err(err) => {
+                // SAFETY: data was just allocated for cap and holds no
+                // values.
+                unsafe { dealloc_storage(data, cap, align) };
+                return Err(err);
+            }
+        };
+        Ok(Self::from_storage(data, flags, cap, 0, align, limit))
+    }
+
+    /// Assembles an arena over `cap` slots at `data`, aligned to `align`,
+    /// whose first `len` are written, published, and flagged ready.
+    fn from_storage(
+        data: *mut T,
+        flags: ReadyBits,
+        cap: usize,
+        len: usize,
+        align: usize,
+        limit: Option<ArenaLimit>,
+    ) -> Self {
         Self {
             data,
             flags,
             cap,
-            cursor: AtomicUsize::new(0),
-            published: AtomicUsize::new(0),
+            cursor: AtomicUsize::new(len),
+            published: AtomicUsize::new(len),
+            poisoned: AtomicUsize::new(NO_POISON),
+            publish_hook: None,
+            waiters: Waiters::new(),
+            stats: StatsCounters::new(),
+            observer: None,
+            limit,
+            max_items: limit.map_or(usize::MAX, ArenaLimit::max_items::<T>),
+            zeroize: false,
+            growth: GrowthPolicy::Double,
+            drop_order: DropOrder::Reverse,
+            align,
         }
     }
 
@@ -98,46 +278,296 @@ impl<T> FastArena<T> {
     /// to expand capacity before this happens.
     pub fn alloc(&self, value: T) -> Idx<T> {
         let slot = self.cursor.fetch_add(1, Ordering::Relaxed);
-        assert!(
-            slot < self.cap,
-            "arena full: slot {slot} >= capacity {}",
-            self.cap,
-        );
+        if slot >= self.cap {
+            self.return_overflow(slot, 1);
+            self.capacity_exceeded(slot, 1);
+        }
+        self.write_slot(slot, value)
+    }
+
+    /// Allocates a value and returns its index together with a reference
+    /// to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full, like [`alloc`](FastArena::alloc).
+    pub fn alloc_ref(&self, value: T) -> (Idx<T>, &T) {
+        let idx = self.alloc(value);
+        // SAFETY: `alloc` wrote the slot, and removing it needs `&mut self`,
+        // which the returned borrow of `self` rules out.
+        let value = unsafe { &*self.data.add(idx.into_raw()) };
+        (idx, value)
+    }
+
+    /// Allocates a value unless the arena is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Limit`] if the arena's
+    /// [limit](FastArena::with_limit) is used up, or [`Error::Full`] if the
+    /// current capacity is. `value` is dropped.
+    pub fn try_alloc(&self, value: T) -> Result<Idx<T>, Error> {
+        self.try_alloc_or_return(value).map_err(|(err, _)| err)
+    }
+
+    /// Like [`try_alloc`](FastArena::try_alloc), but hands `value` back
+    /// with the error.
+    pub(crate) fn try_alloc_or_return(&self, value: T) -> Result<Idx<T>, (Error, T)> {
+        let slot = self.cursor.fetch_add(1, Ordering::Relaxed);
+        if slot >= self.cap {
+            self.return_overflow(slot, 1);
+            return Err((self.capacity_error(), value));
+        }
+        Ok(self.write_slot(slot, value))
+    }
 
+    /// Writes `value` into the reserved `slot` and publishes it.
+    fn write_slot(&self, slot: usize, value: T) -> Idx<T> {
         // SAFETY: slot < cap, and each slot is exclusively owned by the
         // thread that reserved it (unique via fetch_add).
-        unsafe {
-            self.data.add(slot).write(value);
-            (*self.flags.add(slot)).store(true, Ordering::Release);
+        unsafe { self.data.add(slot).write(value) };
+        self.flags.set(slot);
+
+        self.advance_published(slot, slot + 1);
+        if let Some(observer) = &self.observer {
+            observer.on_alloc(slot, 1);
         }
+        Idx::from_raw(slot)
+    }
+
+    /// Allocates the value returned by `f`.
+    ///
+    /// The slot is reserved before `f` runs, which gives the optimizer the
+    /// chance to write the value in place instead of building it on the
+    /// stack and moving it. That is not guaranteed; use
+    /// [`alloc_emplace`](FastArena::alloc_emplace) to initialize the slot
+    /// memory directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full. If `f` panics, the reserved slot is
+    /// returned to the arena when no later slot has been claimed, and
+    /// [poisons](FastArena#poisoning) it otherwise.
+    pub fn alloc_with(&self, f: impl FnOnce() -> T) -> Idx<T> {
+        let slot = self.reserve_block(1);
+        let guard = AbandonOnUnwind { arena: self, slot };
+        // SAFETY: slot < cap and is exclusively owned by this thread.
+        unsafe { self.data.add(slot).write(f()) };
+        std::mem::forget(guard);
+        self.publish_block(slot, 1);
+        Idx::from_raw(slot)
+    }
+
+    /// Allocates the value returned by `f`, or returns its error without
+    /// touching the arena.
+    ///
+    /// Unlike [`alloc_with`](FastArena::alloc_with), `f` runs before a slot
+    /// is reserved: a slot claimed and then left empty would stall or
+    /// [poison](FastArena#poisoning) later allocations.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full.
+    pub fn alloc_try<E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<Idx<T>, E> {
+        f().map(|value| self.alloc(value))
+    }
 
-        self.advance_published(slot);
+    /// Allocates a value initialized in place by `init`.
+    ///
+    /// `init` receives the uninitialized slot memory. Useful for large
+    /// values and for fills that write field by field.
+    ///
+    /// # Safety
+    ///
+    /// `init` must fully initialize the slot before returning.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full. If `init` panics, the reserved slot is
+    /// returned to the arena when no later slot has been claimed, and
+    /// [poisons](FastArena#poisoning) it otherwise.
+    pub unsafe fn alloc_emplace(&self, init: impl FnOnce(&mut MaybeUninit<T>)) -> Idx<T> {
+        let slot = self.reserve_block(1);
+        let guard = AbandonOnUnwind { arena: self, slot };
+        // SAFETY: slot < cap and is exclusively owned by this thread.
+        // MaybeUninit<T> has the same layout as T.
+        init(unsafe { &mut *self.data.add(slot).cast::<MaybeUninit<T>>() });
+        std::mem::forget(guard);
+        self.publish_block(slot, 1);
         Idx::from_raw(slot)
     }
 
-    /// Cooperatively advances `published` past `slot`.
+    /// Reserves a slot without initializing or publishing it.
+    ///
+    /// Fill it through [`SlotGuard::as_uninit`] and make it visible with
+    /// [`SlotGuard::publish`]. Until then, later slots cannot be published
+    /// either, so keep the gap between reserving and publishing short.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full.
+    pub fn alloc_uninit(&self) -> SlotGuard<'_, T> {
+        SlotGuard::new(self, self.reserve_block(1))
+    }
+
+    /// Reserves a slot whose index can be handed out before its value
+    /// exists, like a promise.
+    ///
+    /// Share the index right away; fulfil it later with
+    /// [`SlotGuard::write`], possibly from another thread. Readers turn
+    /// the [`ReservedIdx`] into an [`Idx`] with
+    /// [`resolve`](FastArena::resolve), which returns `None` until then, or
+    /// block in [`resolve_wait`](FastArena::resolve_wait).
+    ///
+    /// Publication stays in index order: allocations and reservations made
+    /// after this one are not visible, and their `alloc` or `write` calls do
+    /// not return, until this guard is written. Fulfil promises in index
+    /// order within a thread, and never allocate from the thread that will
+    /// write the guard before writing it.
+    ///
+    /// ```
+    /// use fast_bump::FastArena;
+    ///
+    /// let arena = FastArena::with_capacity(8);
+    /// let (idx, slot) = arena.reserve_one();
+    /// std::thread::scope(|s| {
+    ///     let reader = s.spawn(|| arena[arena.resolve_wait(idx)]);
+    ///     assert_eq!(arena.resolve(idx), None);
+    ///     slot.write(42);
+    ///     assert_eq!(reader.join().unwrap(), 42);
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full.
+    pub fn reserve_one(&self) -> (ReservedIdx<T>, SlotGuard<'_, T>) {
+        let guard = self.alloc_uninit();
+        (guard.idx(), guard)
+    }
+
+    /// Returns the index of the reserved slot if its value is published.
+    #[must_use]
+    pub fn resolve(&self, reserved: ReservedIdx<T>) -> Option<Idx<T>> {
+        let idx = reserved.assume_published();
+        (idx.into_raw() < self.published.load(Ordering::Acquire)).then_some(idx)
+    }
+
+    /// Returns the index of the reserved slot, blocking until its value is
+    /// published.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as
+    /// [`get_wait`](FastArena::get_wait).
+    #[must_use]
+    pub fn resolve_wait(&self, reserved: ReservedIdx<T>) -> Idx<T> {
+        let idx = reserved.assume_published();
+        let _ = self.get_wait(idx);
+        idx
+    }
+
+    /// Cooperatively advances `published` past the caller's written
+    /// slots `start..end`.
     ///
     /// Same protocol as `SharedArena::advance_published`: each writer
-    /// helps advance through all preceding ready slots.
-    fn advance_published(&self, slot: usize) {
+    /// helps advance through all preceding ready slots. While waiting on a
+    /// slower writer it backs off exponentially, then yields, then parks.
+    /// Once `published` reaches the caller's own block, it jumps over the
+    /// whole block with one CAS. Gives up without publishing if a preceding
+    /// slot is poisoned.
+    fn advance_published(&self, start: usize, end: usize) {
+        let mut backoff = Backoff::new();
+        let mut advanced_to = None;
         loop {
             let p = self.published.load(Ordering::Acquire);
-            if p > slot {
+            if p >= end || p >= self.poisoned.load(Ordering::Acquire) {
                 break;
             }
-            // SAFETY: p < cap (published never exceeds cursor which is < cap).
-            let ready = unsafe { (*self.flags.add(p)).load(Ordering::Acquire) };
-            if !ready {
-                std::hint::spin_loop();
+            let next = if p >= start {
+                // The caller's own slots, all marked ready.
+                end
+            } else if self.flags.is_set(p) {
+                // p < cap: published never exceeds cursor which is < cap.
+                p + 1
+            } else {
+                backoff.snooze();
                 continue;
+            };
+            if self
+                .published
+                // SeqCst pairs with `register_waker`; a plain locked
+                // instruction either way on x86.
+                .compare_exchange_weak(p, next, Ordering::SeqCst, Ordering::Relaxed)
+                .is_ok()
+            {
+                advanced_to = Some(next);
+                backoff.reset();
             }
-            let _ = self.published.compare_exchange_weak(
-                p,
-                p + 1,
-                Ordering::Release,
-                Ordering::Relaxed,
-            );
         }
+        if let Some(len) = advanced_to {
+            self.waiters.wake(len);
+            if let Some(hook) = &self.publish_hook {
+                hook(len);
+            }
+        }
+    }
+
+    /// Registers `waker` to be woken once `slot` is published or the arena
+    /// is poisoned at or before it. Returns `false` if that already
+    /// happened.
+    pub(crate) fn register_waker(&self, slot: usize, waker: &Waker) -> bool {
+        self.waiters.register(slot, waker);
+        self.published.load(Ordering::SeqCst) <= slot && self.poisoned.load(Ordering::SeqCst) > slot
+    }
+
+    /// Withdraws a waker registered by
+    /// [`register_waker`](FastArena::register_waker), e.g. when its future
+    /// is dropped before the slot is published.
+    pub(crate) fn deregister_waker(&self, slot: usize, waker: &Waker) {
+        self.waiters.deregister(slot, waker);
+    }
+
+    /// Installs `hook`, called with the new published length whenever an
+    /// allocation advances `published`.
+    ///
+    /// Lets consumers wake up (e.g. through a condition variable or an
+    /// async `Notify`) instead of polling. The hook runs on the writer thread
+    /// that advanced publication, possibly on several threads at once, and
+    /// one call may cover slots published by other writers; calls are not
+    /// ordered by length. Without a hook, publication costs one extra
+    /// branch.
+    ///
+    /// The hook must be unwind safe, like [`ArenaObserver`]s, so the arena
+    /// stays `UnwindSafe` and `RefUnwindSafe`; a hook capturing an `Arc`
+    /// of a `Mutex` or of atomics qualifies.
+    pub fn set_publish_hook(
+        &mut self,
+        hook: impl Fn(usize) + Send + Sync + RefUnwindSafe + UnwindSafe + 'static,
+    ) {
+        self.publish_hook = Some(Box::new(hook));
+    }
+
+    /// Installs `observer`, replacing any previous one.
+    ///
+    /// See [`ArenaObserver`] for when each event fires.
+    pub fn set_observer(&mut self, observer: impl ArenaObserver + 'static) {
+        self.observer = Some(Box::new(observer));
+    }
+
+    /// Removes the installed observer.
+    pub fn clear_observer(&mut self) {
+        self.observer = None;
+    }
+
+    /// Removes the hook installed by
+    /// [`set_publish_hook`](FastArena::set_publish_hook).
+    pub fn clear_publish_hook(&mut self) {
+        self.publish_hook = None;
     }
 
     /// Returns a reference to the value at `idx`.
@@ -146,20 +576,90 @@ impl<T> FastArena<T> {
     ///
     /// # Panics
     ///
-    /// Panics if `idx` is out of bounds.
+    /// Panics if `idx` is out of bounds, with "arena poisoned" if it lies
+    /// past a poisoned slot.
     #[must_use]
     pub fn get(&self, idx: Idx<T>) -> &T {
         let i = idx.into_raw();
         let published = self.published.load(Ordering::Acquire);
-        assert!(
-            i < published,
-            "index out of bounds: index is {i} but published length is {published}",
-        );
+        if i >= published {
+            self.index_out_of_bounds(i, published);
+        }
+        debug_assert!(self.flags.is_set(i), "published slot {i} is not ready");
         // SAFETY: i < published guarantees the slot is written and the
         // Acquire fence synchronizes with the writer's Release store.
         unsafe { &*self.data.add(i) }
     }
 
+    /// Returns a reference to the value at `idx`, blocking until it is
+    /// published.
+    ///
+    /// Lets a producer hand out an index before the value lands. Waits with
+    /// the same spin-yield-park backoff as writers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is beyond capacity, or with "arena poisoned" if it
+    /// lies past a [poisoned](FastArena#poisoning) slot.
+    #[must_use]
+    pub fn get_wait(&self, idx: Idx<T>) -> &T {
+        let _ = self.wait_published(idx.into_raw(), None);
+        self.get(idx)
+    }
+
+    /// Like [`get_wait`](FastArena::get_wait), but returns `None` if `idx`
+    /// is not published within `timeout`.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as `get_wait`.
+    #[must_use]
+    pub fn get_wait_timeout(&self, idx: Idx<T>, timeout: Duration) -> Option<&T> {
+        if self.wait_published(idx.into_raw(), Instant::now().checked_add(timeout)) {
+            Some(self.get(idx))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a future that resolves to the value at `idx` once it is
+    /// published.
+    ///
+    /// Runtime-agnostic: while the slot is pending, the task's waker is
+    /// registered with the arena and woken by the writer that publishes
+    /// it, so the future never blocks an executor thread or busy-polls.
+    /// Publication pays for this only while a future is waiting.
+    ///
+    /// # Panics
+    ///
+    /// Polling panics under the same conditions as
+    /// [`get_wait`](FastArena::get_wait).
+    pub const fn get_async(&self, idx: Idx<T>) -> GetAsync<'_, T> {
+        GetAsync::new(self, idx)
+    }
+
+    /// Waits until slot `i` is published. Returns `false` if `deadline`
+    /// passes first, and stops early if `i` can never be published.
+    fn wait_published(&self, i: usize, deadline: Option<Instant>) -> bool {
+        assert!(
+            i < self.cap,
+            "index {i} beyond capacity {} can never be published",
+            self.cap,
+        );
+        let mut backoff = Backoff::new();
+        loop {
+            if self.published.load(Ordering::Acquire) > i
+                || self.poisoned.load(Ordering::Acquire) <= i
+            {
+                return true;
+            }
+            if deadline.is_some_and(|d| Instant::now() >= d) {
+                return false;
+            }
+            backoff.snooze();
+        }
+    }
+
     /// Returns a mutable reference to the value at `idx`.
     ///
     /// # Panics
@@ -168,7 +668,7 @@ impl<T> FastArena<T> {
     #[must_use]
     pub fn get_mut(&mut self, idx: Idx<T>) -> &mut T {
         let i = idx.into_raw();
-        let published = *self.published.get_mut();
+        let published = self.published.read();
         assert!(
             i < published,
             "index out of bounds: index is {i} but published length is {published}",
@@ -189,12 +689,49 @@ impl<T> FastArena<T> {
         }
     }
 
+    /// Returns a reference to the value at `idx` as soon as its own slot is
+    /// written, without waiting for earlier slots to be published.
+    ///
+    /// Checks the slot's readiness bit instead of the in-order `published`
+    /// watermark, so a writer can read back its own allocation while slower
+    /// writers still hold earlier slots. Wait-free.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot at `idx` is not written.
+    #[must_use]
+    pub fn get_relaxed(&self, idx: Idx<T>) -> &T {
+        let i = idx.into_raw();
+        assert!(
+            i < self.cap && self.flags.is_set(i),
+            "slot {i} is not ready (capacity {})",
+            self.cap,
+        );
+        // SAFETY: same reasoning as try_get_relaxed().
+        unsafe { &*self.data.add(i) }
+    }
+
+    /// Returns a reference to the value at `idx` if its own slot is written,
+    /// or `None` otherwise. See [`get_relaxed`](FastArena::get_relaxed).
+    #[must_use]
+    pub fn try_get_relaxed(&self, idx: Idx<T>) -> Option<&T> {
+        let i = idx.into_raw();
+        if i < self.cap && self.flags.is_set(i) {
+            // SAFETY: the ready bit is set with Release after the slot is
+            // written; the Acquire load synchronizes with it. Bits are only
+            // cleared through &mut self.
+            Some(unsafe { &*self.data.add(i) })
+        } else {
+            None
+        }
+    }
+
     /// Returns a mutable reference to the value at `idx`, or `None` if
     /// out of bounds.
     #[must_use]
     pub fn try_get_mut(&mut self, idx: Idx<T>) -> Option<&mut T> {
         let i = idx.into_raw();
-        if i < *self.published.get_mut() {
+        if i < self.published.read() {
             // SAFETY: &mut self guarantees exclusive access. i < published.
             Some(unsafe { &mut *self.data.add(i) })
         } else {
@@ -202,6 +739,74 @@ impl<T> FastArena<T> {
         }
     }
 
+    /// Returns a reference to the value at `idx`, or an error describing
+    /// why it cannot be resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::StaleIndex`] if `idx` is not published, with the
+    /// published length at the time of the call.
+    pub fn try_index(&self, idx: Idx<T>) -> Result<&T, Error> {
+        let len = self.published.load(Ordering::Acquire);
+        if idx.into_raw() < len {
+            // SAFETY: the index is below a published length loaded with
+            // Acquire, same reasoning as get().
+            Ok(unsafe { &*self.data.add(idx.into_raw()) })
+        } else {
+            Err(Error::StaleIndex {
+                index: idx.into_raw(),
+                len,
+            })
+        }
+    }
+
+    /// Returns a mutable reference to the value at `idx`, or an error
+    /// describing why it cannot be resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::StaleIndex`] if `idx` is not published.
+    pub fn try_index_mut(&mut self, idx: Idx<T>) -> Result<&mut T, Error> {
+        let (index, len) = (idx.into_raw(), self.published.read());
+        self.try_get_mut(idx)
+            .ok_or(Error::StaleIndex { index, len })
+    }
+
+    /// Returns a reference to the value at `idx` without checking that it
+    /// is published.
+    ///
+    /// # Safety
+    ///
+    /// `idx` must be below the published length, and that publication must
+    /// be visible to the calling thread: it was observed through an
+    /// `Acquire` read such as [`len`](FastArena::len), `get`, or `as_slice`
+    /// on this thread, or the index comes from an `alloc` that happens-before
+    /// this call. An index made stale by rollback or reset is undefined
+    /// behavior.
+    #[must_use]
+    pub unsafe fn get_unchecked(&self, idx: Idx<T>) -> &T {
+        let i = idx.into_raw();
+        debug_assert!(i < self.published.load(Ordering::Acquire));
+        // SAFETY: the caller guarantees the slot is published and its
+        // write is visible to this thread.
+        unsafe { &*self.data.add(i) }
+    }
+
+    /// Returns a mutable reference to the value at `idx` without checking
+    /// that it is published.
+    ///
+    /// # Safety
+    ///
+    /// `idx` must be below the published length.
+    #[must_use]
+    pub unsafe fn get_unchecked_mut(&mut self, idx: Idx<T>) -> &mut T {
+        let i = idx.into_raw();
+        debug_assert!(i < self.published.read());
+        // SAFETY: &mut self guarantees exclusive access; i < published per
+        // the caller's contract.
+        unsafe { &mut *self.data.add(i) }
+    }
+
     /// Returns the number of published (visible) items.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -214,91 +819,503 @@ impl<T> FastArena<T> {
         self.len() == 0
     }
 
-    /// Returns the current capacity.
+    /// Returns the index the next [`alloc`](FastArena::alloc) will return.
+    ///
+    /// Only a prediction while other threads may allocate concurrently:
+    /// they can claim the slot first. Exact under `&mut self` or when
+    /// allocation is confined to a single thread.
     #[must_use]
-    pub const fn capacity(&self) -> usize {
-        self.cap
+    pub fn next_idx(&self) -> Idx<T> {
+        Idx::from_raw(self.cursor.load(Ordering::Relaxed))
     }
 
-    /// Returns `true` if `idx` points to a valid item.
+    /// Returns the index of the last published item, or `None` if the
+    /// arena is empty.
     #[must_use]
-    pub fn is_valid(&self, idx: Idx<T>) -> bool {
-        idx.into_raw() < self.published.load(Ordering::Acquire)
+    pub fn last_idx(&self) -> Option<Idx<T>> {
+        self.len().checked_sub(1).map(Idx::from_raw)
     }
 
-    /// Returns a contiguous slice of all published items.
+    /// Returns the current capacity.
     #[must_use]
-    pub fn as_slice(&self) -> &[T] {
-        let len = self.published.load(Ordering::Acquire);
-        if len == 0 {
-            return &[];
-        }
-        // SAFETY: data[0..len] are all written and published. Acquire
-        // fence synchronizes with writers.
-        unsafe { std::slice::from_raw_parts(self.data, len) }
+    pub const fn capacity(&self) -> usize {
+        self.cap
     }
 
-    /// Returns a mutable slice of all published items.
+    /// Returns the number of reserved slots, including slots claimed by
+    /// in-flight allocations that are not yet published.
+    ///
+    /// Always `>= len()` and `<= capacity()`.
     #[must_use]
-    pub fn as_mut_slice(&mut self) -> &mut [T] {
-        let len = *self.published.get_mut();
-        if len == 0 {
-            return &mut [];
-        }
-        // SAFETY: &mut self guarantees exclusive access.
-        unsafe { std::slice::from_raw_parts_mut(self.data, len) }
+    pub fn reserved_len(&self) -> usize {
+        self.cursor.load(Ordering::Relaxed).min(self.cap)
     }
 
-    /// Saves the current allocation state.
+    /// Returns the number of slots still available before the arena is
+    /// full.
+    ///
+    /// Under concurrent allocation this is a lower bound on how close the
+    /// arena is to the "arena full" panic, not a reservation.
     #[must_use]
-    pub fn checkpoint(&self) -> Checkpoint<T> {
-        Checkpoint::from_len(self.published.load(Ordering::Acquire))
+    pub fn remaining_capacity(&self) -> usize {
+        self.cap - self.reserved_len()
     }
 
-    /// Rolls back to a previous checkpoint, dropping all values
-    /// allocated after it.
+    /// Returns `true` if every slot has been reserved, so the next
+    /// [`alloc`](FastArena::alloc) would panic.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.remaining_capacity() == 0
+    }
+
+    /// Blocks until every slot reserved so far is published.
     ///
-    /// O(k) where k = number of items dropped.
+    /// Afterwards [`as_slice`](FastArena::as_slice) covers all allocations
+    /// that started before the call. Allocations that start concurrently may
+    /// still be in flight; the caller is responsible for stopping writers if
+    /// it needs a stable end. Waits with the same spin-yield-park backoff as
+    /// writers.
     ///
     /// # Panics
     ///
-    /// Panics if `cp` points beyond the current length.
-    pub fn rollback(&mut self, cp: Checkpoint<T>) {
-        let current = *self.published.get_mut();
-        assert!(
-            cp.len() <= current,
-            "checkpoint {} beyond current length {current}",
-            cp.len(),
-        );
-        for slot in (cp.len()..current).rev() {
-            // SAFETY: slot < current = published, so the value is written.
-            // &mut self guarantees exclusive access.
-            unsafe {
-                self.data.add(slot).drop_in_place();
-                (*self.flags.add(slot)).store(false, Ordering::Relaxed);
-            }
+    /// Panics if the arena is [poisoned](FastArena#poisoning), since the
+    /// reserved slots can then never all be published.
+    pub fn quiesce(&self) {
+        if !self.wait_quiesced(None) {
+            let poisoned = self.poisoned.load(Ordering::Acquire);
+            panic!("arena poisoned at slot {poisoned}: cannot quiesce");
         }
-        *self.published.get_mut() = cp.len();
-        *self.cursor.get_mut() = cp.len();
     }
 
-    /// Removes all items, running their destructors.
+    /// Like [`quiesce`](FastArena::quiesce), but gives up after `timeout`.
     ///
-    /// Retains allocated storage for reuse.
+    /// Returns `true` if every reserved slot was published in time, and
+    /// `false` on timeout or if the arena is
+    /// [poisoned](FastArena#poisoning).
+    #[must_use]
+    pub fn try_quiesce(&self, timeout: Duration) -> bool {
+        self.wait_quiesced(Instant::now().checked_add(timeout))
+    }
+
+    /// Waits until `published` catches up with the length reserved at entry.
+    /// Returns `false` if the arena is poisoned or `deadline` passes first.
+    fn wait_quiesced(&self, deadline: Option<Instant>) -> bool {
+        let target = self.reserved_len();
+        let mut backoff = Backoff::new();
+        loop {
+            if self.published.load(Ordering::Acquire) >= target {
+                return true;
+            }
+            if self.is_poisoned() || deadline.is_some_and(|d| Instant::now() >= d) {
+                return false;
+            }
+            backoff.snooze();
+        }
+    }
+
+    /// Returns the items in `range`, e.g. `arena.slice(a..b)` for a block
+    /// of values allocated one after another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or `start > end`.
+    #[must_use]
+    pub fn slice(&self, range: impl Into<IdxRange<T>>) -> &[T] {
+        &self.as_slice()[range.into().into_raw()]
+    }
+
+    /// Returns the items in `range` mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or `start > end`.
+    #[must_use]
+    pub fn slice_mut(&mut self, range: impl Into<IdxRange<T>>) -> &mut [T] {
+        &mut self.as_mut_slice()[range.into().into_raw()]
+    }
+
+    /// Splits the published items at `idx` into the items before it and
+    /// the items from it on, both mutable.
+    ///
+    /// See [`Arena::split_at_mut`](crate::Arena::split_at_mut).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is beyond the published length.
+    #[must_use]
+    pub fn split_at_mut(&mut self, idx: Idx<T>) -> (&mut [T], &mut [T]) {
+        self.as_mut_slice().split_at_mut(idx.into_raw())
+    }
+
+    /// Returns the items in `range`, or `None` if it is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end`.
+    #[must_use]
+    pub fn try_slice(&self, range: impl Into<IdxRange<T>>) -> Option<&[T]> {
+        self.as_slice().get(range.into().into_raw())
+    }
+
+    /// Returns the published items from `start` up to but excluding `end`,
+    /// or `None` unless `start <= end <= len`.
+    ///
+    /// Never panics, like [`Arena::get_span`](crate::Arena::get_span).
+    #[must_use]
+    pub fn get_span(&self, start: Idx<T>, end: Idx<T>) -> Option<&[T]> {
+        self.as_slice().get(start.into_raw()..end.into_raw())
+    }
+
+    /// Returns `true` if `idx` points to a valid item.
+    #[must_use]
+    pub fn is_valid(&self, idx: Idx<T>) -> bool {
+        idx.into_raw() < self.published.load(Ordering::Acquire)
+    }
+
+    /// Returns a snapshot of the items published so far.
+    ///
+    /// The view captures `published` once, so its length stays fixed for a
+    /// whole pass while writers keep allocating.
+    #[must_use]
+    pub fn view(&self) -> ArenaView<'_, T> {
+        ArenaView::new(self.as_slice())
+    }
+
+    /// Returns a contiguous slice of all published items.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        let len = self.published.load(Ordering::Acquire);
+        if len == 0 {
+            return &[];
+        }
+        // SAFETY: data[0..len] are all written and published. Acquire
+        // fence synchronizes with writers.
+        unsafe { std::slice::from_raw_parts(self.data, len) }
+    }
+
+    /// Returns a mutable slice of all published items.
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.published.read();
+        if len == 0 {
+            return &mut [];
+        }
+        // SAFETY: &mut self guarantees exclusive access.
+        unsafe { std::slice::from_raw_parts_mut(self.data, len) }
+    }
+
+    /// Saves the current allocation state.
+    ///
+    /// The checkpoint covers exactly the published prefix: every allocation
+    /// whose `alloc` call returned before this call, on any thread. An
+    /// allocation still in flight may land on either side, and
+    /// [`rollback`](FastArena::rollback) drops it if it lands after. Use
+    /// [`checkpoint_quiesced`](FastArena::checkpoint_quiesced) to cover
+    /// every allocation that started before the call.
+    #[must_use]
+    pub fn checkpoint(&self) -> Checkpoint<T> {
+        Checkpoint::from_len(self.published.load(Ordering::Acquire))
+    }
+
+    /// Waits until every slot reserved so far is published, then saves the
+    /// allocation state.
+    ///
+    /// The checkpoint covers every allocation that started before the
+    /// call, as with [`quiesce`](FastArena::quiesce). Allocations starting
+    /// concurrently may also be covered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is [poisoned](FastArena#poisoning).
+    #[must_use]
+    pub fn checkpoint_quiesced(&self) -> Checkpoint<T> {
+        self.quiesce();
+        self.checkpoint()
+    }
+
+    /// Like [`checkpoint_quiesced`](FastArena::checkpoint_quiesced), but
+    /// gives up after `timeout`.
+    ///
+    /// Returns `None` on timeout or if the arena is
+    /// [poisoned](FastArena#poisoning).
+    #[must_use]
+    pub fn try_checkpoint_quiesced(&self, timeout: Duration) -> Option<Checkpoint<T>> {
+        self.try_quiesce(timeout).then(|| self.checkpoint())
+    }
+
+    /// Rolls back to a previous checkpoint, dropping all values
+    /// allocated after it.
+    ///
+    /// O(k) where k = number of items dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cp` points beyond the current length.
+    pub fn rollback(&mut self, cp: Checkpoint<T>) {
+        if let Err(err) = self.try_rollback(cp) {
+            panic!("{err}");
+        }
+    }
+
+    /// Rolls back to `cp` like [`rollback`](FastArena::rollback), returning
+    /// an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CheckpointBeyondLen`] if `cp` points beyond the
+    /// current length; the arena is left unchanged.
+    pub fn try_rollback(&mut self, cp: Checkpoint<T>) -> Result<(), Error> {
+        let current = self.published.read();
+        if cp.len() > current {
+            return Err(Error::CheckpointBeyondLen {
+                checkpoint: cp.len(),
+                len: current,
+            });
+        }
+        if let Some(observer) = &self.observer {
+            observer.on_rollback(current, cp.len());
+        }
+        trace::rollback::<T>(self.addr(), current, cp.len());
+        self.truncate_slots(cp.len());
+        self.stats.rollback(current, cp.len());
+        Ok(())
+    }
+
+    /// Rolls back to `cp` like [`rollback`](FastArena::rollback), first
+    /// passing each value to be dropped to `f`, in allocation order.
+    ///
+    /// Lets the caller log, recycle resources, or detach external state
+    /// with access to its own context, which `Drop` does not have. Values
+    /// stranded past a [poisoned](FastArena#poisoning) slot are passed too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cp` points beyond the current length.
+    pub fn rollback_with(&mut self, cp: Checkpoint<T>, f: impl FnMut(&mut T)) {
+        if cp.len() <= self.published.read() {
+            self.for_each_written_from(cp.len(), f);
+        }
+        self.rollback(cp);
+    }
+
+    /// Removes all items like [`reset`](FastArena::reset), first passing
+    /// each to `f`, in allocation order.
+    pub fn reset_with(&mut self, f: impl FnMut(&mut T)) {
+        self.for_each_written_from(0, f);
+        self.reset();
+    }
+
+    /// Shortens the arena to `len` items like
+    /// [`rollback`](FastArena::rollback), for callers that kept a raw
+    /// length instead of a [`Checkpoint`].
+    ///
+    /// No-op if `len` is not below the published length.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.published.read() {
+            self.rollback(Checkpoint::from_len(len));
+        }
+    }
+
+    /// Drops the item at `idx` and every item allocated after it, as a
+    /// rollback to just before `idx` was allocated.
+    ///
+    /// No-op if `idx` is not [valid](FastArena::is_valid).
+    #[doc(alias = "reset_from")]
+    pub fn truncate_to(&mut self, idx: Idx<T>) {
+        self.truncate(idx.into_raw());
+    }
+
+    /// Calls `f` on every written slot at or after `len`, including slots
+    /// written past a poisoned slot.
+    fn for_each_written_from(&mut self, len: usize, mut f: impl FnMut(&mut T)) {
+        let reserved = self.cursor.read().min(self.cap);
+        for slot in len..reserved {
+            if self.flags.is_set(slot) {
+                // SAFETY: the ready bit is set only after the slot was
+                // written. &mut self guarantees exclusive access.
+                f(unsafe { &mut *self.data.add(slot) });
+            }
+        }
+    }
+
+    /// Removes all items, running their destructors.
+    ///
+    /// Retains allocated storage for reuse.
     pub fn reset(&mut self) {
-        let current = *self.published.get_mut();
-        for slot in (0..current).rev() {
-            // SAFETY: slot < published. &mut self guarantees exclusive access.
-            unsafe {
-                self.data.add(slot).drop_in_place();
-                (*self.flags.add(slot)).store(false, Ordering::Relaxed);
+        self.stats.reset();
+        let len = self.published.read();
+        if let Some(observer) = &self.observer {
+            observer.on_reset(len);
+        }
+        trace::reset::<T>(self.addr(), len, self.cap);
+        self.truncate_slots(0);
+    }
+
+    /// Starts or stops collecting [`RollbackStats`](crate::RollbackStats)
+    /// into [`stats`](FastArena::stats); stopping discards them.
+    ///
+    /// Off by default. Only `&mut self` operations do extra work.
+    pub fn set_rollback_stats(&mut self, enabled: bool) {
+        self.stats.set_rollback_stats(enabled);
+    }
+
+    /// Returns allocation statistics over the arena's lifetime.
+    ///
+    /// Counting costs nothing on the allocation path: the counters are only
+    /// touched by `&mut self` operations.
+    #[must_use]
+    pub fn stats(&self) -> ArenaStats {
+        self.stats.snapshot::<T>(
+            self.published.load(Ordering::Acquire),
+            self.cap,
+            self.memory_usage(),
+        )
+    }
+
+    /// Returns the heap memory held by the arena, in bytes: the value
+    /// storage (`capacity * size_of::<T>()`) plus the readiness bits.
+    ///
+    /// Does not include memory owned by the values themselves.
+    #[must_use]
+    pub const fn memory_usage(&self) -> usize {
+        self.cap * size_of::<T>() + self.flags.heap_bytes()
+    }
+
+    /// Returns `true` if an abandoned slot has stopped publication.
+    ///
+    /// See [Poisoning](FastArena#poisoning).
+    #[must_use]
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire) != NO_POISON
+    }
+
+    /// Returns the lowest abandoned slot, or `None` if the arena is not
+    /// poisoned.
+    #[must_use]
+    pub fn poisoned_at(&self) -> Option<Idx<T>> {
+        match self.poisoned.load(Ordering::Acquire) {
+            NO_POISON => None,
+            slot => Some(Idx::from_raw(slot)),
+        }
+    }
+
+    /// Gives an unwritten reservation `from..end` back, or poisons `from`
+    /// if later slots were already claimed.
+    pub(crate) fn abandon_block(&self, from: usize, end: usize) {
+        if from < end && !self.release_block(from, end) {
+            self.poisoned.fetch_min(from, Ordering::SeqCst);
+            self.waiters.wake(usize::MAX);
+        }
+    }
+
+    /// Drops every written slot at or after `len` in the arena's
+    /// [`DropOrder`] and resets the allocation state to `len`, clearing any
+    /// poison.
+    ///
+    /// Covers published slots as well as slots written past a poisoned
+    /// slot, which were never published.
+    fn truncate_slots(&mut self, len: usize) {
+        self.stats.shrink(self.published.read(), len);
+        let reserved = self.cursor.read().min(self.cap);
+        let drop_slot = |slot: usize| {
+            if self.flags.is_set(slot) {
+                // SAFETY: the ready bit is set only after the slot was
+                // written. &mut self guarantees exclusive access.
+                unsafe { self.data.add(slot).drop_in_place() };
             }
+        };
+        match self.drop_order {
+            DropOrder::Forward => (len..reserved).for_each(drop_slot),
+            DropOrder::Reverse => (len..reserved).rev().for_each(drop_slot),
         }
-        *self.published.get_mut() = 0;
-        *self.cursor.get_mut() = 0;
+        self.scrub_slots(len, reserved);
+        self.flags.clear_from(len);
+        self.published.write(len);
+        self.cursor.write(len);
+        self.poisoned.write(NO_POISON);
+        self.waiters.clear();
     }
 
-    /// Doubles the arena capacity.
+    /// Returns the error for an allocation past the current capacity.
+    fn capacity_error(&self) -> Error {
+        if self.cap >= self.max_items {
+            Error::Limit {
+                limit: self.limit.unwrap_or(ArenaLimit::Items(self.max_items)),
+            }
+        } else {
+            Error::Full { capacity: self.cap }
+        }
+    }
+
+    /// Gives back a reservation of `n` slots at `start` that does not fit.
+    ///
+    /// If a later reservation was made meanwhile, the cursor cannot move
+    /// back, and the part of the block below capacity would stay reserved
+    /// but never written, stalling publication for good. That part is
+    /// abandoned instead, which poisons the arena.
+    #[cold]
+    fn return_overflow(&self, start: usize, n: usize) {
+        if !self.release_block(start, start.saturating_add(n)) {
+            self.abandon_block(start.min(self.cap), self.cap);
+        }
+    }
+
+    /// Panics for a reservation of `n` slots at `start` that did not fit
+    /// and was given back with [`return_overflow`](Self::return_overflow).
+    #[cold]
+    fn capacity_exceeded(&self, start: usize, n: usize) -> ! {
+        let end = start.saturating_add(n);
+        trace::full::<T>(self.addr(), start, n, self.cap);
+        match self.capacity_error() {
+            Error::Full { capacity } if n == 1 => {
+                panic!("arena full: slot {start} >= capacity {capacity}")
+            }
+            Error::Full { capacity } => {
+                panic!("arena full: slots {start}..{end} exceed capacity {capacity}")
+            }
+            limit => panic!("{limit}"),
+        }
+    }
+
+    /// Scrubs the memory of slots `from..to`, which must hold no live
+    /// values: a zeroizing arena zeroes them, other arenas poison them in
+    /// debug builds.
+    fn scrub_slots(&mut self, from: usize, to: usize) {
+        if from < to {
+            // SAFETY: from < to <= cap; &mut self guarantees exclusive
+            // access, and MaybeUninit<T> has the same layout as T.
+            let slots = unsafe {
+                std::slice::from_raw_parts_mut(
+                    self.data.add(from).cast::<MaybeUninit<T>>(),
+                    to - from,
+                )
+            };
+            if cfg!(feature = "zeroize") && self.zeroize {
+                crate::scrub::zero(slots);
+            } else {
+                crate::scrub::poison(slots);
+            }
+        }
+    }
+
+    /// Address identifying this arena in trace events.
+    fn addr(&self) -> usize {
+        std::ptr::from_ref(self).addr()
+    }
+
+    /// Panics for an index at or past `published`, naming the poisoned
+    /// slot if that is why the index is unreachable.
+    fn index_out_of_bounds(&self, i: usize, published: usize) -> ! {
+        let poisoned = self.poisoned.load(Ordering::Acquire);
+        assert!(
+            poisoned > i,
+            "arena poisoned at slot {poisoned}: index {i} is unreachable"
+        );
+        panic!("index out of bounds: index is {i} but published length is {published}");
+    }
+
+    /// Grows the arena per its [growth policy](FastArena::set_growth_policy)
+    /// (doubling by default), up to its [limit](FastArena::with_limit).
     ///
     /// Requires `&mut self` — no concurrent readers or writers.
     /// Existing indices remain valid.
@@ -307,42 +1324,161 @@ impl<T> FastArena<T> {
     ///
     /// Panics if the new capacity overflows `usize`.
     pub fn grow(&mut self) {
-        let new_cap = self.cap.checked_mul(2).expect("capacity overflow");
-        self.grow_to(new_cap);
+        if let Err(err) = self.try_grow() {
+            err.handle();
+        }
+    }
+
+    /// Grows the arena like [`grow`](FastArena::grow), returning an error
+    /// instead of panicking or aborting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaAllocError`] if the new capacity overflows or the
+    /// allocator fails. The arena is left unchanged.
+    pub fn try_grow(&mut self) -> Result<(), ArenaAllocError> {
+        let new_cap = self
+            .growth
+            .next_capacity(self.cap, self.cap.saturating_add(1));
+        self.try_grow_to(new_cap)
+    }
+
+    /// Grows the capacity by exactly `additional` slots, up to the arena's
+    /// [limit](FastArena::with_limit), regardless of the growth policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    pub fn grow_by(&mut self, additional: usize) {
+        self.grow_to(self.cap.saturating_add(additional));
+    }
+
+    /// Returns the policy used by [`grow`](FastArena::grow).
+    #[must_use]
+    pub const fn growth_policy(&self) -> GrowthPolicy {
+        self.growth
+    }
+
+    /// Returns the order in which discarded values are dropped.
+    #[must_use]
+    pub const fn drop_order(&self) -> DropOrder {
+        self.drop_order
+    }
+
+    /// Sets the order in which `rollback`, `reset` and drop run
+    /// destructors. The default is [`DropOrder::Reverse`], newest first.
+    pub const fn set_drop_order(&mut self, order: DropOrder) {
+        self.drop_order = order;
+    }
+
+    /// Sets how [`grow`](FastArena::grow) picks the new capacity. The
+    /// default is [`GrowthPolicy::Double`].
+    pub const fn set_growth_policy(&mut self, policy: GrowthPolicy) {
+        self.growth = policy;
     }
 
     /// Grows the arena to at least `min_capacity`.
     ///
-    /// No-op if current capacity is already sufficient.
+    /// No-op if current capacity is already sufficient. Never grows past
+    /// the arena's [limit](FastArena::with_limit).
     pub fn grow_to(&mut self, min_capacity: usize) {
-        if min_capacity <= self.cap {
-            return;
+        if let Err(err) = self.try_grow_to(min_capacity) {
+            err.handle();
         }
+    }
 
-        let published = *self.published.get_mut();
-        let (new_data, new_flags) = alloc_storage::<T>(min_capacity);
+    /// Makes room for at least `additional` more values past the reserved
+    /// slots and returns the new capacity.
+    ///
+    /// Grows to exactly the needed capacity, ignoring the growth policy,
+    /// and is a no-op if there is room already. Never grows past the
+    /// arena's [limit](FastArena::with_limit), so compare the result with
+    /// [`reserved_len`](FastArena::reserved_len) to see whether
+    /// `additional` allocations can still hit "arena full".
+    ///
+    /// # Panics
+    ///
+    /// Panics if the needed capacity overflows `usize`.
+    #[doc(alias = "reserve_exact")]
+    pub fn ensure_capacity(&mut self, additional: usize) -> usize {
+        if let Err(err) = self.try_reserve(additional) {
+            err.handle();
+        }
+        self.cap
+    }
 
-        // SAFETY: copy published items to new storage.
-        // &mut self guarantees no concurrent access.
-        unsafe {
-            std::ptr::copy_nonoverlapping(self.data, new_data, published);
-            // Copy flag states
-            for i in 0..published {
-                let flag_val = (*self.flags.add(i)).load(Ordering::Relaxed);
-                (*new_flags.add(i)).store(flag_val, Ordering::Relaxed);
-            }
-            // Deallocate old storage WITHOUT dropping values (they were moved).
-            dealloc_storage(self.data, self.flags, self.cap);
+    /// Makes room for at least `additional` more values past the reserved
+    /// slots, growing to exactly the needed capacity.
+    ///
+    /// Never grows past the arena's [limit](FastArena::with_limit).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaAllocError`] if the capacity overflows or the
+    /// allocator fails. The arena is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), ArenaAllocError> {
+        let needed = self
+            .cursor
+            .read()
+            .min(self.cap)
+            .checked_add(additional)
+            .ok_or(ArenaAllocError::CapacityOverflow)?;
+        self.try_grow_to(needed)
+    }
+
+    /// Grows the arena to at least `min_capacity` like
+    /// [`grow_to`](FastArena::grow_to), returning an error instead of
+    /// panicking or aborting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaAllocError`] if the capacity overflows or the
+    /// allocator fails. The arena is left unchanged.
+    pub fn try_grow_to(&mut self, min_capacity: usize) -> Result<(), ArenaAllocError> {
+        let min_capacity = min_capacity.min(self.max_items);
+        if min_capacity <= self.cap {
+            return Ok(());
+        }
+
+        let new_data = alloc_storage::<T>(min_capacity, self.align)?;
+        if let Err(err) = self.flags.try_grow(min_capacity) {
+            // SAFETY: new_data was just allocated for min_capacity and
+            // holds no values.
+            unsafe { dealloc_storage(new_data, min_capacity, self.align) };
+            return Err(err);
         }
 
+        self.stats.grow();
+        // Reservations past the old capacity failed and were never written.
+        let reserved = self.cursor.read().min(self.cap);
+        self.cursor.write(reserved);
+
+        // SAFETY: copy reserved slots (written or not) to new storage as raw
+        // memory. &mut self guarantees no concurrent access.
+        unsafe { std::ptr::copy_nonoverlapping(self.data, new_data, reserved) };
+        self.scrub_slots(0, reserved);
+        // SAFETY: deallocate old storage WITHOUT dropping values (they were
+        // moved).
+        unsafe { dealloc_storage(self.data, self.cap, self.align) };
+
         self.data = new_data;
-        self.flags = new_flags;
+        if let Some(observer) = &self.observer {
+            observer.on_grow(self.cap, min_capacity);
+        }
+        trace::grow::<T>(self.addr(), self.cap, min_capacity);
         self.cap = min_capacity;
+        Ok(())
     }
 
     /// Returns an iterator over all published items.
-    pub fn iter(&self) -> std::slice::Iter<'_, T> {
-        self.as_slice().iter()
+    ///
+    /// Safe to use while other threads allocate: it yields exactly the
+    /// items published when it was created, which never move under
+    /// `&self`.
+    pub fn iter(&self) -> FastArenaIter<'_, T> {
+        FastArenaIter {
+            inner: self.as_slice().iter(),
+        }
     }
 
     /// Returns a mutable iterator over all published items.
@@ -361,34 +1497,363 @@ impl<T> FastArena<T> {
         crate::IterIndexedMut::new(self.as_mut_slice().iter_mut().enumerate())
     }
 
-    /// Allocates multiple values from an iterator, returning the index
-    /// of the first item.
+    /// Returns an iterator over the indices of the items published so far.
     ///
-    /// Returns `None` if the iterator is empty.
-    pub fn alloc_extend(&self, iter: impl IntoIterator<Item = T>) -> Option<Idx<T>> {
-        let mut first = None;
-        for value in iter {
-            let idx = self.alloc(value);
-            if first.is_none() {
-                first = Some(idx);
-            }
+    /// The iterator does not borrow the arena; later allocations are not
+    /// included.
+    #[must_use]
+    pub fn keys(&self) -> crate::IdxIter<T> {
+        IdxRange::from_raw(0, self.len()).into_iter()
+    }
+
+    /// Returns the index of `value` if it refers to a published item in
+    /// this arena.
+    ///
+    /// O(1): computed from the address of `value`. Returns `None` for
+    /// references outside the arena and for zero-sized `T`.
+    #[must_use]
+    pub fn idx_of(&self, value: &T) -> Option<Idx<T>> {
+        crate::idx::idx_of_in(self.as_slice(), value)
+    }
+
+    /// Binary searches the items with a comparator function.
+    ///
+    /// The items must be sorted with respect to `f`, e.g. because they were
+    /// allocated in order. See [`slice::binary_search_by`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the index where a matching item could be inserted
+    /// if no item matches.
+    pub fn binary_search_by(
+        &self,
+        f: impl FnMut(&T) -> std::cmp::Ordering,
+    ) -> Result<Idx<T>, Idx<T>> {
+        self.as_slice()
+            .binary_search_by(f)
+            .map(Idx::from_raw)
+            .map_err(Idx::from_raw)
+    }
+
+    /// Binary searches the items with a key extraction function.
+    ///
+    /// Same contract as [`binary_search_by`](Self::binary_search_by). See
+    /// [`slice::binary_search_by_key`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the insertion index if no item matches `key`.
+    pub fn binary_search_by_key<B: Ord>(
+        &self,
+        key: &B,
+        f: impl FnMut(&T) -> B,
+    ) -> Result<Idx<T>, Idx<T>> {
+        self.as_slice()
+            .binary_search_by_key(key, f)
+            .map(Idx::from_raw)
+            .map_err(Idx::from_raw)
+    }
+
+    /// Returns the index of the first published item matching `pred`.
+    pub fn find_idx(&self, pred: impl FnMut(&T) -> bool) -> Option<Idx<T>> {
+        self.as_slice().iter().position(pred).map(Idx::from_raw)
+    }
+
+    /// Returns the index of the last published item matching `pred`.
+    pub fn rfind_idx(&self, pred: impl FnMut(&T) -> bool) -> Option<Idx<T>> {
+        self.as_slice().iter().rposition(pred).map(Idx::from_raw)
+    }
+
+    /// Returns `true` if a published item equals `value`. O(n).
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(value)
+    }
+
+    /// Returns the index of the first published item equal to `value`.
+    /// O(n).
+    #[must_use]
+    pub fn idx_of_value(&self, value: &T) -> Option<Idx<T>>
+    where
+        T: PartialEq,
+    {
+        self.find_idx(|item| item == value)
+    }
+
+    /// Allocates multiple values from an iterator as one contiguous block,
+    /// returning the range of their indices.
+    ///
+    /// The whole block is reserved with a single atomic operation, so
+    /// concurrent `alloc_extend` calls never interleave their items.
+    /// Returns an empty range if the iterator is empty.
+    ///
+    /// If the iterator reports an exact length, as an [`ExactSizeIterator`]
+    /// does, the block is reserved up front and the values are written
+    /// straight into it, as through [`reserve_slots`](FastArena::reserve_slots).
+    /// An iterator that then yields fewer values, or panics, leaves the
+    /// block short like an unfinished [`SlotWriter`]. Other iterators are
+    /// collected into a `Vec` first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit in the remaining capacity. At most
+    /// one value past the remaining capacity is taken from `iter`, and
+    /// none if its exact length already does not fit.
+    pub fn alloc_extend(&self, iter: impl IntoIterator<Item = T>) -> IdxRange<T> {
+        let iter = iter.into_iter();
+        if let (n @ 1.., Some(upper)) = iter.size_hint()
+            && n == upper
+        {
+            let mut block = self.reserve_slots(n);
+            iter.take(n).for_each(|value| {
+                block.write(value);
+            });
+            return block.finish();
         }
-        first
+        let mut items: Vec<T> = iter.take(self.collect_budget()).collect();
+        let n = items.len();
+        if n == 0 {
+            let at = self.reserved_len();
+            return IdxRange::from_raw(at, at);
+        }
+        let start = self.reserve_block(n);
+        // SAFETY: slots start..start + n are exclusively reserved and lie
+        // within capacity. Ownership of the values moves into the arena;
+        // set_len(0) keeps the Vec from dropping them.
+        unsafe {
+            std::ptr::copy_nonoverlapping(items.as_ptr(), self.data.add(start), n);
+            items.set_len(0);
+        }
+        self.publish_block(start, n);
+        IdxRange::from_raw(start, start + n)
+    }
+
+    /// Allocates values from an iterator of results like
+    /// [`alloc_extend`](FastArena::alloc_extend), or returns the first
+    /// error with nothing allocated.
+    ///
+    /// The values are collected before the block is reserved, so an error
+    /// never leaves part of them in the arena.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error yielded by the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit in the remaining capacity. At most
+    /// one value past the remaining capacity is taken from `iter`, so an
+    /// error after that is not seen.
+    pub fn alloc_extend_try<E>(
+        &self,
+        iter: impl IntoIterator<Item = Result<T, E>>,
+    ) -> Result<IdxRange<T>, E> {
+        let items = iter
+            .into_iter()
+            .take(self.collect_budget())
+            .collect::<Result<Vec<T>, E>>()?;
+        Ok(self.alloc_extend(items))
+    }
+
+    /// Number of values to collect for one block: one past the remaining
+    /// capacity is enough to know the block cannot fit.
+    fn collect_budget(&self) -> usize {
+        self.remaining_capacity().saturating_add(1)
+    }
+
+    /// Copies `values` into one contiguous block, returning the range of
+    /// their indices.
+    ///
+    /// Like [`alloc_extend`](FastArena::alloc_extend), but the length is
+    /// known up front: the block is reserved with a single atomic operation
+    /// and filled with one `memcpy`, with no intermediate `Vec`.
+    ///
+    /// ```
+    /// use fast_bump::FastArena;
+    ///
+    /// let arena = FastArena::with_capacity(8);
+    /// let block = arena.alloc_copy_slice(&[1, 2, 3]);
+    /// assert_eq!(arena[block], [1, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit in the remaining capacity.
+    pub fn alloc_copy_slice(&self, values: &[T]) -> IdxRange<T>
+    where
+        T: Copy,
+    {
+        let n = values.len();
+        if n == 0 {
+            let at = self.reserved_len();
+            return IdxRange::from_raw(at, at);
+        }
+        let start = self.reserve_block(n);
+        // SAFETY: slots start..start + n are exclusively reserved and lie
+        // within capacity; T: Copy, so duplicating the values is sound.
+        unsafe { std::ptr::copy_nonoverlapping(values.as_ptr(), self.data.add(start), n) };
+        self.publish_block(start, n);
+        IdxRange::from_raw(start, start + n)
+    }
+
+    /// Reserves `n` contiguous slots with a single atomic operation,
+    /// returning a [`SlotWriter`] that fills them one by one.
+    ///
+    /// Use this for chunked production: one contended atomic per block
+    /// instead of one per item. The written values are published together
+    /// when the writer is finished or dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit in the remaining capacity.
+    pub fn reserve_slots(&self, n: usize) -> SlotWriter<'_, T> {
+        let start = if n == 0 {
+            self.reserved_len()
+        } else {
+            self.reserve_block(n)
+        };
+        SlotWriter::new(self, start, start + n)
+    }
+
+    /// Returns a [`LocalWriter`] that reserves [`DEFAULT_BATCH`] slots at a
+    /// time and hands them out without touching the shared cursor.
+    ///
+    /// [`DEFAULT_BATCH`]: LocalWriter::DEFAULT_BATCH
+    #[must_use]
+    pub const fn writer(&self) -> LocalWriter<'_, T> {
+        self.writer_with_batch(LocalWriter::<T>::DEFAULT_BATCH)
+    }
+
+    /// Returns a [`LocalWriter`] that reserves `batch` slots at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch` is zero.
+    #[must_use]
+    pub const fn writer_with_batch(&self, batch: usize) -> LocalWriter<'_, T> {
+        assert!(batch > 0, "batch size must be non-zero");
+        LocalWriter::new(self, batch)
+    }
+
+    /// Atomically reserves `n` contiguous slots, returning the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit in the remaining capacity.
+    fn reserve_block(&self, n: usize) -> usize {
+        self.try_reserve_block(n)
+            .unwrap_or_else(|start| self.capacity_exceeded(start, n))
+    }
+
+    /// Atomically reserves `n` contiguous slots, returning the first, or
+    /// gives the block back and returns where it would have started.
+    pub(crate) fn try_reserve_block(&self, n: usize) -> Result<usize, usize> {
+        let start = self.cursor.fetch_add(n, Ordering::Relaxed);
+        if start.checked_add(n).is_none_or(|end| end > self.cap) {
+            self.return_overflow(start, n);
+            return Err(start);
+        }
+        Ok(start)
+    }
+
+    /// Returns the unwritten tail `from..end` of a reservation to the
+    /// arena. Succeeds only if no later reservation was made.
+    pub(crate) fn release_block(&self, from: usize, end: usize) -> bool {
+        self.cursor
+            .compare_exchange(end, from, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    /// Returns a pointer to the storage of `slot`.
+    ///
+    /// # Safety
+    ///
+    /// `slot` must be below capacity.
+    pub(crate) unsafe fn slot_ptr(&self, slot: usize) -> *mut T {
+        debug_assert!(slot < self.cap);
+        // SAFETY: slot < cap per the caller's contract.
+        unsafe { self.data.add(slot) }
+    }
+
+    /// Marks the written slots `start..start + n` ready and publishes them,
+    /// a flag word and a single CAS at a time rather than per slot.
+    pub(crate) fn publish_block(&self, start: usize, n: usize) {
+        self.flags.set_range(start, start + n);
+        self.advance_published(start, start + n);
+        if let Some(observer) = &self.observer {
+            observer.on_alloc(start, n);
+        }
+    }
+
+    /// Converts the arena into an immutable [`FrozenArena`].
+    ///
+    /// Existing indices stay valid. The readiness flags and unused capacity
+    /// are released.
+    #[must_use]
+    pub fn freeze(self) -> FrozenArena<T> {
+        FrozenArena::from_vec(self.into_vec())
+    }
+
+    /// Converts the arena into a shared slice of its published items, for
+    /// the phase where only reads remain.
+    ///
+    /// Existing indices stay valid as positions in the slice. `Arc` keeps
+    /// its counters in front of the items, so they are moved once with a
+    /// single bulk copy; use [`into_vec`](FastArena::into_vec) or
+    /// [`freeze`](FastArena::freeze) to keep the storage in place.
+    #[must_use]
+    pub fn into_arc_slice(self) -> std::sync::Arc<[T]> {
+        self.into_vec().into()
+    }
+
+    /// Converts the arena into a `Vec<T>` of its published items, reusing
+    /// the storage without copying.
+    ///
+    /// Existing indices stay valid as positions in the vector. Values
+    /// stranded past a [poisoned](FastArena#poisoning) slot are dropped.
+    /// The storage of a zeroizing arena is handed over unscrubbed. An
+    /// [over-aligned](FastArena::with_capacity_aligned) arena cannot hand
+    /// its storage to a `Vec` and moves the items instead.
+    #[must_use]
+    pub fn into_vec(mut self) -> Vec<T> {
+        if self.align != std::mem::align_of::<T>() {
+            return self.drain().collect();
+        }
+        let len = self.published.read();
+        self.truncate_slots(len);
+        let data = std::mem::replace(&mut self.data, std::ptr::NonNull::dangling().as_ptr());
+        let cap = std::mem::replace(&mut self.cap, 0);
+        self.published.write(0);
+        self.cursor.write(0);
+        // SAFETY: `data` comes from the global allocator with the layout of
+        // `[T; cap]`, as `Vec` allocates it, and its first `len` slots hold
+        // published values. `self` no longer owns the storage.
+        unsafe { Vec::from_raw_parts(data, len, cap) }
+    }
+
+    /// Removes all items, returning an iterator that yields each with the
+    /// index it had, in allocation order.
+    ///
+    /// Behaves like [`drain`](FastArena::drain) otherwise.
+    pub fn drain_indexed(&mut self) -> crate::DrainIndexed<std::vec::IntoIter<T>> {
+        crate::DrainIndexed::new(self.drain())
     }
 
     /// Removes all items, returning an iterator that yields them.
     pub fn drain(&mut self) -> std::vec::IntoIter<T> {
-        let current = *self.published.get_mut();
+        let current = self.published.read();
         let mut items = Vec::with_capacity(current);
         for slot in 0..current {
             // SAFETY: slot < published. &mut self guarantees exclusive access.
-            unsafe {
-                items.push(self.data.add(slot).read());
-                (*self.flags.add(slot)).store(false, Ordering::Relaxed);
-            }
+            items.push(unsafe { self.data.add(slot).read() });
         }
-        *self.published.get_mut() = 0;
-        *self.cursor.get_mut() = 0;
+        // The published values were moved out; only slots stranded past a
+        // poisoned slot are left to drop.
+        self.flags.clear_to(current);
+        self.truncate_slots(0);
         items.into_iter()
     }
 }
@@ -413,9 +1878,35 @@ impl<T> std::ops::IndexMut<Idx<T>> for FastArena<T> {
     }
 }
 
+impl<T> std::ops::Index<IdxRange<T>> for FastArena<T> {
+    type Output = [T];
+
+    fn index(&self, range: IdxRange<T>) -> &[T] {
+        self.slice(range)
+    }
+}
+
+impl<T> std::ops::IndexMut<IdxRange<T>> for FastArena<T> {
+    fn index_mut(&mut self, range: IdxRange<T>) -> &mut [T] {
+        self.slice_mut(range)
+    }
+}
+
+impl<T> AsRef<[T]> for FastArena<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsMut<[T]> for FastArena<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
 impl<'a, T> IntoIterator for &'a FastArena<T> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = FastArenaIter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -433,10 +1924,163 @@ impl<'a, T> IntoIterator for &'a mut FastArena<T> {
 
 impl<T> IntoIterator for FastArena<T> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = FastArenaIntoIter<T>;
 
+    /// Consumes the arena, yielding its published items in allocation
+    /// order straight from its storage.
     fn into_iter(mut self) -> Self::IntoIter {
-        self.drain()
+        let len = self.published.read();
+        // Values stranded past a poisoned slot are never yielded.
+        self.truncate_slots(len);
+        FastArenaIntoIter {
+            arena: self,
+            front: 0,
+            back: len,
+        }
+    }
+}
+
+/// Iterator over the items of a [`FastArena`] published when it was
+/// created.
+///
+/// Created by [`FastArena::iter`]. Allocations made while it runs are not
+/// yielded. Unlike a slice iterator, the type does not commit the arena to
+/// contiguous storage.
+pub struct FastArenaIter<'a, T> {
+    inner: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for FastArenaIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        self.inner.nth(n)
+    }
+}
+
+impl<T> DoubleEndedIterator for FastArenaIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for FastArenaIter<'_, T> {}
+
+impl<T> std::iter::FusedIterator for FastArenaIter<'_, T> {}
+
+impl<T> Clone for FastArenaIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for FastArenaIter<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.inner.clone()).finish()
+    }
+}
+
+/// Owning iterator over the items of a [`FastArena`].
+///
+/// Created by `FastArena::into_iter`. Reads each value out of the arena's
+/// storage instead of collecting them into a second buffer. Values not
+/// yet yielded are dropped with the iterator, in the arena's
+/// [`DropOrder`].
+pub struct FastArenaIntoIter<T> {
+    arena: FastArena<T>,
+    /// Next slot to yield from the front.
+    front: usize,
+    /// One past the next slot to yield from the back.
+    back: usize,
+}
+
+impl<T> Iterator for FastArenaIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        // SAFETY: front < back <= published, and each slot is read once:
+        // the cursors only move past it.
+        let value = unsafe { self.arena.data.add(self.front).read() };
+        self.front += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for FastArenaIntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        // SAFETY: as in `next`.
+        Some(unsafe { self.arena.data.add(self.back).read() })
+    }
+}
+
+impl<T> ExactSizeIterator for FastArenaIntoIter<T> {}
+
+impl<T> std::iter::FusedIterator for FastArenaIntoIter<T> {}
+
+impl<T> Drop for FastArenaIntoIter<T> {
+    fn drop(&mut self) {
+        // The yielded values were moved out; clearing their ready bits
+        // leaves only the rest for the arena's own drop.
+        self.arena.flags.clear_to(self.front);
+        self.arena.flags.clear_from(self.back);
+    }
+}
+
+impl<T> From<Vec<T>> for FastArena<T> {
+    /// Takes over the vector's storage without copying: item `i` gets
+    /// `Idx::from_raw(i)` and the vector's capacity becomes the arena's.
+    fn from(items: Vec<T>) -> Self {
+        let mut items = std::mem::ManuallyDrop::new(items);
+        if items.capacity() == 0 {
+            items.reserve_exact(1);
+        }
+        let len = items.len();
+        // A zero-sized `T` reports unbounded capacity; only track what is
+        // used.
+        let cap = if size_of::<T>() == 0 {
+            len.max(1)
+        } else {
+            items.capacity()
+        };
+        let mut flags = ReadyBits::try_new(cap).unwrap_or_else(|err| err.handle());
+        flags.set_to(len);
+        Self::from_storage(
+            items.as_mut_ptr(),
+            flags,
+            cap,
+            len,
+            std::mem::align_of::<T>(),
+            None,
+        )
+    }
+}
+
+impl<T> From<Arena<T>> for FastArena<T> {
+    /// Moves the items over without copying, preserving their indices.
+    fn from(arena: Arena<T>) -> Self {
+        Self::from(arena.into_vec())
     }
 }
 
@@ -461,54 +2105,66 @@ impl<T> std::iter::FromIterator<T> for FastArena<T> {
 
 impl<T> Drop for FastArena<T> {
     fn drop(&mut self) {
-        let published = *self.published.get_mut();
-        // Drop all published values in reverse order.
-        for slot in (0..published).rev() {
-            // SAFETY: slot < published, values are initialized.
-            // &mut self in drop guarantees exclusive access.
-            unsafe {
-                self.data.add(slot).drop_in_place();
-            }
-        }
+        self.truncate_slots(0);
         // SAFETY: dealloc storage without dropping values (already dropped above).
         unsafe {
-            dealloc_storage(self.data, self.flags, self.cap);
+            dealloc_storage(self.data, self.cap, self.align);
         }
     }
 }
 
-/// Allocates raw storage for `cap` items: a `T` array and `AtomicBool` flags.
-///
-/// Returns raw pointers to both allocations. Flags are initialized to `false`.
-fn alloc_storage<T>(cap: usize) -> (*mut T, *mut AtomicBool) {
-    let data_layout = std::alloc::Layout::array::<T>(cap).expect("layout overflow");
-    let flags_layout = std::alloc::Layout::array::<AtomicBool>(cap).expect("layout overflow");
+/// Abandons an unwritten slot if the constructor unwinds.
+struct AbandonOnUnwind<'a, T> {
+    arena: &'a FastArena<T>,
+    slot: usize,
+}
 
-    // SAFETY: layouts are valid (non-zero size for cap >= 1).
-    let data = unsafe { std::alloc::alloc(data_layout) }.cast::<T>();
-    let flags = unsafe { std::alloc::alloc_zeroed(flags_layout) }.cast::<AtomicBool>();
+impl<T> Drop for AbandonOnUnwind<'_, T> {
+    fn drop(&mut self) {
+        self.arena.abandon_block(self.slot, self.slot + 1);
+    }
+}
 
-    assert!(!data.is_null(), "allocation failed for data");
-    assert!(!flags.is_null(), "allocation failed for flags");
+/// Layout of the storage for `cap` items of `T` starting at a multiple of
+/// `align`.
+fn storage_layout<T>(cap: usize, align: usize) -> Option<std::alloc::Layout> {
+    std::alloc::Layout::array::<T>(cap)
+        .ok()?
+        .align_to(align)
+        .ok()
+}
 
-    data.cast::<T>();
-    flags.cast::<AtomicBool>();
+/// Allocates uninitialized storage for `cap` items of `T`, aligned to
+/// `align`.
+///
+/// Zero-sized layouts (ZST `T`) use a dangling, well-aligned pointer
+/// instead of calling the allocator.
+fn alloc_storage<T>(cap: usize, align: usize) -> Result<*mut T, ArenaAllocError> {
+    let layout = storage_layout::<T>(cap, align).ok_or(ArenaAllocError::CapacityOverflow)?;
+    if layout.size() == 0 {
+        return Ok(std::ptr::NonNull::dangling().as_ptr());
+    }
 
-    (data, flags)
+    // SAFETY: layout has non-zero size.
+    let data = unsafe { std::alloc::alloc(layout) }.cast::<T>();
+    if data.is_null() {
+        return Err(ArenaAllocError::AllocFailed { layout });
+    }
+    Ok(data)
 }
 
 /// Deallocates raw storage WITHOUT dropping any values.
 ///
 /// # Safety
 ///
-/// Caller must ensure all live values have been dropped or moved out
-/// before calling this.
-unsafe fn dealloc_storage<T>(data: *mut T, flags: *mut AtomicBool, cap: usize) {
-    let data_layout = std::alloc::Layout::array::<T>(cap).expect("layout overflow");
-    let flags_layout = std::alloc::Layout::array::<AtomicBool>(cap).expect("layout overflow");
-
-    unsafe {
-        std::alloc::dealloc(data.cast::<u8>(), data_layout);
-        std::alloc::dealloc(flags.cast::<u8>(), flags_layout);
+/// `data` must come from `alloc_storage::<T>(cap, align)`, and all live
+/// values must have been dropped or moved out before calling this.
+unsafe fn dealloc_storage<T>(data: *mut T, cap: usize, align: usize) {
+    let layout = storage_layout::<T>(cap, align).expect("layout overflow");
+    if layout.size() == 0 {
+        return;
     }
+
+    // SAFETY: data was allocated with this layout per the contract.
+    unsafe { std::alloc::dealloc(data.cast::<u8>(), layout) };
 }
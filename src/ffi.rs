@@ -0,0 +1,123 @@
+//! FFI-friendly handles for reading arenas from C or C++.
+
+use crate::{Arena, FastArena, Idx};
+
+impl<T: ?Sized> Idx<T> {
+    /// Returns the index as a `u64`, the type handed across FFI.
+    ///
+    /// Requires the `ffi` feature.
+    #[must_use]
+    pub const fn to_ffi(self) -> u64 {
+        self.into_raw() as u64
+    }
+
+    /// Creates an index from a value produced by
+    /// [`to_ffi`](Idx::to_ffi), or `None` if it does not fit in `usize`.
+    ///
+    /// Like [`from_raw`](Idx::from_raw), the index is only meaningful for
+    /// the arena it came from.
+    ///
+    /// Requires the `ffi` feature.
+    #[must_use]
+    pub fn from_ffi(raw: u64) -> Option<Self> {
+        usize::try_from(raw).ok().map(Self::from_raw)
+    }
+}
+
+/// `#[repr(C)]` view of an arena's items: a pointer and a length.
+///
+/// Pass it to C or C++ code that indexes the items with the `u64` values of
+/// [`Idx::to_ffi`]. The view borrows nothing, so the Rust side must keep
+/// it valid: any operation that can move or drop items — `alloc` on an
+/// [`Arena`] that reallocates, `grow`, `rollback`, `reset`, or dropping the
+/// arena — invalidates it. A [`FastArena`] view stays valid while other
+/// threads allocate, because published items never move under `&self`.
+///
+/// Monomorphic `extern "C"` shims expose it for one element type:
+///
+/// ```
+/// use fast_bump::{Arena, FfiSlice};
+///
+/// #[repr(C)]
+/// pub struct Node {
+///     pub value: i64,
+/// }
+///
+/// #[unsafe(no_mangle)]
+/// pub extern "C" fn nodes_get(nodes: FfiSlice<Node>, idx: u64) -> *const Node {
+///     nodes.get(idx)
+/// }
+///
+/// let mut arena = Arena::new();
+/// let idx = arena.alloc(Node { value: 7 });
+/// let ptr = nodes_get(arena.as_ffi_slice(), idx.to_ffi());
+/// // SAFETY: the arena was not modified since the view was taken.
+/// assert_eq!(unsafe { (*ptr).value }, 7);
+/// assert!(nodes_get(arena.as_ffi_slice(), 9).is_null());
+/// ```
+#[repr(C)]
+pub struct FfiSlice<T> {
+    /// Pointer to the first item.
+    pub ptr: *const T,
+    /// Number of items.
+    pub len: usize,
+}
+
+impl<T> FfiSlice<T> {
+    /// Returns a pointer to the item at `idx`, or null if it is out of
+    /// bounds. Dereferencing it is subject to the validity rules above.
+    #[must_use]
+    pub fn get(&self, idx: u64) -> *const T {
+        match usize::try_from(idx) {
+            Ok(i) if i < self.len => self.ptr.wrapping_add(i),
+            _ => std::ptr::null(),
+        }
+    }
+
+    const fn from_slice(items: &[T]) -> Self {
+        Self {
+            ptr: items.as_ptr(),
+            len: items.len(),
+        }
+    }
+}
+
+impl<T> Clone for FfiSlice<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FfiSlice<T> {}
+
+impl<T> std::fmt::Debug for FfiSlice<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FfiSlice")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T> Arena<T> {
+    /// Returns a `#[repr(C)]` view of the items for foreign code.
+    ///
+    /// See [`FfiSlice`] for how long it stays valid. Requires the `ffi`
+    /// feature.
+    #[must_use]
+    pub fn as_ffi_slice(&self) -> FfiSlice<T> {
+        FfiSlice::from_slice(self.as_slice())
+    }
+}
+
+impl<T> FastArena<T> {
+    /// Returns a `#[repr(C)]` view of the published items for foreign
+    /// code.
+    ///
+    /// See [`FfiSlice`] for how long it stays valid. Requires the `ffi`
+    /// feature.
+    #[must_use]
+    pub fn as_ffi_slice(&self) -> FfiSlice<T> {
+        FfiSlice::from_slice(self.as_slice())
+    }
+}
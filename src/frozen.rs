@@ -0,0 +1,126 @@
+use std::ops::Deref;
+
+use crate::{Idx, IterIndexed};
+
+/// Immutable arena produced by [`Arena::freeze`](crate::Arena::freeze) or
+/// [`FastArena::freeze`](crate::FastArena::freeze).
+///
+/// Holds the items in a boxed slice: no readiness flags, no cursor, no
+/// interior mutability. It is `Send + Sync` whenever `T` is, derefs to
+/// `&[T]`, and still resolves the `Idx<T>` handles issued while building, so
+/// it can be wrapped in an `Arc` and read from many threads without a lock.
+#[derive(Clone)]
+pub struct FrozenArena<T> {
+    items: Box<[T]>,
+}
+
+impl<T> FrozenArena<T> {
+    pub(crate) fn from_vec(items: Vec<T>) -> Self {
+        Self {
+            items: items.into_boxed_slice(),
+        }
+    }
+
+    /// Returns a reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[must_use]
+    pub fn get(&self, idx: Idx<T>) -> &T {
+        &self.items[idx.into_raw()]
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if the
+    /// index is out of bounds.
+    #[must_use]
+    pub fn try_get(&self, idx: Idx<T>) -> Option<&T> {
+        self.items.get(idx.into_raw())
+    }
+
+    /// Returns `true` if `idx` points to a valid item.
+    #[must_use]
+    pub const fn is_valid(&self, idx: Idx<T>) -> bool {
+        idx.into_raw() < self.items.len()
+    }
+
+    /// Returns the number of items.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the arena contains no items.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns all items as a slice.
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns an iterator yielding `(Idx<T>, &T)` pairs in allocation order.
+    #[must_use]
+    pub fn iter_indexed(&self) -> IterIndexed<'_, T> {
+        IterIndexed::new(self.items.iter().enumerate())
+    }
+
+    /// Returns the index of `value` if it refers to an item stored in this
+    /// arena.
+    ///
+    /// O(1): computed from the address of `value`. Returns `None` for
+    /// references outside the arena and for zero-sized `T`.
+    #[must_use]
+    pub fn idx_of(&self, value: &T) -> Option<Idx<T>> {
+        crate::idx::idx_of_in(&self.items, value)
+    }
+
+    /// Returns the items as a `Vec<T>` without copying.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.items.into_vec()
+    }
+}
+
+impl<T> Deref for FrozenArena<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T> AsRef<[T]> for FrozenArena<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T> std::ops::Index<Idx<T>> for FrozenArena<T> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.get(idx)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a FrozenArena<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<T> IntoIterator for FrozenArena<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_vec().into_iter()
+    }
+}
@@ -0,0 +1,76 @@
+//! [`Arbitrary`] support for fuzzing code that consumes arenas.
+
+use std::fmt;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Arena, Checkpoint, Idx};
+
+/// An arbitrary [`Arena<T>`] together with indices that are valid in it.
+///
+/// Saves fuzz targets from hand-rolling index generation: every entry of
+/// `indices` is in bounds for `arena`, and may repeat. `indices` is empty
+/// when `arena` is.
+///
+/// Requires the `arbitrary` feature.
+pub struct ArbitraryArena<T> {
+    /// The generated arena.
+    pub arena: Arena<T>,
+    /// Indices into `arena`, in arbitrary order.
+    pub indices: Vec<Idx<T>>,
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for ArbitraryArena<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let arena = Arena::arbitrary(u)?;
+        let indices = if arena.is_empty() {
+            Vec::new()
+        } else {
+            let last = arena.len() - 1;
+            let count = u.arbitrary_len::<usize>()?;
+            (0..count)
+                .map(|_| u.int_in_range(0..=last).map(Idx::from_raw))
+                .collect::<Result<_>>()?
+        };
+        Ok(Self { arena, indices })
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArbitraryArena<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArbitraryArena")
+            .field("arena", &self.arena.as_slice())
+            .field("indices", &self.indices)
+            .finish()
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Arena<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Vec::<T>::size_hint(depth)
+    }
+}
+
+impl<T> Arbitrary<'_> for Idx<T> {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        usize::arbitrary(u).map(Self::from_raw)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        usize::size_hint(depth)
+    }
+}
+
+impl<T> Arbitrary<'_> for Checkpoint<T> {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        usize::arbitrary(u).map(Self::from_len)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        usize::size_hint(depth)
+    }
+}
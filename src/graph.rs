@@ -0,0 +1,343 @@
+use std::collections::VecDeque;
+
+use crate::{Arena, Idx, IdxSet};
+
+/// End of an edge list.
+const NONE: usize = usize::MAX;
+
+/// Endpoints of an edge and the next edge leaving the same node.
+struct Link<N> {
+    from: Idx<N>,
+    to: Idx<N>,
+    next: usize,
+}
+
+/// Directed graph with node weights `N` and edge weights `E`, both stored
+/// in arenas.
+///
+/// Nodes are addressed by `Idx<N>` and edges by `Idx<E>`. Each node keeps
+/// its outgoing edges as a singly linked list threaded through the edge
+/// storage, so [`add_edge`](GraphArena::add_edge) is O(1) and neighbors
+/// come back in insertion order. [`dfs`](GraphArena::dfs) and
+/// [`bfs`](GraphArena::bfs) track visited nodes in an [`IdxSet`].
+///
+/// ```
+/// use fast_bump::GraphArena;
+///
+/// let mut g = GraphArena::new();
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// let c = g.add_node("c");
+/// g.add_edge(a, b, 1.5);
+/// g.add_edge(a, c, 2.0);
+/// g.add_edge(b, c, 0.5);
+///
+/// assert_eq!(g.neighbors(a).collect::<Vec<_>>(), [b, c]);
+/// let order: Vec<_> = g.dfs(a).map(|n| g[n]).collect();
+/// assert_eq!(order, ["a", "b", "c"]);
+/// ```
+pub struct GraphArena<N, E = ()> {
+    nodes: Arena<N>,
+    /// Per node, its first and last outgoing edge, or `NONE`.
+    ends: Vec<(usize, usize)>,
+    edges: Arena<E>,
+    links: Vec<Link<N>>,
+}
+
+impl<N, E> GraphArena<N, E> {
+    /// Creates an empty graph.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            nodes: Arena::new(),
+            ends: Vec::new(),
+            edges: Arena::new(),
+            links: Vec::new(),
+        }
+    }
+
+    /// Creates an empty graph with room for `nodes` nodes and `edges`
+    /// edges.
+    #[must_use]
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        Self {
+            nodes: Arena::with_capacity(nodes),
+            ends: Vec::with_capacity(nodes),
+            edges: Arena::with_capacity(edges),
+            links: Vec::with_capacity(edges),
+        }
+    }
+
+    /// Adds a node, returning its index.
+    pub fn add_node(&mut self, weight: N) -> Idx<N> {
+        self.ends.push((NONE, NONE));
+        self.nodes.alloc(weight)
+    }
+
+    /// Adds an edge from `from` to `to`, returning its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is out of bounds.
+    pub fn add_edge(&mut self, from: Idx<N>, to: Idx<N>, weight: E) -> Idx<E> {
+        assert!(
+            to.into_raw() < self.ends.len(),
+            "edge target {} out of bounds for {} nodes",
+            to.into_raw(),
+            self.ends.len(),
+        );
+        let edge = self.links.len();
+        let (first, last) = &mut self.ends[from.into_raw()];
+        if *last == NONE {
+            *first = edge;
+        } else {
+            self.links[*last].next = edge;
+        }
+        *last = edge;
+        self.links.push(Link {
+            from,
+            to,
+            next: NONE,
+        });
+        self.edges.alloc(weight)
+    }
+
+    /// Returns the weight of `node`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    #[must_use]
+    pub fn node(&self, node: Idx<N>) -> &N {
+        self.nodes.get(node)
+    }
+
+    /// Returns the weight of `node`, mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    #[must_use]
+    pub fn node_mut(&mut self, node: Idx<N>) -> &mut N {
+        self.nodes.get_mut(node)
+    }
+
+    /// Returns the weight of `edge`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edge` is out of bounds.
+    #[must_use]
+    pub fn edge(&self, edge: Idx<E>) -> &E {
+        self.edges.get(edge)
+    }
+
+    /// Returns the weight of `edge`, mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edge` is out of bounds.
+    #[must_use]
+    pub fn edge_mut(&mut self, edge: Idx<E>) -> &mut E {
+        self.edges.get_mut(edge)
+    }
+
+    /// Returns the source and target of `edge`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edge` is out of bounds.
+    #[must_use]
+    pub fn endpoints(&self, edge: Idx<E>) -> (Idx<N>, Idx<N>) {
+        let link = &self.links[edge.into_raw()];
+        (link.from, link.to)
+    }
+
+    /// Returns an iterator over the targets of the edges leaving `node`,
+    /// in insertion order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    pub fn neighbors(&self, node: Idx<N>) -> impl Iterator<Item = Idx<N>> + '_ {
+        self.edges_from(node).map(|(_, to, _)| to)
+    }
+
+    /// Returns an iterator yielding `(edge, target, &weight)` for the
+    /// edges leaving `node`, in insertion order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    pub fn edges_from(&self, node: Idx<N>) -> impl Iterator<Item = (Idx<E>, Idx<N>, &E)> + '_ {
+        let mut at = self.ends[node.into_raw()].0;
+        std::iter::from_fn(move || {
+            let link = self.links.get(at)?;
+            let edge = Idx::from_raw(at);
+            at = link.next;
+            Some((edge, link.to, self.edges.get(edge)))
+        })
+    }
+
+    /// Returns a depth-first iterator over the nodes reachable from
+    /// `start`, in preorder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is out of bounds.
+    #[must_use]
+    pub fn dfs(&self, start: Idx<N>) -> Dfs<'_, N, E> {
+        let mut visited = IdxSet::with_capacity(self.node_count());
+        visited.insert(start);
+        Dfs {
+            graph: self,
+            start: Some(start),
+            stack: vec![self.ends[start.into_raw()].0],
+            visited,
+        }
+    }
+
+    /// Returns a breadth-first iterator over the nodes reachable from
+    /// `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is out of bounds.
+    #[must_use]
+    pub fn bfs(&self, start: Idx<N>) -> Bfs<'_, N, E> {
+        assert!(
+            start.into_raw() < self.node_count(),
+            "start node {} out of bounds for {} nodes",
+            start.into_raw(),
+            self.node_count(),
+        );
+        let mut visited = IdxSet::with_capacity(self.node_count());
+        visited.insert(start);
+        Bfs {
+            graph: self,
+            queue: VecDeque::from([start]),
+            visited,
+        }
+    }
+
+    /// Returns the number of nodes.
+    #[must_use]
+    pub const fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of edges.
+    #[must_use]
+    pub const fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns the arena of node weights.
+    #[must_use]
+    pub const fn nodes(&self) -> &Arena<N> {
+        &self.nodes
+    }
+
+    /// Returns the arena of edge weights.
+    #[must_use]
+    pub const fn edges(&self) -> &Arena<E> {
+        &self.edges
+    }
+
+    /// Removes every node and edge, keeping the storage.
+    pub fn reset(&mut self) {
+        self.nodes.reset();
+        self.ends.clear();
+        self.edges.reset();
+        self.links.clear();
+    }
+}
+
+impl<N, E> Default for GraphArena<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E> std::ops::Index<Idx<N>> for GraphArena<N, E> {
+    type Output = N;
+
+    fn index(&self, node: Idx<N>) -> &N {
+        self.node(node)
+    }
+}
+
+impl<N, E> std::ops::IndexMut<Idx<N>> for GraphArena<N, E> {
+    fn index_mut(&mut self, node: Idx<N>) -> &mut N {
+        self.node_mut(node)
+    }
+}
+
+impl<N, E> std::fmt::Debug for GraphArena<N, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GraphArena")
+            .field("nodes", &self.node_count())
+            .field("edges", &self.edge_count())
+            .finish()
+    }
+}
+
+/// Depth-first traversal of a [`GraphArena`].
+///
+/// Created by [`GraphArena::dfs`].
+pub struct Dfs<'a, N, E> {
+    graph: &'a GraphArena<N, E>,
+    start: Option<Idx<N>>,
+    /// Per node on the current path, the next edge to follow.
+    stack: Vec<usize>,
+    visited: IdxSet<N>,
+}
+
+impl<N, E> Iterator for Dfs<'_, N, E> {
+    type Item = Idx<N>;
+
+    fn next(&mut self) -> Option<Idx<N>> {
+        if let Some(start) = self.start.take() {
+            return Some(start);
+        }
+        loop {
+            let cursor = self.stack.last_mut()?;
+            let Some(link) = self.graph.links.get(*cursor) else {
+                self.stack.pop();
+                continue;
+            };
+            *cursor = link.next;
+            if self.visited.insert(link.to) {
+                self.stack.push(self.graph.ends[link.to.into_raw()].0);
+                return Some(link.to);
+            }
+        }
+    }
+}
+
+impl<N, E> std::iter::FusedIterator for Dfs<'_, N, E> {}
+
+/// Breadth-first traversal of a [`GraphArena`].
+///
+/// Created by [`GraphArena::bfs`].
+pub struct Bfs<'a, N, E> {
+    graph: &'a GraphArena<N, E>,
+    queue: VecDeque<Idx<N>>,
+    visited: IdxSet<N>,
+}
+
+impl<N, E> Iterator for Bfs<'_, N, E> {
+    type Item = Idx<N>;
+
+    fn next(&mut self) -> Option<Idx<N>> {
+        let node = self.queue.pop_front()?;
+        for to in self.graph.neighbors(node) {
+            if self.visited.insert(to) {
+                self.queue.push_back(to);
+            }
+        }
+        Some(node)
+    }
+}
+
+impl<N, E> std::iter::FusedIterator for Bfs<'_, N, E> {}
@@ -0,0 +1,36 @@
+/// How an arena picks its next capacity when it runs out of room.
+///
+/// Set with [`Arena::set_growth_policy`](crate::Arena::set_growth_policy)
+/// or [`FastArena::set_growth_policy`](crate::FastArena::set_growth_policy).
+/// `Arena` applies it whenever `alloc`, `extend` or `reserve` need more
+/// capacity; `FastArena` applies it in `grow`. The new capacity is never
+/// less than what the triggering operation needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum GrowthPolicy {
+    /// Double the capacity (amortized O(1) appends).
+    #[default]
+    Double,
+    /// Grow by this percentage of the current capacity, at least one slot.
+    /// `Percent(50)` grows by 1.5×.
+    Percent(usize),
+    /// Grow by a fixed number of slots.
+    Increment(usize),
+}
+
+impl GrowthPolicy {
+    /// Returns the capacity to grow to from `current` when at least
+    /// `needed` slots are required. Saturates at `usize::MAX`.
+    #[must_use]
+    pub const fn next_capacity(self, current: usize, needed: usize) -> usize {
+        let grown = match self {
+            Self::Double => current.saturating_mul(2),
+            Self::Percent(percent) => {
+                let step = current.saturating_mul(percent) / 100;
+                current.saturating_add(if step == 0 { 1 } else { step })
+            }
+            Self::Increment(step) => current.saturating_add(step),
+        };
+        if grown > needed { grown } else { needed }
+    }
+}
@@ -14,12 +14,12 @@ use std::marker::PhantomData;
 ///
 /// Indexing with a stale `Idx` (after rollback/reset) panics with
 /// an out-of-bounds error.
-pub struct Idx<T> {
+pub struct Idx<T: ?Sized> {
     index: usize,
     _marker: PhantomData<T>,
 }
 
-impl<T> Idx<T> {
+impl<T: ?Sized> Idx<T> {
     /// Returns the raw index value.
     #[must_use]
     pub const fn into_raw(self) -> usize {
@@ -36,43 +36,158 @@ impl<T> Idx<T> {
             _marker: PhantomData,
         }
     }
+
+    /// Returns the index `n` slots after this one, or `None` on overflow.
+    ///
+    /// Raw index math: the result is only valid if the arena holds that
+    /// many values, e.g. for siblings allocated in one batch.
+    #[must_use]
+    pub const fn checked_add(self, n: usize) -> Option<Self> {
+        match self.index.checked_add(n) {
+            Some(index) => Some(Self::from_raw(index)),
+            None => None,
+        }
+    }
+
+    /// Returns the index of the next slot.
+    ///
+    /// Raw index math, like [`checked_add`](Idx::checked_add).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is `usize::MAX`.
+    #[must_use]
+    pub const fn successor(self) -> Self {
+        self.checked_add(1).expect("index overflow")
+    }
+
+    /// Returns the number of slots between `self` and `other`, in either
+    /// order.
+    #[must_use]
+    pub const fn distance(self, other: Self) -> usize {
+        self.index.abs_diff(other.index)
+    }
+}
+
+/// Index of a [`FastArena`](crate::FastArena) slot that was reserved but
+/// may not be published yet.
+///
+/// Returned by [`FastArena::reserve_one`](crate::FastArena::reserve_one)
+/// and [`SlotGuard::idx`](crate::SlotGuard::idx). It cannot index the
+/// arena, so reading a slot whose value was never written does not
+/// compile. Turn it into an [`Idx`] once the value is visible with
+/// [`FastArena::resolve`](crate::FastArena::resolve) or
+/// [`FastArena::resolve_wait`](crate::FastArena::resolve_wait); writing
+/// the [`SlotGuard`](crate::SlotGuard) also returns the `Idx`.
+pub struct ReservedIdx<T: ?Sized> {
+    idx: Idx<T>,
+}
+
+impl<T: ?Sized> ReservedIdx<T> {
+    pub(crate) const fn from_raw(index: usize) -> Self {
+        Self {
+            idx: Idx::from_raw(index),
+        }
+    }
+
+    /// Returns the raw index value.
+    #[must_use]
+    pub const fn into_raw(self) -> usize {
+        self.idx.index
+    }
+
+    /// Returns the index the value will have, without checking that it is
+    /// published.
+    ///
+    /// For APIs that wait for publication themselves, such as
+    /// [`FastArena::get_wait_timeout`](crate::FastArena::get_wait_timeout)
+    /// and [`FastArena::get_async`](crate::FastArena::get_async). Other
+    /// reads panic or return `None` until the slot is written.
+    #[must_use]
+    pub const fn assume_published(self) -> Idx<T> {
+        self.idx
+    }
+}
+
+impl<T: ?Sized> Clone for ReservedIdx<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for ReservedIdx<T> {}
+
+impl<T: ?Sized> PartialEq for ReservedIdx<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.idx == other.idx
+    }
+}
+
+impl<T: ?Sized> Eq for ReservedIdx<T> {}
+
+impl<T: ?Sized> std::hash::Hash for ReservedIdx<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.idx.hash(state);
+    }
+}
+
+impl<T: ?Sized> std::fmt::Debug for ReservedIdx<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReservedIdx({})", self.idx.index)
+    }
+}
+
+/// Computes the index of `value` within `slice` from its address.
+///
+/// Returns `None` if `value` does not point at an element of `slice`, or if
+/// `T` is zero-sized (every element shares the same address).
+#[allow(clippy::redundant_pub_crate)] // crate-internal even if `idx` is ever made public
+pub(crate) fn idx_of_in<T>(slice: &[T], value: &T) -> Option<Idx<T>> {
+    let size = size_of::<T>();
+    if size == 0 {
+        return None;
+    }
+    let start = slice.as_ptr().addr();
+    let offset = std::ptr::from_ref(value).addr().checked_sub(start)?;
+    let index = offset / size;
+    (offset % size == 0 && index < slice.len()).then(|| Idx::from_raw(index))
 }
 
-impl<T> Clone for Idx<T> {
+impl<T: ?Sized> Clone for Idx<T> {
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<T> Copy for Idx<T> {}
+impl<T: ?Sized> Copy for Idx<T> {}
 
-impl<T> PartialEq for Idx<T> {
+impl<T: ?Sized> PartialEq for Idx<T> {
     fn eq(&self, other: &Self) -> bool {
         self.index == other.index
     }
 }
 
-impl<T> Eq for Idx<T> {}
+impl<T: ?Sized> Eq for Idx<T> {}
 
-impl<T> std::hash::Hash for Idx<T> {
+impl<T: ?Sized> std::hash::Hash for Idx<T> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.index.hash(state);
     }
 }
 
-impl<T> std::fmt::Debug for Idx<T> {
+impl<T: ?Sized> std::fmt::Debug for Idx<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Idx({})", self.index)
     }
 }
 
-impl<T> PartialOrd for Idx<T> {
+impl<T: ?Sized> PartialOrd for Idx<T> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T> Ord for Idx<T> {
+impl<T: ?Sized> Ord for Idx<T> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.index.cmp(&other.index)
     }
@@ -0,0 +1,203 @@
+use std::marker::PhantomData;
+
+use crate::Idx;
+
+/// Half-open range of contiguous indices `start..end`.
+///
+/// Returned by batch allocation such as
+/// [`FastArena::alloc_extend`](crate::FastArena::alloc_extend). Implements
+/// [`Copy`]; iterate with [`iter`](IdxRange::iter) or `for idx in range`.
+pub struct IdxRange<T> {
+    start: usize,
+    end: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> IdxRange<T> {
+    /// Creates the range `start..end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end`.
+    #[must_use]
+    pub fn new(start: Idx<T>, end: Idx<T>) -> Self {
+        assert!(
+            start <= end,
+            "range start {} greater than end {}",
+            start.into_raw(),
+            end.into_raw(),
+        );
+        Self::from_raw(start.into_raw(), end.into_raw())
+    }
+
+    /// Creates a range from raw `start..end` values.
+    ///
+    /// The caller must ensure `start <= end` and that the range is valid
+    /// for the target arena.
+    #[must_use]
+    pub const fn from_raw(start: usize, end: usize) -> Self {
+        Self {
+            start,
+            end,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the range as raw `start..end` values.
+    #[must_use]
+    pub const fn into_raw(self) -> std::ops::Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the first index of the range (inclusive).
+    #[must_use]
+    pub const fn start(&self) -> Idx<T> {
+        Idx::from_raw(self.start)
+    }
+
+    /// Returns the end of the range (exclusive).
+    #[must_use]
+    pub const fn end(&self) -> Idx<T> {
+        Idx::from_raw(self.end)
+    }
+
+    /// Returns the number of indices in the range.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the range contains no indices.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns `true` if `idx` lies within the range.
+    #[must_use]
+    pub const fn contains(&self, idx: Idx<T>) -> bool {
+        self.start <= idx.into_raw() && idx.into_raw() < self.end
+    }
+
+    /// Returns the first index, or `None` if the range is empty.
+    #[must_use]
+    pub const fn first(&self) -> Option<Idx<T>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(Idx::from_raw(self.start))
+        }
+    }
+
+    /// Returns the last index, or `None` if the range is empty.
+    #[must_use]
+    pub const fn last(&self) -> Option<Idx<T>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(Idx::from_raw(self.end - 1))
+        }
+    }
+
+    /// Returns an iterator over the indices in the range.
+    #[must_use]
+    pub const fn iter(&self) -> IdxIter<T> {
+        IdxIter {
+            inner: self.start..self.end,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for IdxRange<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for IdxRange<T> {}
+
+impl<T> PartialEq for IdxRange<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<T> Eq for IdxRange<T> {}
+
+impl<T> std::hash::Hash for IdxRange<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+impl<T> std::fmt::Debug for IdxRange<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IdxRange({}..{})", self.start, self.end)
+    }
+}
+
+impl<T> From<std::ops::Range<Idx<T>>> for IdxRange<T> {
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end`.
+    fn from(range: std::ops::Range<Idx<T>>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T> IntoIterator for IdxRange<T> {
+    type Item = Idx<T>;
+    type IntoIter = IdxIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for &IdxRange<T> {
+    type Item = Idx<T>;
+    type IntoIter = IdxIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the indices of an [`IdxRange`].
+pub struct IdxIter<T> {
+    inner: std::ops::Range<usize>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Clone for IdxIter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Iterator for IdxIter<T> {
+    type Item = Idx<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Idx::from_raw)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IdxIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Idx::from_raw)
+    }
+}
+
+impl<T> ExactSizeIterator for IdxIter<T> {}
+
+impl<T> std::iter::FusedIterator for IdxIter<T> {}
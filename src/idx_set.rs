@@ -0,0 +1,151 @@
+use std::marker::PhantomData;
+
+use crate::Idx;
+
+const BITS: usize = u64::BITS as usize;
+
+/// Set of indices stored as a bitset, one bit per possible index.
+///
+/// Sized for dense index spaces such as "nodes visited" during a graph
+/// traversal: membership is a shift and a mask, and the storage grows to
+/// the highest index inserted.
+///
+/// ```
+/// use fast_bump::{Arena, IdxSet};
+///
+/// let mut arena = Arena::new();
+/// let a = arena.alloc("a");
+/// let b = arena.alloc("b");
+/// let mut seen = IdxSet::new();
+/// assert!(seen.insert(b));
+/// assert!(!seen.insert(b));
+/// assert!(seen.contains(b) && !seen.contains(a));
+/// ```
+pub struct IdxSet<T> {
+    words: Vec<u64>,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> IdxSet<T> {
+    /// Creates an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            words: Vec::new(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates an empty set with room for indices below `capacity`.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(capacity.div_ceil(BITS)),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds `idx`, returning `true` if it was not in the set.
+    pub fn insert(&mut self, idx: Idx<T>) -> bool {
+        let (word, mask) = Self::locate(idx);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let added = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        self.len += usize::from(added);
+        added
+    }
+
+    /// Removes `idx`, returning `true` if it was in the set.
+    pub fn remove(&mut self, idx: Idx<T>) -> bool {
+        let (word, mask) = Self::locate(idx);
+        let Some(bits) = self.words.get_mut(word) else {
+            return false;
+        };
+        let removed = *bits & mask != 0;
+        *bits &= !mask;
+        self.len -= usize::from(removed);
+        removed
+    }
+
+    /// Returns `true` if `idx` is in the set.
+    #[must_use]
+    pub fn contains(&self, idx: Idx<T>) -> bool {
+        let (word, mask) = Self::locate(idx);
+        self.words.get(word).is_some_and(|bits| bits & mask != 0)
+    }
+
+    /// Returns the number of indices in the set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the set is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the indices in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Idx<T>> + '_ {
+        self.words.iter().enumerate().flat_map(|(word, &bits)| {
+            (0..BITS)
+                .filter(move |bit| bits & (1 << bit) != 0)
+                .map(move |bit| Idx::from_raw(word * BITS + bit))
+        })
+    }
+
+    /// Removes every index, keeping the storage.
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+
+    const fn locate(idx: Idx<T>) -> (usize, u64) {
+        let i = idx.into_raw();
+        (i / BITS, 1 << (i % BITS))
+    }
+}
+
+impl<T> Default for IdxSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for IdxSet<T> {
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words.clone(),
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Extend<Idx<T>> for IdxSet<T> {
+    fn extend<I: IntoIterator<Item = Idx<T>>>(&mut self, iter: I) {
+        for idx in iter {
+            self.insert(idx);
+        }
+    }
+}
+
+impl<T> FromIterator<Idx<T>> for IdxSet<T> {
+    fn from_iter<I: IntoIterator<Item = Idx<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T> std::fmt::Debug for IdxSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,127 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash, RandomState};
+
+use crate::{Arena, Idx, ValueIndex};
+
+/// Hash-consing arena: structurally equal values share one [`Idx<T>`].
+///
+/// [`intern`](Interner::intern) stores a value the first time it is seen
+/// and returns the existing index for every equal value after that, so
+/// index equality stands in for value equality. Values are stored once, in
+/// an [`Arena<T>`]; the table only keeps hashes and indices, so `T` need not
+/// be `Clone`.
+///
+/// ```
+/// use fast_bump::Interner;
+///
+/// let mut types = Interner::new();
+/// let a = types.intern(String::from("i32"));
+/// let b = types.intern(String::from("i32"));
+/// assert_eq!(a, b);
+/// assert_eq!(types.get("i32"), Some(a));
+/// assert_eq!(types[a], "i32");
+/// assert_eq!(types.len(), 1);
+/// ```
+pub struct Interner<T, S = RandomState> {
+    arena: Arena<T>,
+    index: ValueIndex<T, S>,
+}
+
+impl<T: Eq + Hash> Interner<T> {
+    /// Creates an empty interner.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> Interner<T, S> {
+    /// Creates an empty interner hashing values with `hasher`.
+    #[must_use]
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            arena: Arena::new(),
+            index: ValueIndex::with_hasher(hasher),
+        }
+    }
+
+    /// Returns the index of the value equal to `value`, allocating it if
+    /// it has not been interned yet.
+    pub fn intern(&mut self, value: T) -> Idx<T> {
+        let hash = self.index.hash(&value);
+        if let Some(idx) = self.index.find(self.arena.as_slice(), hash, &value) {
+            return idx;
+        }
+        self.index.push(hash);
+        self.arena.alloc(value)
+    }
+
+    /// Returns the index of the interned value equal to `value`, if any.
+    #[must_use]
+    pub fn get<Q>(&self, value: &Q) -> Option<Idx<T>>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.index.hash(value);
+        self.index.find(self.arena.as_slice(), hash, value)
+    }
+}
+
+impl<T, S> Interner<T, S> {
+    /// Returns the interned value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[must_use]
+    pub fn resolve(&self, idx: Idx<T>) -> &T {
+        self.arena.get(idx)
+    }
+
+    /// Returns the number of distinct values.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Returns `true` if nothing has been interned.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.arena.is_empty()
+    }
+
+    /// Returns the arena holding the interned values.
+    #[must_use]
+    pub const fn arena(&self) -> &Arena<T> {
+        &self.arena
+    }
+
+    /// Consumes the interner, returning its arena. Indices stay valid.
+    #[must_use]
+    pub fn into_arena(self) -> Arena<T> {
+        self.arena
+    }
+}
+
+impl<T: Eq + Hash> Default for Interner<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S> std::ops::Index<Idx<T>> for Interner<T, S> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.resolve(idx)
+    }
+}
+
+impl<T, S> std::fmt::Debug for Interner<T, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Interner")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,136 @@
+//! Item storage of [`Arena`](crate::Arena).
+//!
+//! `Vec<T>` lets its items borrow data that is dropped before the vector
+//! (`#[may_dangle]`), and `Arena<T>` inherits that as long as it has no
+//! `Drop` impl of its own. Honoring the arena's [`DropOrder`] and zeroizing
+//! when it is dropped needs one, so the vector is held type-erased in the
+//! non-generic [`RawItems`], whose `Drop` cannot constrain `T`'s lifetimes.
+//! `PhantomData<T>` keeps dropck checking `T`'s own destructor, as `Vec`
+//! does.
+
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+
+use crate::DropOrder;
+
+/// `Vec<T>` that drops its items in a configurable order and can zero its
+/// storage before releasing it.
+pub struct Items<T> {
+    raw: RawItems,
+    _marker: PhantomData<T>,
+}
+
+/// Type-erased `Vec` and the monomorphized function that drops it.
+struct RawItems {
+    /// Holds a `Vec<T>`; see `Items::from_vec`.
+    vec: MaybeUninit<[usize; 3]>,
+    drop_vec: unsafe fn(&mut Self),
+    order: DropOrder,
+    zeroize: bool,
+}
+
+impl<T> Items<T> {
+    /// Takes over `vec`, dropping its items forward without zeroizing.
+    pub const fn from_vec(vec: Vec<T>) -> Self {
+        const {
+            assert!(size_of::<Vec<T>>() == size_of::<[usize; 3]>());
+            assert!(align_of::<Vec<T>>() == align_of::<[usize; 3]>());
+        }
+        let mut raw = RawItems {
+            vec: MaybeUninit::uninit(),
+            drop_vec: drop_vec::<T>,
+            order: DropOrder::Forward,
+            zeroize: false,
+        };
+        // SAFETY: the buffer has the size and alignment of `Vec<T>`.
+        unsafe { raw.vec.as_mut_ptr().cast::<Vec<T>>().write(vec) };
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the vector; `Deref` without the `const` restriction.
+    pub const fn vec(&self) -> &Vec<T> {
+        // SAFETY: `raw.vec` holds a `Vec<T>` until `RawItems` is dropped.
+        unsafe { &*self.raw.vec.as_ptr().cast::<Vec<T>>() }
+    }
+
+    pub const fn drop_order(&self) -> DropOrder {
+        self.raw.order
+    }
+
+    pub const fn set_drop_order(&mut self, order: DropOrder) {
+        self.raw.order = order;
+    }
+
+    pub const fn zeroize(&self) -> bool {
+        self.raw.zeroize
+    }
+
+    #[cfg(feature = "zeroize")]
+    pub const fn set_zeroize(&mut self, zeroize: bool) {
+        self.raw.zeroize = zeroize;
+    }
+
+    /// Drops the items past `len` in the configured order.
+    pub fn truncate_in_order(&mut self, len: usize) {
+        let order = self.raw.order;
+        truncate(self, order, len);
+    }
+}
+
+fn truncate<T>(vec: &mut Vec<T>, order: DropOrder, len: usize) {
+    match order {
+        DropOrder::Forward => vec.truncate(len),
+        DropOrder::Reverse => {
+            while vec.len() > len {
+                vec.pop();
+            }
+        }
+    }
+}
+
+/// Drops `vec`'s items in `order`, then zeroes its storage if `zeroize`
+/// is set.
+pub fn drop_in_order<T>(mut vec: Vec<T>, order: DropOrder, zeroize: bool) {
+    truncate(&mut vec, order, 0);
+    if zeroize {
+        crate::scrub::zero(vec.spare_capacity_mut());
+    }
+}
+
+/// Drops the `Vec<T>` held by `raw`.
+///
+/// # Safety
+///
+/// `raw` must hold a `Vec<T>`, which is not used again.
+unsafe fn drop_vec<T>(raw: &mut RawItems) {
+    // SAFETY: per the caller's contract, this moves the vector out once.
+    let vec = unsafe { raw.vec.as_mut_ptr().cast::<Vec<T>>().read() };
+    drop_in_order(vec, raw.order, raw.zeroize);
+}
+
+impl Drop for RawItems {
+    fn drop(&mut self) {
+        // SAFETY: `drop_vec` was instantiated for the type of the vector
+        // written in `Items::from_vec`, and `self` is never used again.
+        unsafe { (self.drop_vec)(self) };
+    }
+}
+
+impl<T> Deref for Items<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        self.vec()
+    }
+}
+
+impl<T> DerefMut for Items<T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        // SAFETY: as in `deref`, and `&mut self` makes the access exclusive.
+        unsafe { &mut *self.raw.vec.as_mut_ptr().cast::<Vec<T>>() }
+    }
+}
@@ -29,6 +29,22 @@ impl<'a, T> Iterator for IterIndexed<'a, T> {
 
 impl<T> ExactSizeIterator for IterIndexed<'_, T> {}
 
+impl<T> DoubleEndedIterator for IterIndexed<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(i, v)| (Idx::from_raw(i), v))
+    }
+}
+
+impl<T> std::iter::FusedIterator for IterIndexed<'_, T> {}
+
+impl<T> Clone for IterIndexed<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 /// Mutable iterator yielding `(Idx<T>, &mut T)` pairs in allocation order.
 ///
 /// Created by [`Arena::iter_indexed_mut`](crate::Arena::iter_indexed_mut).
@@ -58,3 +74,112 @@ impl<'a, T> Iterator for IterIndexedMut<'a, T> {
 }
 
 impl<T> ExactSizeIterator for IterIndexedMut<'_, T> {}
+
+impl<T> DoubleEndedIterator for IterIndexedMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(i, v)| (Idx::from_raw(i), v))
+    }
+}
+
+impl<T> std::iter::FusedIterator for IterIndexedMut<'_, T> {}
+
+/// Draining iterator yielding `(Idx<T>, T)` pairs in allocation order.
+///
+/// Created by [`Arena::drain_indexed`](crate::Arena::drain_indexed) and
+/// [`FastArena::drain_indexed`](crate::FastArena::drain_indexed). Each index
+/// is the one the value had before it was drained.
+pub struct DrainIndexed<I> {
+    inner: std::iter::Enumerate<I>,
+}
+
+impl<I: Iterator> DrainIndexed<I> {
+    pub(crate) fn new(inner: I) -> Self {
+        Self {
+            inner: inner.enumerate(),
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for DrainIndexed<I> {
+    type Item = (Idx<I::Item>, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, v)| (Idx::from_raw(i), v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator for DrainIndexed<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(i, v)| (Idx::from_raw(i), v))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for DrainIndexed<I> {}
+
+impl<I: std::iter::FusedIterator> std::iter::FusedIterator for DrainIndexed<I> {}
+
+/// Iterator yielding `(Idx<A>, &A, &B)` triples over two parallel arenas.
+///
+/// Created by [`zip_indexed`].
+pub struct ZipIndexed<'a, A, B> {
+    inner: std::iter::Enumerate<std::iter::Zip<std::slice::Iter<'a, A>, std::slice::Iter<'a, B>>>,
+}
+
+impl<'a, A, B> Iterator for ZipIndexed<'a, A, B> {
+    type Item = (Idx<A>, &'a A, &'a B);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, (a, b))| (Idx::from_raw(i), a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<A, B> ExactSizeIterator for ZipIndexed<'_, A, B> {}
+
+/// Iterates two arenas used as parallel columns, yielding
+/// `(Idx<A>, &A, &B)` triples in allocation order.
+///
+/// Accepts anything that exposes a contiguous slice, including
+/// [`Arena`](crate::Arena) and [`FastArena`](crate::FastArena). The index
+/// refers to the first arena; convert it with
+/// [`Idx::from_raw`]/[`Idx::into_raw`] to address the second.
+///
+/// # Panics
+///
+/// Panics if the two arenas have different lengths.
+///
+/// # Example
+///
+/// ```
+/// use fast_bump::{Arena, zip_indexed};
+///
+/// let types: Arena<&str> = ["int", "bool"].into_iter().collect();
+/// let spans: Arena<(u32, u32)> = [(0, 3), (4, 8)].into_iter().collect();
+///
+/// for (idx, ty, span) in zip_indexed(&types, &spans) {
+///     assert_eq!(types[idx], *ty);
+///     assert!(span.0 < span.1);
+/// }
+/// ```
+pub fn zip_indexed<'a, A, B>(
+    a: &'a (impl AsRef<[A]> + ?Sized),
+    b: &'a (impl AsRef<[B]> + ?Sized),
+) -> ZipIndexed<'a, A, B> {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    assert!(
+        a.len() == b.len(),
+        "zip_indexed length mismatch: {} vs {}",
+        a.len(),
+        b.len(),
+    );
+    ZipIndexed {
+        inner: a.iter().zip(b.iter()).enumerate(),
+    }
+}
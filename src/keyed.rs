@@ -0,0 +1,248 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{PoisonError, RwLock};
+
+use crate::{Arena, FastArena, Idx};
+
+/// [`Arena<T>`] memoized by an external key.
+///
+/// [`get_or_alloc`](KeyedArena::get_or_alloc) allocates a value the first
+/// time a key is seen and returns the same index for that key afterwards,
+/// e.g. to lower each definition once and cache the result by definition
+/// id. See [`KeyedFastArena`] for the thread-safe flavor.
+///
+/// ```
+/// use fast_bump::KeyedArena;
+///
+/// let mut lowered = KeyedArena::new();
+/// let a = lowered.get_or_alloc(7_u32, || "fn main");
+/// let b = lowered.get_or_alloc(7, || unreachable!());
+/// assert_eq!(a, b);
+/// assert_eq!(lowered[a], "fn main");
+/// ```
+pub struct KeyedArena<K, T> {
+    arena: Arena<T>,
+    keys: HashMap<K, Idx<T>>,
+}
+
+impl<K: Eq + Hash, T> KeyedArena<K, T> {
+    /// Creates an empty arena.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            arena: Arena::new(),
+            keys: HashMap::new(),
+        }
+    }
+
+    /// Returns the index stored for `key`, allocating the value returned
+    /// by `f` if there is none yet.
+    pub fn get_or_alloc(&mut self, key: K, f: impl FnOnce() -> T) -> Idx<T> {
+        *self
+            .keys
+            .entry(key)
+            .or_insert_with(|| self.arena.alloc(f()))
+    }
+
+    /// Returns the index stored for `key`, if any.
+    #[must_use]
+    pub fn get_idx<Q>(&self, key: &Q) -> Option<Idx<T>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.keys.get(key).copied()
+    }
+
+    /// Returns the number of keys, which equals the number of values.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if no value has been allocated.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<K, T> KeyedArena<K, T> {
+    /// Returns the arena holding the values.
+    #[must_use]
+    pub const fn arena(&self) -> &Arena<T> {
+        &self.arena
+    }
+
+    /// Returns a mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, idx: Idx<T>) -> &mut T {
+        self.arena.get_mut(idx)
+    }
+
+    /// Drops every value and forgets every key.
+    pub fn reset(&mut self) {
+        self.keys.clear();
+        self.arena.reset();
+    }
+}
+
+impl<K: Eq + Hash, T> Default for KeyedArena<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, T> std::ops::Index<Idx<T>> for KeyedArena<K, T> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.arena.get(idx)
+    }
+}
+
+impl<K, T> std::ops::IndexMut<Idx<T>> for KeyedArena<K, T> {
+    fn index_mut(&mut self, idx: Idx<T>) -> &mut T {
+        self.get_mut(idx)
+    }
+}
+
+impl<K, T> std::fmt::Debug for KeyedArena<K, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyedArena")
+            .field("len", &self.arena.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Thread-safe [`KeyedArena`] over a [`FastArena<T>`].
+///
+/// [`get_or_alloc`](KeyedFastArena::get_or_alloc) takes `&self`: a key is
+/// looked up under a shared lock and allocated under an exclusive one, so
+/// a key gets exactly one value even when threads race on it.
+pub struct KeyedFastArena<K, T> {
+    arena: FastArena<T>,
+    keys: RwLock<HashMap<K, Idx<T>>>,
+}
+
+impl<K: Eq + Hash, T> KeyedFastArena<K, T> {
+    /// Creates an arena with capacity for `capacity` values.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            arena: FastArena::with_capacity(capacity),
+            keys: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the index stored for `key`, allocating the value returned
+    /// by `f` if there is none yet.
+    ///
+    /// `f` runs outside the lock. Threads racing on a new key may each run
+    /// it; the first to take the lock allocates its value, and the others
+    /// drop theirs and return that index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value must be allocated and the arena is full, as
+    /// [`FastArena::alloc`] does.
+    pub fn get_or_alloc(&self, key: K, f: impl FnOnce() -> T) -> Idx<T> {
+        if let Some(&idx) = self
+            .keys
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+        {
+            return idx;
+        }
+        let value = f();
+        let mut keys = self.keys.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(&idx) = keys.get(&key) {
+            drop(keys);
+            // Lost the race; dropped outside the lock.
+            drop(value);
+            return idx;
+        }
+        let idx = self.arena.alloc(value);
+        keys.insert(key, idx);
+        idx
+    }
+
+    /// Returns the index stored for `key`, if any.
+    #[must_use]
+    pub fn get_idx<Q>(&self, key: &Q) -> Option<Idx<T>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.keys
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .copied()
+    }
+}
+
+impl<K, T> KeyedFastArena<K, T> {
+    /// Returns the arena holding the values.
+    #[must_use]
+    pub const fn arena(&self) -> &FastArena<T> {
+        &self.arena
+    }
+
+    /// Returns a mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, idx: Idx<T>) -> &mut T {
+        self.arena.get_mut(idx)
+    }
+
+    /// Grows the arena, as [`FastArena::grow`] does; existing indices stay
+    /// valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    pub fn grow(&mut self) {
+        self.arena.grow();
+    }
+
+    /// Drops every value and forgets every key.
+    pub fn reset(&mut self) {
+        self.keys
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        self.arena.reset();
+    }
+}
+
+impl<K, T> std::ops::Index<Idx<T>> for KeyedFastArena<K, T> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.arena.get(idx)
+    }
+}
+
+impl<K, T> std::ops::IndexMut<Idx<T>> for KeyedFastArena<K, T> {
+    fn index_mut(&mut self, idx: Idx<T>) -> &mut T {
+        self.get_mut(idx)
+    }
+}
+
+impl<K, T> std::fmt::Debug for KeyedFastArena<K, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyedFastArena")
+            .field("len", &self.arena.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -8,6 +8,12 @@
 //! - [`Arena<T>`] — single-thread, zero overhead, backed by [`Vec<T>`]
 //! - [`FastArena<T>`] — concurrent (`Send + Sync`), lock-free allocation,
 //!   contiguous `&[T]` slices, immediate `&T` access
+//! - [`SpscArena<T>`] — single writer, many readers; no readiness flags
+//! - [`FrozenArena<T>`] — immutable result of `freeze`, shareable without
+//!   locks
+//! - [`AnyArena`] — one `Arena<T>` per type behind a single handle
+//! - [`SoaArena<T>`] — struct-of-arrays layout, one column per field of a
+//!   [`Soa`] struct
 //!
 //! Both types share the same [`Idx<T>`] and [`Checkpoint<T>`] types, support
 //! checkpoint/rollback, and run destructors on rollback/reset/drop.
@@ -39,17 +45,120 @@
 
 #![deny(missing_docs)]
 
+mod any_arena;
 mod arena;
+mod arena_ref;
+mod backoff;
+mod bounded;
 mod checkpoint;
+mod drop_order;
+mod dyn_arena;
+mod epoch;
+mod error;
 mod fast_arena;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod frozen;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+mod graph;
+mod growth;
 mod idx;
+mod idx_range;
+mod idx_set;
+mod interner;
+mod items;
 mod iter;
+mod keyed;
+mod limit;
+mod observer;
+mod option_arena;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "rcu")]
+mod rcu;
+mod ready_bits;
+#[cfg(feature = "registry")]
+pub mod registry;
+mod remap;
+mod ring_arena;
+mod row_arena;
+mod savepoints;
+mod scrub;
+mod sharded;
+mod slot;
+mod small_arena;
+#[cfg(feature = "bytemuck")]
+mod snapshot;
+mod soa;
+mod split;
+mod spsc_arena;
+mod static_arena;
+mod stats;
+mod sync;
+mod trace;
+mod tree;
+mod value_index;
+mod view;
+mod wait;
+mod world;
 
-pub use arena::Arena;
+pub use any_arena::{AnyArena, AnyCheckpoint};
+pub use arena::{Arena, ArenaDrain, ArenaIntoIter};
+pub use arena_ref::ArenaRef;
+pub use bounded::{BoundedArena, BoundedWriter};
 pub use checkpoint::Checkpoint;
-pub use fast_arena::FastArena;
-pub use idx::Idx;
-pub use iter::{IterIndexed, IterIndexedMut};
+pub use drop_order::DropOrder;
+pub use dyn_arena::DynArena;
+pub use epoch::{EpochArena, EpochIdx};
+pub use error::{ArenaAllocError, Error};
+pub use fast_arena::{FastArena, FastArenaIntoIter, FastArenaIter};
+#[cfg(feature = "derive")]
+pub use fast_bump_derive::Soa;
+#[cfg(feature = "ffi")]
+pub use ffi::FfiSlice;
+pub use frozen::FrozenArena;
+#[cfg(feature = "arbitrary")]
+pub use fuzz::ArbitraryArena;
+pub use graph::{Bfs, Dfs, GraphArena};
+pub use growth::GrowthPolicy;
+pub use idx::{Idx, ReservedIdx};
+pub use idx_range::{IdxIter, IdxRange};
+pub use idx_set::IdxSet;
+pub use interner::Interner;
+pub use iter::{DrainIndexed, IterIndexed, IterIndexedMut, ZipIndexed, zip_indexed};
+pub use keyed::{KeyedArena, KeyedFastArena};
+pub use limit::ArenaLimit;
+pub use observer::ArenaObserver;
+pub use option_arena::{OptionArena, OptionEntry};
+#[cfg(feature = "rcu")]
+pub use rcu::RcuArena;
+pub use remap::IdxRemap;
+pub use ring_arena::RingArena;
+pub use row_arena::{RowArena, RowIdx};
+pub use savepoints::Savepoints;
+pub use sharded::ShardedArena;
+pub use slot::{LocalWriter, SlotGuard, SlotWriter, UninitSlot};
+pub use small_arena::SmallArena;
+pub use soa::{Soa, SoaArena};
+pub use split::{ArenaReader, ArenaWriter};
+pub use spsc_arena::{SpscArena, SpscWriter};
+pub use static_arena::StaticArena;
+pub use stats::{ArenaStats, RollbackStats};
+pub use tree::{Postorder, Preorder, TreeArena};
+pub use value_index::ValueIndex;
+pub use view::ArenaView;
+pub use wait::GetAsync;
+pub use world::WorldArena;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "derive"))]
+extern crate self as fast_bump;
+
+#[cfg(all(test, not(loom)))]
 mod tests;
+
+#[cfg(all(test, loom))]
+#[path = "tests/loom.rs"]
+mod loom_tests;
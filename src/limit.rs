@@ -0,0 +1,40 @@
+use std::fmt;
+
+/// Memory budget of an arena, set with
+/// [`Arena::with_limit`](crate::Arena::with_limit) or
+/// [`FastArena::with_limit`](crate::FastArena::with_limit).
+///
+/// Allocations past the budget make `try_alloc` return
+/// [`Error::Limit`](crate::Error::Limit) and `alloc` panic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArenaLimit {
+    /// At most this many values.
+    Items(usize),
+    /// At most this many bytes of values (`len * size_of::<T>()`).
+    Bytes(usize),
+}
+
+impl ArenaLimit {
+    /// Returns the number of `T` values that fit in the budget.
+    ///
+    /// A byte budget never limits zero-sized `T`.
+    #[must_use]
+    pub const fn max_items<T>(self) -> usize {
+        match self {
+            Self::Items(n) => n,
+            Self::Bytes(n) => match size_of::<T>() {
+                0 => usize::MAX,
+                size => n / size,
+            },
+        }
+    }
+}
+
+impl fmt::Display for ArenaLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Items(n) => write!(f, "{n} items"),
+            Self::Bytes(n) => write!(f, "{n} bytes"),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+/// Instrumentation hooks for arena events.
+///
+/// Install with [`Arena::set_observer`](crate::Arena::set_observer) or
+/// [`FastArena::set_observer`](crate::FastArena::set_observer). Every method
+/// has an empty default, so an observer only implements the events it cares
+/// about. Without an observer each event costs one untaken branch.
+///
+/// On a [`FastArena`](crate::FastArena), [`on_alloc`](Self::on_alloc) runs
+/// on the allocating thread, concurrently with other allocations.
+///
+/// Observers must be unwind safe so that arenas holding one stay
+/// `UnwindSafe` and `RefUnwindSafe`; atomics and `Mutex` qualify.
+pub trait ArenaObserver: Send + Sync + RefUnwindSafe + UnwindSafe {
+    /// Called after `count` values were allocated starting at index `start`.
+    fn on_alloc(&self, start: usize, count: usize) {
+        let _ = (start, count);
+    }
+
+    /// Called when a rollback shrinks the arena from `from_len` to `to_len`,
+    /// before the removed values are dropped.
+    fn on_rollback(&self, from_len: usize, to_len: usize) {
+        let _ = (from_len, to_len);
+    }
+
+    /// Called when a reset removes `len` values, before they are dropped.
+    fn on_reset(&self, len: usize) {
+        let _ = len;
+    }
+
+    /// Called after the backing storage grew from `old_capacity` to
+    /// `new_capacity` values.
+    fn on_grow(&self, old_capacity: usize, new_capacity: usize) {
+        let _ = (old_capacity, new_capacity);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::Idx;
+
+/// Arena of optional slots, filled lazily at pre-assigned indices.
+///
+/// Suited to sparse tables keyed by ids handed out elsewhere: the storage
+/// is a contiguous `Vec<Option<T>>` that grows to the highest index used,
+/// so lookups are a bounds check instead of a hash. Unlike the other
+/// arenas, slots can be filled in any order and emptied again.
+///
+/// ```
+/// use fast_bump::{Idx, OptionArena};
+///
+/// let mut types = OptionArena::new();
+/// let id = Idx::from_raw(3);
+/// *types.entry(id).or_insert_with(|| 0) += 1;
+/// *types.entry(id).or_insert_with(|| 0) += 1;
+/// assert_eq!(types.get(id), Some(&2));
+/// assert_eq!(types.get(Idx::from_raw(1)), None);
+/// ```
+pub struct OptionArena<T> {
+    slots: Vec<Option<T>>,
+    /// Number of filled slots.
+    filled: usize,
+}
+
+impl<T> OptionArena<T> {
+    /// Creates an empty arena.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            filled: 0,
+        }
+    }
+
+    /// Creates an empty arena with room for indices below `capacity`.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            filled: 0,
+        }
+    }
+
+    /// Returns the entry for `idx`, growing the storage to reach it.
+    pub fn entry(&mut self, idx: Idx<T>) -> OptionEntry<'_, T> {
+        let i = idx.into_raw();
+        if i >= self.slots.len() {
+            self.slots.resize_with(i + 1, || None);
+        }
+        OptionEntry {
+            idx,
+            slot: &mut self.slots[i],
+            filled: &mut self.filled,
+        }
+    }
+
+    /// Stores `value` at `idx`, returning the previous value.
+    pub fn insert(&mut self, idx: Idx<T>, value: T) -> Option<T> {
+        let entry = self.entry(idx);
+        let old = entry.slot.replace(value);
+        if old.is_none() {
+            *entry.filled += 1;
+        }
+        old
+    }
+
+    /// Empties the slot at `idx`, returning its value.
+    pub fn remove(&mut self, idx: Idx<T>) -> Option<T> {
+        let old = self.slots.get_mut(idx.into_raw())?.take();
+        if old.is_some() {
+            self.filled -= 1;
+        }
+        old
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if the slot is
+    /// empty.
+    #[must_use]
+    pub fn get(&self, idx: Idx<T>) -> Option<&T> {
+        self.slots.get(idx.into_raw())?.as_ref()
+    }
+
+    /// Returns a mutable reference to the value at `idx`, or `None` if the
+    /// slot is empty.
+    #[must_use]
+    pub fn get_mut(&mut self, idx: Idx<T>) -> Option<&mut T> {
+        self.slots.get_mut(idx.into_raw())?.as_mut()
+    }
+
+    /// Returns `true` if the slot at `idx` is filled.
+    #[must_use]
+    pub fn contains(&self, idx: Idx<T>) -> bool {
+        self.get(idx).is_some()
+    }
+
+    /// Returns the number of filled slots.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.filled
+    }
+
+    /// Returns `true` if no slot is filled.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.filled == 0
+    }
+
+    /// Returns an iterator yielding `(Idx<T>, &T)` for the filled slots in
+    /// index order.
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (Idx<T>, &T)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(i, slot)| Some((Idx::from_raw(i), slot.as_ref()?)))
+    }
+
+    /// Empties every slot, keeping the storage.
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.filled = 0;
+    }
+}
+
+impl<T> Default for OptionArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::ops::Index<Idx<T>> for OptionArena<T> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.get(idx).expect("slot is empty")
+    }
+}
+
+impl<T> std::ops::IndexMut<Idx<T>> for OptionArena<T> {
+    fn index_mut(&mut self, idx: Idx<T>) -> &mut T {
+        self.get_mut(idx).expect("slot is empty")
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for OptionArena<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter_indexed()).finish()
+    }
+}
+
+/// A slot of an [`OptionArena`], filled or empty.
+///
+/// Created by [`OptionArena::entry`].
+pub struct OptionEntry<'a, T> {
+    idx: Idx<T>,
+    slot: &'a mut Option<T>,
+    filled: &'a mut usize,
+}
+
+impl<'a, T> OptionEntry<'a, T> {
+    /// Returns the index of the slot.
+    #[must_use]
+    pub const fn idx(&self) -> Idx<T> {
+        self.idx
+    }
+
+    /// Returns `true` if the slot is filled.
+    #[must_use]
+    pub const fn is_filled(&self) -> bool {
+        self.slot.is_some()
+    }
+
+    /// Fills the slot with `value` if it is empty, returning the value in
+    /// the slot.
+    pub fn or_insert(self, value: T) -> &'a mut T {
+        self.or_insert_with(|| value)
+    }
+
+    /// Fills the slot with the result of `f` if it is empty, returning the
+    /// value in the slot.
+    pub fn or_insert_with(self, f: impl FnOnce() -> T) -> &'a mut T {
+        if self.slot.is_none() {
+            // Counted only once `f` has returned, so a panic in it leaves
+            // the count matching the slots.
+            let value = f();
+            *self.filled += 1;
+            return self.slot.insert(value);
+        }
+        self.slot.get_or_insert_with(f)
+    }
+
+    /// Fills the slot with `T::default()` if it is empty, returning the
+    /// value in the slot.
+    pub fn or_default(self) -> &'a mut T
+    where
+        T: Default,
+    {
+        self.or_insert_with(T::default)
+    }
+
+    /// Runs `f` on the value if the slot is filled.
+    #[must_use]
+    pub fn and_modify(self, f: impl FnOnce(&mut T)) -> Self {
+        if let Some(value) = self.slot.as_mut() {
+            f(value);
+        }
+        self
+    }
+}
@@ -0,0 +1,126 @@
+//! Parallel iteration over arenas with rayon.
+
+use rayon::prelude::*;
+
+use crate::{Arena, FastArena, Idx, IdxRange};
+
+impl<T: Sync> Arena<T> {
+    /// Returns a parallel iterator over the items.
+    ///
+    /// Requires the `rayon` feature.
+    #[must_use]
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &T> {
+        self.as_slice().par_iter()
+    }
+}
+
+// `Idx<T>` is only `Send` when `T` is.
+impl<T: Send + Sync> Arena<T> {
+    /// Returns a parallel iterator yielding `(Idx<T>, &T)` pairs.
+    ///
+    /// Requires the `rayon` feature.
+    #[must_use]
+    pub fn par_iter_indexed(&self) -> impl IndexedParallelIterator<Item = (Idx<T>, &T)> {
+        self.par_iter()
+            .enumerate()
+            .map(|(i, v)| (Idx::from_raw(i), v))
+    }
+}
+
+impl<T: Send> Arena<T> {
+    /// Returns a parallel iterator over mutable references to the items.
+    ///
+    /// Requires the `rayon` feature.
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T> {
+        self.as_mut_slice().par_iter_mut()
+    }
+
+    /// Returns a parallel iterator yielding `(Idx<T>, &mut T)` pairs.
+    ///
+    /// Requires the `rayon` feature.
+    pub fn par_iter_indexed_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = (Idx<T>, &mut T)> {
+        self.par_iter_mut()
+            .enumerate()
+            .map(|(i, v)| (Idx::from_raw(i), v))
+    }
+}
+
+impl<T: Sync> FastArena<T> {
+    /// Returns a parallel iterator over the published items.
+    ///
+    /// Requires the `rayon` feature.
+    #[must_use]
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &T> {
+        self.as_slice().par_iter()
+    }
+}
+
+// `Idx<T>` is only `Send` when `T` is.
+impl<T: Send + Sync> FastArena<T> {
+    /// Returns a parallel iterator yielding `(Idx<T>, &T)` pairs for the
+    /// published items.
+    ///
+    /// Requires the `rayon` feature.
+    #[must_use]
+    pub fn par_iter_indexed(&self) -> impl IndexedParallelIterator<Item = (Idx<T>, &T)> {
+        self.par_iter()
+            .enumerate()
+            .map(|(i, v)| (Idx::from_raw(i), v))
+    }
+}
+
+impl<T: Send> FastArena<T> {
+    /// Returns a parallel iterator over mutable references to the
+    /// published items.
+    ///
+    /// Requires the `rayon` feature.
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T> {
+        self.as_mut_slice().par_iter_mut()
+    }
+
+    /// Returns a parallel iterator yielding `(Idx<T>, &mut T)` pairs for
+    /// the published items.
+    ///
+    /// Requires the `rayon` feature.
+    pub fn par_iter_indexed_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = (Idx<T>, &mut T)> {
+        self.par_iter_mut()
+            .enumerate()
+            .map(|(i, v)| (Idx::from_raw(i), v))
+    }
+}
+
+impl<T: Send> FastArena<T> {
+    /// Builds an arena from an indexed parallel iterator, returning it with
+    /// the range of the new items.
+    ///
+    /// The storage is sized from the iterator's length up front and every
+    /// worker thread writes its items straight into their final slots;
+    /// item `i` gets `Idx::from_raw(i)`. The capacity has no room to spare,
+    /// so [`grow`](FastArena::grow) the arena before allocating more.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// ```
+    /// use fast_bump::FastArena;
+    /// use rayon::prelude::*;
+    ///
+    /// let (arena, range) = FastArena::from_par_iter((0..1000).into_par_iter().map(|i| i * 2));
+    /// assert_eq!(range.len(), 1000);
+    /// assert_eq!(arena[range.start()], 0);
+    /// assert_eq!(arena.as_slice()[999], 1998);
+    /// ```
+    pub fn from_par_iter<I>(iter: I) -> (Self, IdxRange<T>)
+    where
+        I: IntoParallelIterator<Item = T>,
+        I::Iter: IndexedParallelIterator,
+    {
+        let mut items = Vec::new();
+        iter.into_par_iter().collect_into_vec(&mut items);
+        let range = IdxRange::from_raw(0, items.len());
+        (Self::from(items), range)
+    }
+}
@@ -0,0 +1,131 @@
+//! Byte views of arenas holding [`Pod`] values.
+
+use bytemuck::{Pod, PodCastError, Zeroable};
+
+use crate::{Arena, ArenaAllocError, FastArena};
+
+/// Copies `bytes` into a freshly allocated `Vec<T>` with a single memcpy.
+fn vec_from_bytes<T: Pod>(bytes: &[u8]) -> Result<Vec<T>, PodCastError> {
+    let size = size_of::<T>();
+    if bytes.len().checked_rem(size) != Some(0) {
+        return Err(PodCastError::OutputSliceWouldHaveSlop);
+    }
+    let mut items = vec![T::zeroed(); bytes.len() / size];
+    bytemuck::cast_slice_mut::<T, u8>(&mut items).copy_from_slice(bytes);
+    Ok(items)
+}
+
+/// Returns `n` zeroed values straight from `alloc_zeroed`, which can map
+/// fresh zero pages instead of writing every byte.
+pub fn zeroed_vec<T: Zeroable>(n: usize) -> Vec<T> {
+    let layout = std::alloc::Layout::array::<T>(n)
+        .unwrap_or_else(|_| ArenaAllocError::CapacityOverflow.handle());
+    let data = if layout.size() == 0 {
+        // No storage needed: `n` is 0 or `T` is zero-sized.
+        std::ptr::NonNull::<T>::dangling().as_ptr()
+    } else {
+        // SAFETY: `layout` has a non-zero size.
+        let data = unsafe { std::alloc::alloc_zeroed(layout) }.cast::<T>();
+        if data.is_null() {
+            ArenaAllocError::AllocFailed { layout }.handle();
+        }
+        data
+    };
+    let items = std::ptr::slice_from_raw_parts_mut(data, n);
+    // SAFETY: `data` is dangling for a zero-sized layout, which `Box`
+    // accepts, or was allocated by the global allocator with the layout of
+    // `[T; n]`; all-zero bytes are a valid `T`.
+    unsafe { Box::from_raw(items) }.into_vec()
+}
+
+impl<T: Zeroable> Arena<T> {
+    /// Creates an arena holding `n` zeroed values at indices `0..n`.
+    ///
+    /// The storage comes from `alloc_zeroed`, which typically maps fresh
+    /// zero pages instead of initializing large arenas value by value.
+    ///
+    /// Requires the `bytemuck` feature.
+    #[must_use]
+    pub fn with_len_zeroed(n: usize) -> Self {
+        Self::from(zeroed_vec(n))
+    }
+}
+
+impl<T: Zeroable> FastArena<T> {
+    /// Creates an arena with `n` zeroed values already published at
+    /// indices `0..n`, like [`Arena::with_len_zeroed`].
+    ///
+    /// The arena is full; [`grow`](FastArena::grow) it before allocating
+    /// more.
+    ///
+    /// Requires the `bytemuck` feature.
+    #[must_use]
+    pub fn with_len_zeroed(n: usize) -> Self {
+        Self::from(zeroed_vec(n))
+    }
+}
+
+impl<T: Pod> Arena<T> {
+    /// Returns the items as raw bytes, without copying.
+    ///
+    /// Requires the `bytemuck` feature.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Returns the items as mutable raw bytes, without copying.
+    ///
+    /// Requires the `bytemuck` feature.
+    #[must_use]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+
+    /// Builds an arena from bytes produced by [`as_bytes`](Arena::as_bytes).
+    ///
+    /// `bytes` need not be aligned for `T`; they are copied once into the
+    /// new storage. Index `i` refers to the `i`-th `size_of::<T>()` chunk.
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PodCastError::OutputSliceWouldHaveSlop`] if the length of
+    /// `bytes` is not a multiple of `size_of::<T>()`, or `T` is zero-sized.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PodCastError> {
+        vec_from_bytes(bytes).map(Self::from)
+    }
+}
+
+impl<T: Pod> FastArena<T> {
+    /// Returns the published items as raw bytes, without copying.
+    ///
+    /// Requires the `bytemuck` feature.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Returns the published items as mutable raw bytes, without copying.
+    ///
+    /// Requires the `bytemuck` feature.
+    #[must_use]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+
+    /// Builds an arena from bytes produced by
+    /// [`as_bytes`](FastArena::as_bytes), like
+    /// [`Arena::from_bytes`].
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PodCastError::OutputSliceWouldHaveSlop`] if the length of
+    /// `bytes` is not a multiple of `size_of::<T>()`, or `T` is zero-sized.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PodCastError> {
+        vec_from_bytes(bytes).map(Self::from)
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+use crate::backoff::Backoff;
+use crate::{Checkpoint, FastArena, Idx};
+
+/// [`FastArena`] that can be reset through a shared reference.
+///
+/// Holds the current generation behind an `Arc`. Readers and writers
+/// [`load`](RcuArena::load) the generation they work on;
+/// [`reset_deferred`](RcuArena::reset_deferred) swaps in fresh storage
+/// immediately, and the old generation is reclaimed once the last handle to
+/// it is dropped (read-copy-update). An `Arc`-shared pipeline can therefore
+/// start a new generation without being torn down.
+///
+/// Indices are only meaningful for the generation that issued them; compare
+/// [`generation`](RcuArena::generation) numbers if they can cross a reset.
+///
+/// Requires the `rcu` feature.
+pub struct RcuArena<T> {
+    current: RwLock<Generation<T>>,
+    rollback: Mutex<()>,
+}
+
+struct Generation<T> {
+    arena: Arc<FastArena<T>>,
+    number: u64,
+}
+
+impl<T> RcuArena<T> {
+    /// Creates an arena whose generations have the default initial capacity.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_arena(FastArena::new())
+    }
+
+    /// Creates an arena whose generations hold `capacity` items.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_arena(FastArena::with_capacity(capacity))
+    }
+
+    /// Wraps `arena` as generation 0.
+    #[must_use]
+    pub fn from_arena(arena: FastArena<T>) -> Self {
+        Self {
+            current: RwLock::new(Generation {
+                arena: Arc::new(arena),
+                number: 0,
+            }),
+            rollback: Mutex::new(()),
+        }
+    }
+
+    /// Returns a handle to the current generation.
+    ///
+    /// The handle keeps that generation alive across a concurrent reset, so
+    /// indices read through it stay valid for as long as it is held.
+    #[must_use]
+    pub fn load(&self) -> Arc<FastArena<T>> {
+        Arc::clone(&self.read().arena)
+    }
+
+    /// Returns the number of resets performed so far.
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.read().number
+    }
+
+    /// Allocates `value` in the current generation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current generation is full.
+    pub fn alloc(&self, value: T) -> Idx<T> {
+        self.read().arena.alloc(value)
+    }
+
+    /// Starts a new, empty generation with the same capacity.
+    ///
+    /// Takes effect immediately for subsequent [`load`](RcuArena::load)s.
+    /// Values of the old generation are dropped, and its storage freed, when
+    /// the last handle to it goes away — possibly right here if none is held.
+    /// Allocations made through an old handle after the reset land in the
+    /// old generation and are discarded with it.
+    pub fn reset_deferred(&self) {
+        let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+        let fresh = Arc::new(FastArena::with_capacity(current.arena.capacity()));
+        let old = std::mem::replace(&mut current.arena, fresh);
+        current.number += 1;
+        drop(current);
+        // Dropped outside the lock: may run destructors.
+        drop(old);
+    }
+
+    /// Rolls back the current generation to `cp` without exclusive access.
+    ///
+    /// Waits until every other handle to the current generation has been
+    /// dropped, so no reader can still see a value past `cp`. The lock is
+    /// not held while waiting: [`load`](RcuArena::load),
+    /// [`alloc`](RcuArena::alloc) and [`checkpoint`](RcuArena::checkpoint)
+    /// keep working, and values allocated meanwhile land past `cp` and are
+    /// discarded with the tail. The values after `cp` are then dropped in
+    /// place; the ones before it are neither moved nor cloned, so the cost
+    /// is that of the discarded tail alone. Indices before `cp` stay valid,
+    /// and the generation number is bumped as by
+    /// [`reset_deferred`](RcuArena::reset_deferred). If a reset replaces the
+    /// generation first, there is nothing left to roll back and this
+    /// returns without further effect.
+    ///
+    /// The calling thread must not hold a handle from `load` itself, or it
+    /// waits forever. Concurrent rollbacks run one after the other.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cp` is beyond the current generation's published length.
+    pub fn rollback_deferred(&self, cp: Checkpoint<T>) {
+        let _serial = self.rollback.lock().unwrap_or_else(PoisonError::into_inner);
+        let target = {
+            let current = self.read();
+            let len = current.arena.len();
+            assert!(
+                cp.len() <= len,
+                "checkpoint {} beyond current length {len}",
+                cp.len(),
+            );
+            Arc::clone(&current.arena)
+        };
+        let mut backoff = Backoff::new();
+        loop {
+            let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+            if !Arc::ptr_eq(&current.arena, &target) {
+                drop(current);
+                // Dropped outside the lock: may run destructors.
+                drop(target);
+                return;
+            }
+            // `target` and `current` are the last two handles; with the
+            // lock held, no new one can be loaded.
+            if Arc::strong_count(&target) == 2 {
+                drop(target);
+                let arena = Arc::get_mut(&mut current.arena).expect("sole handle");
+                arena.rollback(cp);
+                current.number += 1;
+                return;
+            }
+            drop(current);
+            backoff.snooze();
+        }
+    }
+
+    /// Saves the allocation state of the current generation.
+    #[must_use]
+    pub fn checkpoint(&self) -> Checkpoint<T> {
+        self.read().arena.checkpoint()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Generation<T>> {
+        self.current.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Default for RcuArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,112 @@
+use crate::ArenaAllocError;
+use crate::sync::{AtomicU64, Exclusive, Ordering};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Packed per-slot readiness flags, one bit per slot.
+///
+/// Writers set their slot's bit with a `Release` RMW after writing the
+/// value; readers test it with `Acquire`. Clearing requires `&mut self`.
+pub struct ReadyBits {
+    words: Box<[AtomicU64]>,
+}
+
+impl ReadyBits {
+    /// Creates cleared flags for `cap` slots.
+    pub fn try_new(cap: usize) -> Result<Self, ArenaAllocError> {
+        let mut words = Vec::new();
+        grow_words(&mut words, cap.div_ceil(WORD_BITS))?;
+        Ok(Self {
+            words: words.into_boxed_slice(),
+        })
+    }
+
+    /// Marks `slot` ready.
+    pub fn set(&self, slot: usize) {
+        self.words[slot / WORD_BITS].fetch_or(bit(slot), Ordering::Release);
+    }
+
+    /// Marks the slots `start..end` ready, with one RMW per word.
+    pub fn set_range(&self, start: usize, end: usize) {
+        let mut slot = start;
+        while slot < end {
+            let word = slot / WORD_BITS;
+            let upto = end.min((word + 1) * WORD_BITS);
+            let lo = slot % WORD_BITS;
+            let hi = upto - word * WORD_BITS;
+            let mask = (u64::MAX >> (WORD_BITS - hi)) & (u64::MAX << lo);
+            self.words[word].fetch_or(mask, Ordering::Release);
+            slot = upto;
+        }
+    }
+
+    /// Returns `true` if `slot` is marked ready.
+    pub fn is_set(&self, slot: usize) -> bool {
+        self.words[slot / WORD_BITS].load(Ordering::Acquire) & bit(slot) != 0
+    }
+
+    /// Clears the flags of all slots `>= from`.
+    pub fn clear_from(&mut self, from: usize) {
+        let first = from / WORD_BITS;
+        if let Some(word) = self.words.get_mut(first) {
+            let kept = word.read() & (bit(from) - 1);
+            word.write(kept);
+        }
+        for word in self.words.iter_mut().skip(first + 1) {
+            word.write(0);
+        }
+    }
+
+    /// Clears the flags of all slots `< to`.
+    pub fn clear_to(&mut self, to: usize) {
+        let last = to / WORD_BITS;
+        for word in self.words.iter_mut().take(last) {
+            word.write(0);
+        }
+        if let Some(word) = self.words.get_mut(last) {
+            let kept = word.read() & !(bit(to) - 1);
+            word.write(kept);
+        }
+    }
+
+    /// Sets the flags of all slots `< to`.
+    pub fn set_to(&mut self, to: usize) {
+        let last = to / WORD_BITS;
+        for word in self.words.iter_mut().take(last) {
+            word.write(u64::MAX);
+        }
+        if let Some(word) = self.words.get_mut(last) {
+            let set = word.read() | (bit(to) - 1);
+            word.write(set);
+        }
+    }
+
+    /// Returns the heap memory used by the flags, in bytes.
+    pub const fn heap_bytes(&self) -> usize {
+        size_of_val::<[AtomicU64]>(&self.words)
+    }
+
+    /// Grows the flags to cover `cap` slots, preserving existing bits.
+    ///
+    /// On failure the flags are left unchanged.
+    pub fn try_grow(&mut self, cap: usize) -> Result<(), ArenaAllocError> {
+        let mut words = std::mem::take(&mut self.words).into_vec();
+        let grown = grow_words(&mut words, cap.div_ceil(WORD_BITS));
+        self.words = words.into_boxed_slice();
+        grown
+    }
+}
+
+/// Appends cleared words to `words` until it holds `len`.
+fn grow_words(words: &mut Vec<AtomicU64>, len: usize) -> Result<(), ArenaAllocError> {
+    let additional = len.saturating_sub(words.len());
+    words
+        .try_reserve_exact(additional)
+        .map_err(|_| ArenaAllocError::for_capacity::<AtomicU64>(len))?;
+    words.resize_with(len, || AtomicU64::new(0));
+    Ok(())
+}
+
+const fn bit(slot: usize) -> u64 {
+    1 << (slot % WORD_BITS)
+}
@@ -0,0 +1,134 @@
+//! Global registry of arenas for diagnostics dumps.
+//!
+//! Requires the `registry` feature. Register an arena shared through an
+//! `Arc` with [`register`] or [`register_named`], then call [`dump`] to get
+//! the statistics of every registered arena that is still alive:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use fast_bump::{FastArena, registry};
+//!
+//! let types = Arc::new(FastArena::<u64>::with_capacity(1024));
+//! registry::register_named("types", &types);
+//! types.alloc(7);
+//!
+//! let report = registry::dump();
+//! let entry = report.iter().find(|r| r.name.as_deref() == Some("types")).unwrap();
+//! assert_eq!(entry.stats.len, 1);
+//! ```
+//!
+//! The registry holds weak references: registering never keeps an arena
+//! alive, and dropped arenas disappear from the next dump.
+
+use std::sync::{Arc, Mutex, PoisonError, RwLock, Weak};
+
+use crate::{Arena, ArenaStats, FastArena};
+
+/// An arena whose statistics can be reported by the registry.
+///
+/// Implemented for [`FastArena`] and for an [`Arena`] behind a `Mutex` or
+/// `RwLock`.
+pub trait StatsSource: Send + Sync {
+    /// Returns the current statistics.
+    fn stats(&self) -> ArenaStats;
+
+    /// Returns the element type name, for the report.
+    fn type_name(&self) -> &'static str;
+}
+
+impl<T: Send + Sync> StatsSource for FastArena<T> {
+    fn stats(&self) -> ArenaStats {
+        Self::stats(self)
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+}
+
+impl<T: Send> StatsSource for Mutex<Arena<T>> {
+    fn stats(&self) -> ArenaStats {
+        self.lock().unwrap_or_else(PoisonError::into_inner).stats()
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+}
+
+impl<T: Send + Sync> StatsSource for RwLock<Arena<T>> {
+    fn stats(&self) -> ArenaStats {
+        self.read().unwrap_or_else(PoisonError::into_inner).stats()
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+}
+
+/// One registered arena in a [`dump`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArenaReport {
+    /// Name given to [`register_named`], if any.
+    pub name: Option<String>,
+    /// Element type of the arena.
+    pub type_name: &'static str,
+    /// Statistics at the time of the dump.
+    pub stats: ArenaStats,
+}
+
+struct Entry {
+    name: Option<String>,
+    source: Weak<dyn StatsSource>,
+}
+
+static REGISTRY: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+
+/// Registers `arena` without a name.
+pub fn register<A: StatsSource + 'static>(arena: &Arc<A>) {
+    insert(None, arena);
+}
+
+/// Registers `arena` under `name`.
+pub fn register_named<A: StatsSource + 'static>(name: impl Into<String>, arena: &Arc<A>) {
+    insert(Some(name.into()), arena);
+}
+
+fn insert<A: StatsSource + 'static>(name: Option<String>, arena: &Arc<A>) {
+    let source: Arc<dyn StatsSource> = Arc::<A>::clone(arena);
+    let entry = Entry {
+        name,
+        source: Arc::downgrade(&source),
+    };
+    lock().push(entry);
+}
+
+/// Returns the statistics of every registered arena that is still alive, in
+/// registration order.
+///
+/// Entries of dropped arenas are removed.
+#[must_use]
+pub fn dump() -> Vec<ArenaReport> {
+    let live: Vec<_> = {
+        let mut entries = lock();
+        entries.retain(|entry| entry.source.strong_count() > 0);
+        entries
+            .iter()
+            .filter_map(|entry| Some((entry.name.clone(), entry.source.upgrade()?)))
+            .collect()
+    };
+    // Sources are queried, and possibly dropped, outside the lock: their
+    // `stats` may take the arena's own locks.
+    live.into_iter()
+        .map(|(name, source)| ArenaReport {
+            name,
+            type_name: source.type_name(),
+            stats: source.stats(),
+        })
+        .collect()
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Vec<Entry>> {
+    REGISTRY.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,161 @@
+use std::marker::PhantomData;
+
+use crate::Idx;
+
+/// `new_of_old` entry for an index whose value was removed.
+#[allow(clippy::redundant_pub_crate)] // crate-internal even if `remap` is ever made public
+pub(crate) const REMOVED: usize = usize::MAX;
+
+/// Mapping from the indices before an operation that moves items to the
+/// indices after it.
+///
+/// Returned by every operation that shuffles an arena's items:
+/// [`Arena::sort_by`](crate::Arena::sort_by) reorders them,
+/// [`Arena::retain`](crate::Arena::retain) compacts them and
+/// [`Arena::append`](crate::Arena::append) moves them to another arena.
+/// Pass each `Idx<T>` held outside the arena through
+/// [`remap`](IdxRemap::remap) to keep pointing at the same value; indices
+/// of removed values map to `None` under [`try_remap`](IdxRemap::try_remap).
+///
+/// ```
+/// use fast_bump::Arena;
+///
+/// let mut arena: Arena<i32> = (0..5).collect();
+/// let mut ids: Vec<_> = arena.keys().collect();
+/// let remap = arena.retain(|&v| v % 2 == 0);
+/// ids.retain(|&idx| remap.try_remap(idx).is_some());
+/// remap.remap_slice(&mut ids);
+/// assert_eq!(ids.iter().map(|&idx| arena[idx]).collect::<Vec<_>>(), [0, 2, 4]);
+/// ```
+pub struct IdxRemap<T> {
+    /// `new_of_old[old] = new`, or `REMOVED`.
+    new_of_old: Vec<usize>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> IdxRemap<T> {
+    pub(crate) const fn from_new_of_old(new_of_old: Vec<usize>) -> Self {
+        Self {
+            new_of_old,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Maps `old..old + len` to `start..start + len`.
+    pub(crate) fn shifted(len: usize, start: usize) -> Self {
+        Self::from_new_of_old((start..start + len).collect())
+    }
+
+    /// Returns the new index of the value previously at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` was not valid before the operation, or if its value
+    /// was removed.
+    #[must_use]
+    pub fn remap(&self, idx: Idx<T>) -> Idx<T> {
+        self.try_remap(idx).unwrap_or_else(|| {
+            let i = idx.into_raw();
+            assert!(
+                i >= self.new_of_old.len(),
+                "index {i} refers to a removed value",
+            );
+            panic!(
+                "index out of bounds: index is {i} but remap length is {}",
+                self.new_of_old.len(),
+            )
+        })
+    }
+
+    /// Returns the new index of the value previously at `idx`, or `None`
+    /// if `idx` was not valid before the operation or its value was
+    /// removed.
+    #[must_use]
+    pub fn try_remap(&self, idx: Idx<T>) -> Option<Idx<T>> {
+        match self.new_of_old.get(idx.into_raw()) {
+            Some(&new) if new != REMOVED => Some(Idx::from_raw(new)),
+            _ => None,
+        }
+    }
+
+    /// Remaps every index in `ids` in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics like [`remap`](IdxRemap::remap) on the first index that
+    /// cannot be remapped; indices before it are already updated.
+    pub fn remap_slice(&self, ids: &mut [Idx<T>]) {
+        for idx in ids {
+            *idx = self.remap(*idx);
+        }
+    }
+
+    /// Returns an iterator yielding `(old, new)` for every index covered,
+    /// with `None` for removed values.
+    #[must_use]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Idx<T>, Option<Idx<T>>)> + '_ {
+        (0..self.new_of_old.len()).map(|old| {
+            let old = Idx::from_raw(old);
+            (old, self.try_remap(old))
+        })
+    }
+
+    /// Returns the number of indices covered by the mapping.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.new_of_old.len()
+    }
+
+    /// Returns `true` if the mapping covers no indices.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.new_of_old.is_empty()
+    }
+
+    /// Returns the number of indices whose value was removed.
+    #[must_use]
+    pub fn removed(&self) -> usize {
+        self.new_of_old
+            .iter()
+            .filter(|&&new| new == REMOVED)
+            .count()
+    }
+
+    /// Returns `true` if every index maps to itself.
+    #[must_use]
+    pub fn is_identity(&self) -> bool {
+        self.new_of_old.iter().enumerate().all(|(old, &new)| old == new)
+    }
+}
+
+impl<T> std::fmt::Debug for IdxRemap<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(
+                self.iter()
+                    .map(|(old, new)| (old.into_raw(), new.map(Idx::into_raw))),
+            )
+            .finish()
+    }
+}
+
+/// Reorders `items` so position `new` receives the item previously at
+/// `order[new]`.
+///
+/// Returns the inverse, `new_of_old` mapping: `new_of_old[order[new]] = new`.
+#[allow(clippy::redundant_pub_crate)] // crate-internal even if `remap` is ever made public
+pub(crate) fn apply_order<T>(items: &mut [T], order: &[usize]) -> Vec<usize> {
+    let mut new_of_old = vec![0; order.len()];
+    for (new, &old) in order.iter().enumerate() {
+        new_of_old[old] = new;
+    }
+    let mut target = new_of_old.clone();
+    for i in 0..items.len() {
+        while target[i] != i {
+            let j = target[i];
+            items.swap(i, j);
+            target.swap(i, j);
+        }
+    }
+    new_of_old
+}
@@ -0,0 +1,246 @@
+use std::collections::VecDeque;
+
+use crate::{ArenaLimit, Idx};
+
+/// Called with each value [`RingArena`] evicts, before it is dropped.
+type EvictHook<T> = Box<dyn FnMut(Idx<T>, &mut T) + Send>;
+
+/// Arena over a bounded sliding window of the most recent values.
+///
+/// Once the arena holds as many values as its [`ArenaLimit`] allows,
+/// every [`alloc`](RingArena::alloc) evicts the oldest value: the
+/// [eviction hook](RingArena::set_evict_hook) sees it, then its destructor
+/// runs. Indices keep counting up and are never reused, so an evicted
+/// index is reported as such instead of aliasing a newer value:
+/// [`try_get`](RingArena::try_get) returns `None` for it.
+///
+/// ```
+/// use fast_bump::RingArena;
+///
+/// let mut window = RingArena::new(2);
+/// let a = window.alloc("a");
+/// let b = window.alloc("b");
+/// let c = window.alloc("c"); // evicts "a"
+///
+/// assert_eq!(window.try_get(a), None);
+/// assert_eq!(window[b], "b");
+/// assert_eq!(window[c], "c");
+/// ```
+pub struct RingArena<T> {
+    items: VecDeque<T>,
+    /// Index of `items[0]`; also the number of values evicted or reset.
+    first: usize,
+    max_items: usize,
+    limit: ArenaLimit,
+    on_evict: Option<EvictHook<T>>,
+}
+
+impl<T> RingArena<T> {
+    /// Creates an arena keeping the `capacity` most recent values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::with_limit(ArenaLimit::Items(capacity))
+    }
+
+    /// Creates an arena keeping as many of the most recent values as
+    /// `limit` allows. A byte budget counts `size_of::<T>()` per value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the budget does not fit a single value.
+    #[must_use]
+    pub fn with_limit(limit: ArenaLimit) -> Self {
+        let max_items = limit.max_items::<T>();
+        assert!(max_items > 0, "ring arena limit of {limit} fits no value");
+        Self {
+            items: VecDeque::new(),
+            first: 0,
+            max_items,
+            limit,
+            on_evict: None,
+        }
+    }
+
+    /// Returns the budget the window is bounded by.
+    #[must_use]
+    pub const fn limit(&self) -> ArenaLimit {
+        self.limit
+    }
+
+    /// Installs a hook called with each evicted value and its index, before
+    /// the value is dropped. Not called on [`reset`](RingArena::reset) or
+    /// when the arena is dropped.
+    pub fn set_evict_hook(&mut self, hook: impl FnMut(Idx<T>, &mut T) + Send + 'static) {
+        self.on_evict = Some(Box::new(hook));
+    }
+
+    /// Removes the eviction hook.
+    pub fn clear_evict_hook(&mut self) {
+        self.on_evict = None;
+    }
+
+    /// Allocates a value, evicting the oldest one if the window is full.
+    pub fn alloc(&mut self, value: T) -> Idx<T> {
+        if self.items.len() == self.max_items {
+            self.evict_oldest();
+        }
+        let idx = Idx::from_raw(self.first + self.items.len());
+        self.items.push_back(value);
+        idx
+    }
+
+    fn evict_oldest(&mut self) {
+        if let Some(mut value) = self.items.pop_front() {
+            let idx = Idx::from_raw(self.first);
+            self.first += 1;
+            if let Some(hook) = &mut self.on_evict {
+                hook(idx, &mut value);
+            }
+        }
+    }
+
+    /// Returns a reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value at `idx` was evicted or not allocated yet.
+    #[must_use]
+    pub fn get(&self, idx: Idx<T>) -> &T {
+        self.try_get(idx)
+            .unwrap_or_else(|| self.outside_window(idx))
+    }
+
+    /// Returns a mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value at `idx` was evicted or not allocated yet.
+    #[must_use]
+    pub fn get_mut(&mut self, idx: Idx<T>) -> &mut T {
+        if !self.is_valid(idx) {
+            self.outside_window(idx);
+        }
+        &mut self.items[idx.into_raw() - self.first]
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if it was
+    /// evicted or not allocated yet.
+    #[must_use]
+    pub fn try_get(&self, idx: Idx<T>) -> Option<&T> {
+        self.items.get(idx.into_raw().checked_sub(self.first)?)
+    }
+
+    /// Returns a mutable reference to the value at `idx`, or `None` if it
+    /// was evicted or not allocated yet.
+    #[must_use]
+    pub fn try_get_mut(&mut self, idx: Idx<T>) -> Option<&mut T> {
+        self.items.get_mut(idx.into_raw().checked_sub(self.first)?)
+    }
+
+    /// Returns `true` if `idx` refers to a value still in the window.
+    #[must_use]
+    pub fn is_valid(&self, idx: Idx<T>) -> bool {
+        (self.first..self.first + self.items.len()).contains(&idx.into_raw())
+    }
+
+    /// Returns the index of the oldest value still in the window.
+    #[must_use]
+    pub fn first_idx(&self) -> Option<Idx<T>> {
+        (!self.items.is_empty()).then(|| Idx::from_raw(self.first))
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the window is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the maximum number of values the window holds.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.max_items
+    }
+
+    /// Returns the total number of values evicted or reset so far.
+    #[must_use]
+    pub const fn evicted(&self) -> usize {
+        self.first
+    }
+
+    /// Drops every value without calling the eviction hook. Indices handed
+    /// out before stay invalid; new ones continue the count.
+    pub fn reset(&mut self) {
+        self.first += self.items.len();
+        self.items.clear();
+    }
+
+    /// Returns an iterator over the window, oldest first.
+    #[must_use]
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Returns an iterator yielding `(Idx<T>, &T)` pairs, oldest first.
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (Idx<T>, &T)> {
+        (self.first..).map(Idx::from_raw).zip(&self.items)
+    }
+
+    #[cold]
+    fn outside_window(&self, idx: Idx<T>) -> ! {
+        panic!(
+            "index {} outside window {}..{}",
+            idx.into_raw(),
+            self.first,
+            self.first + self.items.len(),
+        )
+    }
+}
+
+impl<T> std::ops::Index<Idx<T>> for RingArena<T> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Panics if the value at `idx` was evicted or not allocated yet.
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.get(idx)
+    }
+}
+
+impl<T> std::ops::IndexMut<Idx<T>> for RingArena<T> {
+    /// # Panics
+    ///
+    /// Panics if the value at `idx` was evicted or not allocated yet.
+    fn index_mut(&mut self, idx: Idx<T>) -> &mut T {
+        self.get_mut(idx)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingArena<T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::vec_deque::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for RingArena<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RingArena")
+            .field("first", &self.first)
+            .field("items", &self.items)
+            .field("limit", &self.limit)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{Arena, Idx, IdxRange};
+
+/// Index of a row in a [`RowArena<T>`].
+pub type RowIdx<T> = Idx<[T]>;
+
+/// Two-dimensional arena: rows of variable length, each stored contiguously.
+///
+/// Values of every row live back to back in one [`Arena<T>`], and a second
+/// arena records where each row ends, so [`get_row`](RowArena::get_row) is
+/// two loads and a slice. This is the compressed sparse row (CSR) layout
+/// used for graph adjacency lists.
+///
+/// ```
+/// use fast_bump::RowArena;
+///
+/// let mut edges = RowArena::new();
+/// let a = edges.push_row([1, 2]);
+/// let b = edges.push_row([]);
+/// let c = edges.push_row([0]);
+/// assert_eq!(edges.get_row(a), [1, 2]);
+/// assert!(edges.get_row(b).is_empty());
+/// assert_eq!(edges[c], [0]);
+/// assert_eq!(edges.values(), [1, 2, 0]);
+/// ```
+pub struct RowArena<T> {
+    values: Arena<T>,
+    /// Per row, the end of its values in `values`.
+    ends: Arena<usize>,
+}
+
+impl<T> RowArena<T> {
+    /// Creates an empty arena.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            values: Arena::new(),
+            ends: Arena::new(),
+        }
+    }
+
+    /// Creates an empty arena with room for `rows` rows holding `values`
+    /// values in total.
+    #[must_use]
+    pub fn with_capacity(rows: usize, values: usize) -> Self {
+        Self {
+            values: Arena::with_capacity(values),
+            ends: Arena::with_capacity(rows),
+        }
+    }
+
+    /// Appends a row holding the items of `iter`, returning its index.
+    ///
+    /// Empty rows are allowed.
+    pub fn push_row(&mut self, iter: impl IntoIterator<Item = T>) -> RowIdx<T> {
+        self.values.alloc_extend(iter);
+        let end = self.ends.alloc(self.values.len());
+        RowIdx::from_raw(end.into_raw())
+    }
+
+    /// Returns the values of the row at `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    #[must_use]
+    pub fn get_row(&self, row: RowIdx<T>) -> &[T] {
+        self.values.slice(self.row_range(row))
+    }
+
+    /// Returns the values of the row at `row`, mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    #[must_use]
+    pub fn get_row_mut(&mut self, row: RowIdx<T>) -> &mut [T] {
+        let range = self.row_range(row);
+        self.values.slice_mut(range)
+    }
+
+    /// Returns the values of the row at `row`, or `None` if it is out of
+    /// bounds.
+    #[must_use]
+    pub fn try_get_row(&self, row: RowIdx<T>) -> Option<&[T]> {
+        (row.into_raw() < self.ends.len()).then(|| self.get_row(row))
+    }
+
+    /// Returns the indices of the row's values in [`values`](RowArena::values).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    #[must_use]
+    pub fn row_range(&self, row: RowIdx<T>) -> IdxRange<T> {
+        let ends = self.ends.as_slice();
+        let i = row.into_raw();
+        let start = if i == 0 { 0 } else { ends[i - 1] };
+        IdxRange::from_raw(start, ends[i])
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if there are no rows.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the values of every row, back to back.
+    #[must_use]
+    pub fn values(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    /// Returns an iterator over the rows in order.
+    #[must_use]
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> {
+        (0..self.len()).map(|i| self.get_row(RowIdx::from_raw(i)))
+    }
+
+    /// Removes every row, keeping the storage.
+    pub fn reset(&mut self) {
+        self.values.reset();
+        self.ends.reset();
+    }
+}
+
+impl<T> Default for RowArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::ops::Index<RowIdx<T>> for RowArena<T> {
+    type Output = [T];
+
+    fn index(&self, row: RowIdx<T>) -> &[T] {
+        self.get_row(row)
+    }
+}
+
+impl<T> std::ops::IndexMut<RowIdx<T>> for RowArena<T> {
+    fn index_mut(&mut self, row: RowIdx<T>) -> &mut [T] {
+        self.get_row_mut(row)
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for RowArena<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
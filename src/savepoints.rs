@@ -0,0 +1,119 @@
+use crate::Checkpoint;
+
+/// Stack of named [`Checkpoint`]s.
+///
+/// The bookkeeping a backtracking parser keeps around its arena: push a
+/// savepoint before trying a rule, roll back to it by name when the rule
+/// fails, and pop it once the rule commits. Savepoints only record
+/// checkpoints; pass the one returned by
+/// [`rollback_to`](Savepoints::rollback_to) to the arena's `rollback`.
+///
+/// ```
+/// use fast_bump::{Arena, Savepoints};
+///
+/// let mut arena = Arena::new();
+/// let mut saves = Savepoints::new();
+///
+/// saves.push("expr", arena.checkpoint());
+/// arena.alloc("lhs");
+/// saves.push("term", arena.checkpoint());
+/// arena.alloc("bad");
+///
+/// let cp = saves.rollback_to("expr").unwrap();
+/// arena.rollback(cp);
+/// assert!(arena.is_empty());
+/// assert_eq!(saves.top(), Some("expr"));
+///
+/// arena.alloc("good");
+/// saves.pop_commit();
+/// assert!(saves.is_empty());
+/// assert_eq!(arena.len(), 1);
+/// ```
+pub struct Savepoints<T> {
+    stack: Vec<(&'static str, Checkpoint<T>)>,
+}
+
+impl<T> Savepoints<T> {
+    /// Creates an empty stack.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { stack: Vec::new() }
+    }
+
+    /// Pushes a savepoint named `name` at `cp`.
+    ///
+    /// Names need not be unique; lookups find the most recent one.
+    pub fn push(&mut self, name: &'static str, cp: Checkpoint<T>) {
+        self.stack.push((name, cp));
+    }
+
+    /// Discards every savepoint above the most recent one named `name` and
+    /// returns its checkpoint, for the caller to roll the arena back to.
+    ///
+    /// The savepoint itself stays on the stack, so the rule can be retried
+    /// and rolled back to again. Returns `None`, leaving the stack
+    /// unchanged, if no savepoint is named `name`.
+    pub fn rollback_to(&mut self, name: &str) -> Option<Checkpoint<T>> {
+        let at = self.stack.iter().rposition(|&(n, _)| n == name)?;
+        self.stack.truncate(at + 1);
+        Some(self.stack[at].1)
+    }
+
+    /// Pops the top savepoint, keeping everything allocated since it.
+    pub fn pop_commit(&mut self) -> Option<(&'static str, Checkpoint<T>)> {
+        self.stack.pop()
+    }
+
+    /// Returns the name of the top savepoint.
+    #[must_use]
+    pub fn top(&self) -> Option<&'static str> {
+        self.stack.last().map(|&(name, _)| name)
+    }
+
+    /// Returns the checkpoint of the most recent savepoint named `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Checkpoint<T>> {
+        self.stack
+            .iter()
+            .rev()
+            .find(|&&(n, _)| n == name)
+            .map(|&(_, cp)| cp)
+    }
+
+    /// Returns the number of savepoints.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns `true` if there are no savepoints.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Removes every savepoint.
+    pub fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
+
+impl<T> Default for Savepoints<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Savepoints<T> {
+    fn clone(&self) -> Self {
+        Self {
+            stack: self.stack.clone(),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Savepoints<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.stack).finish()
+    }
+}
@@ -0,0 +1,36 @@
+//! Scrubbing of freed slot memory.
+//!
+//! Zeroizing arenas zero freed slots. In debug builds every other arena
+//! fills them with [`POISON`] so that reads through stale raw pointers see
+//! an obviously bogus pattern instead of plausible stale data.
+#![cfg_attr(
+    not(feature = "zeroize"),
+    allow(clippy::missing_const_for_fn, clippy::needless_pass_by_ref_mut)
+)]
+
+use std::mem::MaybeUninit;
+
+/// Byte written over freed slots in debug builds.
+pub const POISON: u8 = 0xA5;
+
+/// Overwrites freed slot memory with zero bytes.
+///
+/// Uses volatile writes through `zeroize` so the compiler cannot elide
+/// them. Only called for arenas created with a `*_zeroizing` constructor,
+/// which exist with the `zeroize` feature.
+pub fn zero<T>(slots: &mut [MaybeUninit<T>]) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(slots);
+    #[cfg(not(feature = "zeroize"))]
+    let _ = slots;
+}
+
+/// Fills freed slot memory with [`POISON`] in debug builds; a no-op in
+/// release builds.
+pub const fn poison<T>(slots: &mut [MaybeUninit<T>]) {
+    if cfg!(debug_assertions) {
+        // SAFETY: the slots are owned, writable, and hold no live values;
+        // any byte pattern is a valid `MaybeUninit<T>`.
+        unsafe { slots.as_mut_ptr().write_bytes(POISON, slots.len()) };
+    }
+}
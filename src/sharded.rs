@@ -0,0 +1,201 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{Error, FastArena, Idx};
+
+/// Source of per-thread shard hints.
+static NEXT_THREAD: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// This thread's hint; each thread gets the next number.
+    static THREAD_HINT: usize = NEXT_THREAD.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Concurrent arena split into per-thread [`FastArena`] shards.
+///
+/// Every `FastArena` allocation is a `fetch_add` on one shared cursor,
+/// which becomes the bottleneck with many writer threads. A sharded arena
+/// sends each thread to its own shard — thread `k` allocates in shard
+/// `k % shards` — so writers on different shards never touch the same
+/// cursor or `published` line.
+///
+/// An [`Idx<T>`] encodes the shard and the offset within it as
+/// `offset * shards + shard`. Indices are stable and unique, but not dense
+/// or ordered by allocation time across shards. Iteration visits shard 0,
+/// then shard 1, and so on.
+///
+/// ```
+/// use fast_bump::ShardedArena;
+///
+/// let arena = ShardedArena::new(4, 1024);
+/// std::thread::scope(|s| {
+///     for t in 0..4 {
+///         let arena = &arena;
+///         s.spawn(move || arena.alloc(t));
+///     }
+/// });
+/// assert_eq!(arena.len(), 4);
+/// assert_eq!(arena.iter().sum::<i32>(), 6);
+/// ```
+pub struct ShardedArena<T> {
+    shards: Box<[FastArena<T>]>,
+}
+
+impl<T> ShardedArena<T> {
+    /// Creates an arena with `shards` shards of `capacity` slots each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is zero.
+    #[must_use]
+    pub fn new(shards: usize, capacity: usize) -> Self {
+        assert!(shards > 0, "a sharded arena needs at least one shard");
+        Self {
+            shards: (0..shards)
+                .map(|_| FastArena::with_capacity(capacity))
+                .collect(),
+        }
+    }
+
+    /// Creates an arena with one shard per available CPU.
+    #[must_use]
+    pub fn with_capacity_per_shard(capacity: usize) -> Self {
+        let shards = std::thread::available_parallelism().map_or(1, usize::from);
+        Self::new(shards, capacity)
+    }
+
+    /// Returns the number of shards.
+    #[must_use]
+    pub const fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Returns the shard the current thread allocates in.
+    #[must_use]
+    pub fn current_shard(&self) -> usize {
+        THREAD_HINT.with(|&hint| hint % self.shards.len())
+    }
+
+    /// Allocates a value in the current thread's shard.
+    ///
+    /// # Panics
+    ///
+    /// Panics if that shard is full, as [`FastArena::alloc`] does.
+    pub fn alloc(&self, value: T) -> Idx<T> {
+        let shard = self.current_shard();
+        self.encode(shard, self.shards[shard].alloc(value))
+    }
+
+    /// Allocates a value in the current thread's shard, returning an error
+    /// instead of panicking if it is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Full`] or [`Error::Limit`] from the shard.
+    pub fn try_alloc(&self, value: T) -> Result<Idx<T>, Error> {
+        let shard = self.current_shard();
+        let idx = self.shards[shard].try_alloc(value)?;
+        Ok(self.encode(shard, idx))
+    }
+
+    const fn encode(&self, shard: usize, idx: Idx<T>) -> Idx<T> {
+        Idx::from_raw(idx.into_raw() * self.shards.len() + shard)
+    }
+
+    const fn decode(&self, idx: Idx<T>) -> (usize, Idx<T>) {
+        let raw = idx.into_raw();
+        let n = self.shards.len();
+        (raw % n, Idx::from_raw(raw / n))
+    }
+
+    /// Returns a reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not published in its shard.
+    #[must_use]
+    pub fn get(&self, idx: Idx<T>) -> &T {
+        let (shard, idx) = self.decode(idx);
+        self.shards[shard].get(idx)
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if it is not
+    /// published.
+    #[must_use]
+    pub fn try_get(&self, idx: Idx<T>) -> Option<&T> {
+        let (shard, idx) = self.decode(idx);
+        self.shards[shard].try_get(idx)
+    }
+
+    /// Returns a mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not published in its shard.
+    #[must_use]
+    pub fn get_mut(&mut self, idx: Idx<T>) -> &mut T {
+        let (shard, idx) = self.decode(idx);
+        self.shards[shard].get_mut(idx)
+    }
+
+    /// Returns the shards, e.g. to process them in parallel.
+    #[must_use]
+    pub fn shards(&self) -> &[FastArena<T>] {
+        &self.shards
+    }
+
+    /// Returns the number of published values across all shards.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(FastArena::len).sum()
+    }
+
+    /// Returns `true` if no shard holds a published value.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.shards.iter().all(FastArena::is_empty)
+    }
+
+    /// Returns an iterator over the published values, shard by shard.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.shards.iter().flat_map(FastArena::iter)
+    }
+
+    /// Returns an iterator yielding `(Idx<T>, &T)` pairs, shard by shard.
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (Idx<T>, &T)> {
+        self.shards
+            .iter()
+            .enumerate()
+            .flat_map(move |(shard, arena)| {
+                arena
+                    .iter_indexed()
+                    .map(move |(idx, value)| (self.encode(shard, idx), value))
+            })
+    }
+
+    /// Grows every shard, like [`FastArena::grow`].
+    pub fn grow(&mut self) {
+        self.shards.iter_mut().for_each(FastArena::grow);
+    }
+
+    /// Removes all values from every shard.
+    pub fn reset(&mut self) {
+        self.shards.iter_mut().for_each(FastArena::reset);
+    }
+}
+
+impl<T> std::ops::Index<Idx<T>> for ShardedArena<T> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.get(idx)
+    }
+}
+
+impl<T> std::fmt::Debug for ShardedArena<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShardedArena")
+            .field("shards", &self.shards.len())
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
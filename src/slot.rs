@@ -0,0 +1,284 @@
+use std::mem::MaybeUninit;
+
+use crate::{ArenaObserver, FastArena, Idx, IdxRange, ReservedIdx};
+
+/// Writer for a block of contiguous slots reserved in a [`FastArena`].
+///
+/// Created by [`FastArena::reserve_slots`]. The whole block is claimed with
+/// a single atomic operation; [`write`](SlotWriter::write) then fills the
+/// slots in order without further contention. Written values become visible
+/// to readers when the writer is [`finish`](SlotWriter::finish)ed or
+/// dropped.
+///
+/// Dropping a writer with unwritten slots returns them to the arena if no
+/// later reservation was made; otherwise the first unwritten slot
+/// [poisons](FastArena#poisoning) the arena, because readers could never
+/// observe allocations past the gap.
+pub struct SlotWriter<'a, T> {
+    arena: &'a FastArena<T>,
+    start: usize,
+    next: usize,
+    end: usize,
+    open: bool,
+}
+
+impl<'a, T> SlotWriter<'a, T> {
+    pub(crate) const fn new(arena: &'a FastArena<T>, start: usize, end: usize) -> Self {
+        Self {
+            arena,
+            start,
+            next: start,
+            end,
+            open: true,
+        }
+    }
+
+    /// Writes `value` into the next reserved slot, returning its index.
+    ///
+    /// The value is not readable until the writer is finished or dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every reserved slot has already been written.
+    pub fn write(&mut self, value: T) -> Idx<T> {
+        assert!(
+            self.next < self.end,
+            "slot writer full: all {} slots written",
+            self.end - self.start,
+        );
+        let slot = self.next;
+        // SAFETY: slot lies in the block exclusively reserved by this
+        // writer and has not been written yet.
+        unsafe { self.arena.slot_ptr(slot).write(value) };
+        self.next += 1;
+        Idx::from_raw(slot)
+    }
+
+    /// Returns the number of reserved slots not yet written.
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.end - self.next
+    }
+
+    /// Returns the full range of reserved slots.
+    #[must_use]
+    pub const fn reserved(&self) -> IdxRange<T> {
+        IdxRange::from_raw(self.start, self.end)
+    }
+
+    /// Publishes the written values, returning their index range.
+    ///
+    /// Unwritten slots are handled as when dropping the writer.
+    #[must_use = "dropping the writer publishes the values as well"]
+    pub fn finish(mut self) -> IdxRange<T> {
+        self.close()
+    }
+
+    fn close(&mut self) -> IdxRange<T> {
+        self.open = false;
+        self.arena.abandon_block(self.next, self.end);
+        let written = self.next - self.start;
+        if written > 0 {
+            self.arena.publish_block(self.start, written);
+        }
+        IdxRange::from_raw(self.start, self.next)
+    }
+}
+
+impl<T> Drop for SlotWriter<'_, T> {
+    fn drop(&mut self) {
+        if self.open {
+            self.close();
+        }
+    }
+}
+
+/// Thread-local allocation handle for a [`FastArena`] that reserves slots
+/// in batches.
+///
+/// Created by [`FastArena::writer`]. Each [`alloc`](LocalWriter::alloc)
+/// fills the next slot of the current batch; only refilling the batch
+/// touches the arena's shared cursor, so a producer pays one contended
+/// atomic per batch instead of one per value.
+///
+/// Written values are published when their batch is full, on
+/// [`flush`](LocalWriter::flush), and when the writer is dropped. Until
+/// then, later allocations by other threads wait for the batch like they
+/// wait for any slow writer, so flush before pausing production. Do not
+/// allocate in the same arena on this thread while the writer holds
+/// unpublished values: that allocation would wait on the writer forever.
+pub struct LocalWriter<'a, T> {
+    arena: &'a FastArena<T>,
+    batch: usize,
+    block: Option<SlotWriter<'a, T>>,
+}
+
+impl<'a, T> LocalWriter<'a, T> {
+    /// Batch size used by [`FastArena::writer`].
+    pub const DEFAULT_BATCH: usize = 64;
+
+    pub(crate) const fn new(arena: &'a FastArena<T>, batch: usize) -> Self {
+        Self {
+            arena,
+            batch,
+            block: None,
+        }
+    }
+
+    /// Writes `value` into the next slot of the current batch, reserving
+    /// a new batch first if it is used up.
+    ///
+    /// The value is readable once its batch is published.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full.
+    pub fn alloc(&mut self, value: T) -> Idx<T> {
+        let block = match &mut self.block {
+            Some(block) if block.remaining() > 0 => block,
+            block => {
+                drop(block.take());
+                // A short final batch instead of a spurious "arena full"
+                // while single slots remain.
+                let n = self.batch.min(self.arena.remaining_capacity()).max(1);
+                block.insert(self.arena.reserve_slots(n))
+            }
+        };
+        block.write(value)
+    }
+
+    /// Publishes the values written so far and returns the unwritten rest
+    /// of the batch to the arena.
+    ///
+    /// Unwritten slots are handled as when dropping a [`SlotWriter`].
+    pub fn flush(&mut self) {
+        drop(self.block.take());
+    }
+
+    /// Returns the number of slots reserved per batch.
+    #[must_use]
+    pub const fn batch_size(&self) -> usize {
+        self.batch
+    }
+
+    /// Returns the number of slots left in the current batch.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.block.as_ref().map_or(0, SlotWriter::remaining)
+    }
+}
+
+/// Uninitialized slot at the end of an [`Arena`](crate::Arena).
+///
+/// Created by [`Arena::alloc_uninit`](crate::Arena::alloc_uninit). The slot
+/// joins the arena only when committed with
+/// [`assume_init`](UninitSlot::assume_init) or [`write`](UninitSlot::write);
+/// dropping it allocates nothing.
+pub struct UninitSlot<'a, T> {
+    items: &'a mut Vec<T>,
+    observer: Option<&'a dyn ArenaObserver>,
+}
+
+impl<'a, T> UninitSlot<'a, T> {
+    pub(crate) fn new(items: &'a mut Vec<T>, observer: Option<&'a dyn ArenaObserver>) -> Self {
+        items.reserve(1);
+        Self { items, observer }
+    }
+
+    /// Returns the index the value will have once committed.
+    #[must_use]
+    pub const fn idx(&self) -> Idx<T> {
+        Idx::from_raw(self.items.len())
+    }
+
+    /// Returns the uninitialized slot memory.
+    #[must_use]
+    pub fn as_uninit(&mut self) -> &mut MaybeUninit<T> {
+        &mut self.items.spare_capacity_mut()[0]
+    }
+
+    /// Commits the slot, returning its index.
+    ///
+    /// # Safety
+    ///
+    /// The slot must have been fully initialized through
+    /// [`as_uninit`](UninitSlot::as_uninit).
+    #[must_use]
+    pub unsafe fn assume_init(self) -> Idx<T> {
+        let idx = self.idx();
+        // SAFETY: capacity was reserved in `new` and the caller guarantees
+        // the slot at `len` is initialized.
+        unsafe { self.items.set_len(idx.into_raw() + 1) };
+        if let Some(observer) = self.observer {
+            observer.on_alloc(idx.into_raw(), 1);
+        }
+        idx
+    }
+
+    /// Writes `value` into the slot and commits it, returning its index.
+    pub fn write(mut self, value: T) -> Idx<T> {
+        self.as_uninit().write(value);
+        // SAFETY: the slot was just initialized.
+        unsafe { self.assume_init() }
+    }
+}
+
+/// Reserved, unpublished slot in a [`FastArena`].
+///
+/// Created by [`FastArena::alloc_uninit`]. The slot is claimed immediately
+/// but stays invisible to readers until [`publish`](SlotGuard::publish) or
+/// [`write`](SlotGuard::write) is called.
+///
+/// Dropping an unpublished guard returns the slot to the arena if no later
+/// slot was claimed; otherwise it [poisons](FastArena#poisoning) the arena,
+/// because readers could never observe allocations past the gap.
+pub struct SlotGuard<'a, T> {
+    arena: &'a FastArena<T>,
+    slot: usize,
+}
+
+impl<'a, T> SlotGuard<'a, T> {
+    pub(crate) const fn new(arena: &'a FastArena<T>, slot: usize) -> Self {
+        Self { arena, slot }
+    }
+
+    /// Returns the index the value will have once published.
+    #[must_use]
+    pub const fn idx(&self) -> ReservedIdx<T> {
+        ReservedIdx::from_raw(self.slot)
+    }
+
+    /// Returns the uninitialized slot memory.
+    #[must_use]
+    pub fn as_uninit(&mut self) -> &mut MaybeUninit<T> {
+        // SAFETY: the slot is exclusively reserved by this guard and below
+        // capacity. MaybeUninit<T> has the same layout as T.
+        unsafe { &mut *self.arena.slot_ptr(self.slot).cast::<MaybeUninit<T>>() }
+    }
+
+    /// Publishes the slot, returning its index.
+    ///
+    /// # Safety
+    ///
+    /// The slot must have been fully initialized through
+    /// [`as_uninit`](SlotGuard::as_uninit).
+    #[must_use]
+    pub unsafe fn publish(self) -> Idx<T> {
+        let this = std::mem::ManuallyDrop::new(self);
+        this.arena.publish_block(this.slot, 1);
+        Idx::from_raw(this.slot)
+    }
+
+    /// Writes `value` into the slot and publishes it, returning its index.
+    pub fn write(mut self, value: T) -> Idx<T> {
+        self.as_uninit().write(value);
+        // SAFETY: the slot was just initialized.
+        unsafe { self.publish() }
+    }
+}
+
+impl<T> Drop for SlotGuard<'_, T> {
+    fn drop(&mut self) {
+        self.arena.abandon_block(self.slot, self.slot + 1);
+    }
+}
@@ -0,0 +1,332 @@
+use std::mem::MaybeUninit;
+
+use crate::{Arena, Checkpoint, Error, Idx};
+
+/// Up to `N` values stored in place.
+struct Inline<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> Inline<T, N> {
+    const fn new() -> Self {
+        Self {
+            items: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Appends `value`, handing it back if the buffer is full.
+    fn push(&mut self, value: T) -> Result<usize, T> {
+        let Some(slot) = self.items.get_mut(self.len) else {
+            return Err(value);
+        };
+        slot.write(value);
+        self.len += 1;
+        Ok(self.len - 1)
+    }
+
+    const fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` items are initialized.
+        unsafe { std::slice::from_raw_parts(self.items.as_ptr().cast::<T>(), self.len) }
+    }
+
+    const fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` items are initialized.
+        unsafe { std::slice::from_raw_parts_mut(self.items.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.len -= 1;
+            // SAFETY: the item at the old `len - 1` is initialized, and
+            // lowering `len` first keeps a panicking destructor from
+            // dropping it twice.
+            unsafe { self.items[self.len].assume_init_drop() };
+        }
+    }
+
+    /// Moves the values into a vector with room for `additional` more.
+    fn spill(&mut self, additional: usize) -> Vec<T> {
+        let mut items = Vec::with_capacity((self.len + additional).max(2 * N));
+        let len = std::mem::replace(&mut self.len, 0);
+        // SAFETY: the first `len` items are initialized and, with `len`
+        // reset, read exactly once.
+        items.extend(
+            self.items[..len]
+                .iter()
+                .map(|item| unsafe { item.assume_init_read() }),
+        );
+        items
+    }
+}
+
+impl<T, const N: usize> Drop for Inline<T, N> {
+    fn drop(&mut self) {
+        self.truncate(0);
+    }
+}
+
+enum Storage<T, const N: usize> {
+    Inline(Inline<T, N>),
+    Heap(Vec<T>),
+}
+
+/// [`Arena<T>`] that keeps its first `N` values inline.
+///
+/// Allocating does not touch the heap until the `N + 1`-th value, when the
+/// values move to a `Vec` and stay there. Indices are plain [`Idx<T>`],
+/// so code written against `Arena` needs no changes, and the storage is
+/// one contiguous slice either way. Suited to many short-lived arenas that
+/// usually stay tiny, e.g. per-function scratch.
+///
+/// ```
+/// use fast_bump::SmallArena;
+///
+/// let mut scratch = SmallArena::<&str, 4>::new();
+/// let a = scratch.alloc("a");
+/// let b = scratch.alloc("b");
+/// assert!(!scratch.spilled());
+/// assert_eq!((scratch[a], scratch[b]), ("a", "b"));
+///
+/// scratch.extend(["c", "d", "e"]);
+/// assert!(scratch.spilled());
+/// assert_eq!(scratch[a], "a");
+/// ```
+pub struct SmallArena<T, const N: usize> {
+    storage: Storage<T, N>,
+}
+
+impl<T, const N: usize> SmallArena<T, N> {
+    /// Creates an empty arena; no memory is allocated.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            storage: Storage::Inline(Inline::new()),
+        }
+    }
+
+    /// Allocates `value` and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spilled storage exceeds `isize::MAX` bytes.
+    pub fn alloc(&mut self, value: T) -> Idx<T> {
+        let value = match &mut self.storage {
+            Storage::Inline(inline) => match inline.push(value) {
+                Ok(index) => return Idx::from_raw(index),
+                Err(value) => value,
+            },
+            Storage::Heap(items) => {
+                items.push(value);
+                return Idx::from_raw(items.len() - 1);
+            }
+        };
+        let items = self.spill(1);
+        items.push(value);
+        Idx::from_raw(items.len() - 1)
+    }
+
+    /// Moves inline values to the heap, returning the heap vector.
+    fn spill(&mut self, additional: usize) -> &mut Vec<T> {
+        if let Storage::Inline(inline) = &mut self.storage {
+            self.storage = Storage::Heap(inline.spill(additional));
+        }
+        match &mut self.storage {
+            Storage::Heap(items) => items,
+            Storage::Inline(_) => unreachable!("storage was just spilled"),
+        }
+    }
+
+    /// Returns a reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds (stale after rollback/reset).
+    #[must_use]
+    pub fn get(&self, idx: Idx<T>) -> &T {
+        &self.as_slice()[idx.into_raw()]
+    }
+
+    /// Returns a mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds (stale after rollback/reset).
+    #[must_use]
+    pub fn get_mut(&mut self, idx: Idx<T>) -> &mut T {
+        &mut self.as_mut_slice()[idx.into_raw()]
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if out of bounds.
+    #[must_use]
+    pub fn try_get(&self, idx: Idx<T>) -> Option<&T> {
+        self.as_slice().get(idx.into_raw())
+    }
+
+    /// Returns a mutable reference to the value at `idx`, or `None` if out
+    /// of bounds.
+    #[must_use]
+    pub fn try_get_mut(&mut self, idx: Idx<T>) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(idx.into_raw())
+    }
+
+    /// Returns the number of allocated items.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match &self.storage {
+            Storage::Inline(inline) => inline.len,
+            Storage::Heap(items) => items.len(),
+        }
+    }
+
+    /// Returns `true` if no items have been allocated.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` once the values have moved to the heap.
+    #[must_use]
+    pub const fn spilled(&self) -> bool {
+        matches!(self.storage, Storage::Heap(_))
+    }
+
+    /// Returns all items as a slice, in allocation order.
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        match &self.storage {
+            Storage::Inline(inline) => inline.as_slice(),
+            Storage::Heap(items) => items.as_slice(),
+        }
+    }
+
+    /// Returns all items as a mutable slice, in allocation order.
+    #[must_use]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.storage {
+            Storage::Inline(inline) => inline.as_mut_slice(),
+            Storage::Heap(items) => items.as_mut_slice(),
+        }
+    }
+
+    /// Returns an iterator over all items, in allocation order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Saves the current allocation state.
+    #[must_use]
+    pub const fn checkpoint(&self) -> Checkpoint<T> {
+        Checkpoint::from_len(self.len())
+    }
+
+    /// Rolls back to a previous checkpoint, dropping all values
+    /// allocated after it. Spilled storage stays on the heap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cp` points beyond the current length.
+    pub fn rollback(&mut self, cp: Checkpoint<T>) {
+        if let Err(err) = self.try_rollback(cp) {
+            panic!("{err}");
+        }
+    }
+
+    /// Rolls back to `cp` like [`rollback`](SmallArena::rollback),
+    /// returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CheckpointBeyondLen`] if `cp` points beyond the
+    /// current length; the arena is left unchanged.
+    pub fn try_rollback(&mut self, cp: Checkpoint<T>) -> Result<(), Error> {
+        if cp.len() > self.len() {
+            return Err(Error::CheckpointBeyondLen {
+                checkpoint: cp.len(),
+                len: self.len(),
+            });
+        }
+        match &mut self.storage {
+            Storage::Inline(inline) => inline.truncate(cp.len()),
+            Storage::Heap(items) => items.truncate(cp.len()),
+        }
+        Ok(())
+    }
+
+    /// Removes all items, keeping any heap capacity for reuse.
+    pub fn reset(&mut self) {
+        self.rollback(Checkpoint::from_len(0));
+    }
+
+    /// Converts into an [`Arena`] with the same indices.
+    #[must_use]
+    pub fn into_arena(mut self) -> Arena<T> {
+        Arena::from(std::mem::take(self.spill(0)))
+    }
+}
+
+impl<T, const N: usize> Default for SmallArena<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SmallArena<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> std::ops::Index<Idx<T>> for SmallArena<T, N> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.get(idx)
+    }
+}
+
+impl<T, const N: usize> std::ops::IndexMut<Idx<T>> for SmallArena<T, N> {
+    fn index_mut(&mut self, idx: Idx<T>) -> &mut T {
+        self.get_mut(idx)
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallArena<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        while let Storage::Inline(_) = self.storage {
+            let Some(value) = iter.next() else {
+                return;
+            };
+            self.alloc(value);
+        }
+        self.spill(0).extend(iter);
+    }
+}
+
+impl<T, const N: usize> std::iter::FromIterator<T> for SmallArena<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut arena = Self::new();
+        arena.extend(iter);
+        arena
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallArena<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: std::fmt::Debug, const N: usize> std::fmt::Debug for SmallArena<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmallArena")
+            .field("items", &self.as_slice())
+            .field("spilled", &self.spilled())
+            .finish()
+    }
+}
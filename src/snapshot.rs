@@ -0,0 +1,107 @@
+//! Versioned binary snapshots of arenas holding [`Pod`] values.
+//!
+//! Layout, all integers little-endian:
+//!
+//! | Bytes | Field |
+//! |---|---|
+//! | 8 | magic `b"FBSNAP\0\0"` |
+//! | 4 | format version, currently 1 |
+//! | 4 | `size_of::<T>()` |
+//! | 8 | number of values |
+//! | `len * size` | the values, in index order, native byte order |
+
+use std::io::{self, Read, Write};
+
+use bytemuck::Pod;
+
+use crate::Arena;
+
+const MAGIC: [u8; 8] = *b"FBSNAP\0\0";
+const VERSION: u32 = 1;
+const HEADER_LEN: usize = 24;
+/// Bytes of values read per step; bounds the memory a header can claim
+/// ahead of the data.
+const CHUNK_BYTES: usize = 1 << 16;
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl<T: Pod> Arena<T> {
+    /// Writes the arena as a versioned binary snapshot.
+    ///
+    /// Values are written in index order, so
+    /// [`read_snapshot`](Arena::read_snapshot) restores every `Idx<T>` to
+    /// the same value. The values are stored in native byte order; the
+    /// header records `size_of::<T>()` to catch type mismatches.
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if `size_of::<T>()` does not
+    /// fit the header, and any error from `writer`.
+    pub fn write_snapshot(&self, writer: &mut impl Write) -> io::Result<()> {
+        let size = u32::try_from(size_of::<T>()).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "value too large to snapshot")
+        })?;
+        let mut header = [0; HEADER_LEN];
+        header[..8].copy_from_slice(&MAGIC);
+        header[8..12].copy_from_slice(&VERSION.to_le_bytes());
+        header[12..16].copy_from_slice(&size.to_le_bytes());
+        header[16..].copy_from_slice(&(self.len() as u64).to_le_bytes());
+        writer.write_all(&header)?;
+        writer.write_all(self.as_bytes())
+    }
+
+    /// Reads a snapshot written by [`write_snapshot`](Arena::write_snapshot).
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::InvalidData`] if the header is not a
+    /// snapshot, has an unknown version, or was written for a `T` of
+    /// another size, [`io::ErrorKind::OutOfMemory`] if the values do not
+    /// fit in memory, and any error from `reader`, including
+    /// [`io::ErrorKind::UnexpectedEof`] for a truncated snapshot.
+    pub fn read_snapshot(reader: &mut impl Read) -> io::Result<Self> {
+        let mut header = [0; HEADER_LEN];
+        reader.read_exact(&mut header)?;
+        if header[..8] != MAGIC {
+            return Err(invalid("not an arena snapshot".into()));
+        }
+        let field = |at: usize| u32::from_le_bytes(std::array::from_fn(|i| header[at + i]));
+        let version = field(8);
+        if version != VERSION {
+            return Err(invalid(format!("unsupported snapshot version {version}")));
+        }
+        let size = field(12);
+        if size as usize != size_of::<T>() {
+            return Err(invalid(format!(
+                "snapshot of {size}-byte values, expected {}",
+                size_of::<T>()
+            )));
+        }
+        let len = usize::try_from(u64::from_le_bytes(std::array::from_fn(|i| header[16 + i])))
+            .map_err(|_| invalid("snapshot length overflows usize".into()))?;
+        if size_of::<T>() == 0 {
+            return Ok(Self::from(crate::pod::zeroed_vec(len)));
+        }
+
+        // Grow the storage as the values arrive, so a corrupt length ends
+        // in `UnexpectedEof` instead of a huge allocation up front.
+        let chunk = (CHUNK_BYTES / size_of::<T>()).max(1);
+        let mut items = Vec::new();
+        while items.len() < len {
+            let start = items.len();
+            let n = chunk.min(len - start);
+            items
+                .try_reserve(n)
+                .map_err(|_| io::Error::from(io::ErrorKind::OutOfMemory))?;
+            items.resize(start + n, T::zeroed());
+            reader.read_exact(bytemuck::cast_slice_mut(&mut items[start..]))?;
+        }
+        Ok(Self::from(items))
+    }
+}
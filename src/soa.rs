@@ -0,0 +1,166 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{Checkpoint, Idx};
+
+/// A struct stored field by field in a [`SoaArena`].
+///
+/// Implement with `#[derive(Soa)]` (`derive` feature), which generates the
+/// column type with one slice accessor per field.
+pub trait Soa: Sized {
+    /// One `Vec` per field, kept at equal length by [`SoaArena`].
+    type Columns: Default;
+
+    /// Appends the fields of `value` to their columns.
+    fn push(columns: &mut Self::Columns, value: Self);
+
+    /// Shortens every column to `len`, dropping the removed fields.
+    fn truncate(columns: &mut Self::Columns, len: usize);
+
+    /// Reserves room for `additional` more values in every column.
+    fn reserve(columns: &mut Self::Columns, additional: usize);
+}
+
+/// Struct-of-arrays arena: each field of `T` lives in its own contiguous
+/// column.
+///
+/// `alloc` splits a value into its fields and returns an [`Idx<T>`] that
+/// indexes every column. The arena derefs to `T::Columns`, so a hot loop
+/// over one field reads only that field's memory:
+///
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use fast_bump::{Soa, SoaArena};
+///
+/// #[derive(Soa)]
+/// struct Particle {
+///     x: f32,
+///     v: f32,
+/// }
+///
+/// let mut arena = SoaArena::<Particle>::new();
+/// let p = arena.alloc(Particle { x: 1.0, v: 0.5 });
+/// arena.alloc(Particle { x: 2.0, v: 0.5 });
+///
+/// let total: f32 = arena.x().iter().sum();
+/// assert_eq!(total, 3.0);
+/// assert_eq!(arena.v()[p.into_raw()], 0.5);
+/// ```
+pub struct SoaArena<T: Soa> {
+    columns: T::Columns,
+    len: usize,
+}
+
+impl<T: Soa> SoaArena<T> {
+    /// Creates an empty arena.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            columns: T::Columns::default(),
+            len: 0,
+        }
+    }
+
+    /// Creates an arena with room for `capacity` values in every column.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut arena = Self::new();
+        T::reserve(&mut arena.columns, capacity);
+        arena
+    }
+
+    /// Allocates a value, splitting it into its columns, and returns its
+    /// index.
+    pub fn alloc(&mut self, value: T) -> Idx<T> {
+        T::push(&mut self.columns, value);
+        self.len += 1;
+        Idx::from_raw(self.len - 1)
+    }
+
+    /// Returns the number of values.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the arena holds no values.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if `idx` points to a value in this arena.
+    #[must_use]
+    pub const fn is_valid(&self, idx: Idx<T>) -> bool {
+        idx.into_raw() < self.len
+    }
+
+    /// Returns the columns.
+    #[must_use]
+    pub const fn columns(&self) -> &T::Columns {
+        &self.columns
+    }
+
+    /// Returns the columns mutably. Columns can be modified in place but
+    /// not resized.
+    #[must_use]
+    pub const fn columns_mut(&mut self) -> &mut T::Columns {
+        &mut self.columns
+    }
+
+    /// Creates a checkpoint for later rollback.
+    #[must_use]
+    pub const fn checkpoint(&self) -> Checkpoint<T> {
+        Checkpoint::from_len(self.len)
+    }
+
+    /// Rolls back to a previous checkpoint, dropping the fields of all
+    /// values allocated after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cp` points beyond the current length.
+    pub fn rollback(&mut self, cp: Checkpoint<T>) {
+        assert!(
+            cp.len() <= self.len,
+            "checkpoint {} beyond current length {}",
+            cp.len(),
+            self.len,
+        );
+        T::truncate(&mut self.columns, cp.len());
+        self.len = cp.len();
+    }
+
+    /// Removes all values, keeping the columns' memory.
+    pub fn reset(&mut self) {
+        T::truncate(&mut self.columns, 0);
+        self.len = 0;
+    }
+}
+
+impl<T: Soa> Default for SoaArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Soa> Deref for SoaArena<T> {
+    type Target = T::Columns;
+
+    fn deref(&self) -> &T::Columns {
+        &self.columns
+    }
+}
+
+impl<T: Soa> DerefMut for SoaArena<T> {
+    fn deref_mut(&mut self) -> &mut T::Columns {
+        &mut self.columns
+    }
+}
+
+impl<T: Soa> Extend<T> for SoaArena<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.alloc(value);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use crate::{ArenaView, Error, FastArena, Idx, IdxRange};
+
+/// Allocating half of a [`FastArena`] split with
+/// [`FastArena::split`].
+///
+/// Clones share the arena and may allocate concurrently. A writer can
+/// read back what it allocated and hand out [`ArenaReader`]s, but cannot
+/// roll back, reset or grow: those need the arena back from
+/// [`FastArena::join`].
+pub struct ArenaWriter<T> {
+    arena: Arc<FastArena<T>>,
+}
+
+/// Read-only half of a [`FastArena`] split with [`FastArena::split`].
+///
+/// Clones share the arena. Readers see every published value but cannot
+/// allocate.
+pub struct ArenaReader<T> {
+    arena: Arc<FastArena<T>>,
+}
+
+impl<T> FastArena<T> {
+    /// Splits the arena into a writer and a reader handle, so the type
+    /// system records who may allocate and who may only read.
+    ///
+    /// Both handles are `Clone`. Operations that need `&mut self` —
+    /// [`rollback`](FastArena::rollback), [`reset`](FastArena::reset),
+    /// [`grow`](FastArena::grow) — are available again once the handles
+    /// are rejoined with [`join`](FastArena::join).
+    ///
+    /// ```
+    /// use fast_bump::FastArena;
+    ///
+    /// let (writer, reader) = FastArena::with_capacity(8).split();
+    /// let idx = writer.alloc(1);
+    /// assert_eq!(*reader.get(idx), 1);
+    ///
+    /// let mut arena = FastArena::join(writer, reader).unwrap();
+    /// arena.reset();
+    /// ```
+    #[must_use]
+    pub fn split(self) -> (ArenaWriter<T>, ArenaReader<T>) {
+        let arena = Arc::new(self);
+        let reader = ArenaReader {
+            arena: Arc::clone(&arena),
+        };
+        (ArenaWriter { arena }, reader)
+    }
+
+    /// Reassembles an arena from its last writer and reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns both handles unchanged if they belong to different arenas or
+    /// other clones are still alive.
+    #[allow(clippy::missing_panics_doc)] // the writer holds the last handle
+    pub fn join(
+        writer: ArenaWriter<T>,
+        reader: ArenaReader<T>,
+    ) -> Result<Self, (ArenaWriter<T>, ArenaReader<T>)> {
+        // Clones can only be made from handles, and we own both remaining
+        // ones, so the count cannot rise after this check.
+        if !Arc::ptr_eq(&writer.arena, &reader.arena) || Arc::strong_count(&writer.arena) != 2 {
+            return Err((writer, reader));
+        }
+        drop(reader);
+        Ok(Arc::into_inner(writer.arena).expect("the writer holds the last handle"))
+    }
+}
+
+impl<T> ArenaWriter<T> {
+    /// Allocates a value, like [`FastArena::alloc`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full.
+    pub fn alloc(&self, value: T) -> Idx<T> {
+        self.arena.alloc(value)
+    }
+
+    /// Allocates a value, like [`FastArena::try_alloc`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Full`] or [`Error::Limit`] if the value does not
+    /// fit.
+    pub fn try_alloc(&self, value: T) -> Result<Idx<T>, Error> {
+        self.arena.try_alloc(value)
+    }
+
+    /// Allocates a batch of values, like [`FastArena::alloc_extend`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the batch does not fit.
+    pub fn alloc_extend(&self, iter: impl IntoIterator<Item = T>) -> IdxRange<T> {
+        self.arena.alloc_extend(iter)
+    }
+
+    /// Returns a reference to the published value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not published.
+    #[must_use]
+    pub fn get(&self, idx: Idx<T>) -> &T {
+        self.arena.get(idx)
+    }
+
+    /// Returns a new reader of the same arena.
+    #[must_use]
+    pub fn reader(&self) -> ArenaReader<T> {
+        ArenaReader {
+            arena: Arc::clone(&self.arena),
+        }
+    }
+}
+
+impl<T> ArenaReader<T> {
+    /// Returns a reference to the published value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not published.
+    #[must_use]
+    pub fn get(&self, idx: Idx<T>) -> &T {
+        self.arena.get(idx)
+    }
+
+    /// Returns the published value at `idx`, or `None`.
+    #[must_use]
+    pub fn try_get(&self, idx: Idx<T>) -> Option<&T> {
+        self.arena.try_get(idx)
+    }
+
+    /// Blocks until `idx` is published, like [`FastArena::get_wait`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is beyond capacity or past a poisoned slot.
+    #[must_use]
+    pub fn get_wait(&self, idx: Idx<T>) -> &T {
+        self.arena.get_wait(idx)
+    }
+
+    /// Returns `true` if `idx` is published.
+    #[must_use]
+    pub fn is_valid(&self, idx: Idx<T>) -> bool {
+        self.arena.is_valid(idx)
+    }
+
+    /// Returns the number of published values.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Returns `true` if nothing is published.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.arena.is_empty()
+    }
+
+    /// Returns the published values as a slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        self.arena.as_slice()
+    }
+
+    /// Returns a snapshot with a fixed length, like [`FastArena::view`].
+    #[must_use]
+    pub fn view(&self) -> ArenaView<'_, T> {
+        self.arena.view()
+    }
+}
+
+impl<T> Clone for ArenaWriter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            arena: Arc::clone(&self.arena),
+        }
+    }
+}
+
+impl<T> Clone for ArenaReader<T> {
+    fn clone(&self) -> Self {
+        Self {
+            arena: Arc::clone(&self.arena),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for ArenaWriter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArenaWriter")
+            .field("len", &self.arena.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> std::fmt::Debug for ArenaReader<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArenaReader")
+            .field("len", &self.arena.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,301 @@
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::{Checkpoint, Idx};
+
+/// Single-producer, multi-reader typed arena with contiguous storage.
+///
+/// A lighter [`FastArena`](crate::FastArena) for pipelines with exactly one
+/// writer. There are no readiness flags and no cooperative publication
+/// protocol: the lone [`SpscWriter`] writes the slot and then stores the new
+/// length with `Release`. Readers see the same `&T` and `&[T]` access as
+/// `FastArena`.
+///
+/// `SpscArena<T>` is `Send + Sync` when `T: Send + Sync`.
+///
+/// # Example
+///
+/// ```
+/// use fast_bump::SpscArena;
+///
+/// let arena = SpscArena::with_capacity(16);
+/// std::thread::scope(|s| {
+///     let mut writer = arena.writer().expect("no other writer");
+///     s.spawn(move || {
+///         for i in 0..16 {
+///             writer.alloc(i);
+///         }
+///     });
+///     s.spawn(|| {
+///         let seen = arena.as_slice();
+///         assert!(seen.iter().enumerate().all(|(i, &v)| v == i));
+///     });
+/// });
+/// assert_eq!(arena.len(), 16);
+/// ```
+pub struct SpscArena<T> {
+    /// Storage for values. Length = capacity.
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    /// Boundary: all slots `< published` are written and readable.
+    published: AtomicUsize,
+    /// Set while an `SpscWriter` exists.
+    writer_taken: AtomicBool,
+}
+
+// SAFETY: slots < published are initialized and never mutated through
+// `&self`. Only the unique SpscWriter writes, and only to slots >= published,
+// before publishing them with a Release store.
+unsafe impl<T: Send + Sync> Send for SpscArena<T> {}
+unsafe impl<T: Send + Sync> Sync for SpscArena<T> {}
+
+const INITIAL_CAP: usize = 64;
+
+impl<T> SpscArena<T> {
+    /// Creates a new arena with default initial capacity.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(INITIAL_CAP)
+    }
+
+    /// Creates a new arena with the specified capacity.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: new_slots(capacity),
+            published: AtomicUsize::new(0),
+            writer_taken: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the unique writer handle, or `None` if one is already live.
+    ///
+    /// The handle is released when dropped.
+    pub fn writer(&self) -> Option<SpscWriter<'_, T>> {
+        self.writer_taken
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()?;
+        Some(SpscWriter {
+            arena: self,
+            next: self.published.load(Ordering::Relaxed),
+        })
+    }
+
+    /// Returns a reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[must_use]
+    pub fn get(&self, idx: Idx<T>) -> &T {
+        let i = idx.into_raw();
+        let published = self.len();
+        assert!(
+            i < published,
+            "index out of bounds: index is {i} but published length is {published}",
+        );
+        &self.as_slice()[i]
+    }
+
+    /// Returns a mutable reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, idx: Idx<T>) -> &mut T {
+        let i = idx.into_raw();
+        let published = *self.published.get_mut();
+        assert!(
+            i < published,
+            "index out of bounds: index is {i} but published length is {published}",
+        );
+        &mut self.as_mut_slice()[i]
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if out of bounds.
+    #[must_use]
+    pub fn try_get(&self, idx: Idx<T>) -> Option<&T> {
+        self.as_slice().get(idx.into_raw())
+    }
+
+    /// Returns the number of published items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.published.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if the arena contains no items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the current capacity.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns `true` if `idx` points to a valid item.
+    #[must_use]
+    pub fn is_valid(&self, idx: Idx<T>) -> bool {
+        idx.into_raw() < self.len()
+    }
+
+    /// Returns a contiguous slice of all published items.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        let len = self.len();
+        // SAFETY: slots[0..len] are initialized; the Acquire load
+        // synchronizes with the writer's Release store. UnsafeCell and
+        // MaybeUninit are layout-transparent over T.
+        unsafe { std::slice::from_raw_parts(self.slots.as_ptr().cast::<T>(), len) }
+    }
+
+    /// Returns a mutable slice of all published items.
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = *self.published.get_mut();
+        // SAFETY: &mut self guarantees exclusive access; slots[0..len] are
+        // initialized.
+        unsafe { std::slice::from_raw_parts_mut(self.slots.as_mut_ptr().cast::<T>(), len) }
+    }
+
+    /// Returns an iterator over all published items.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Saves the current allocation state.
+    #[must_use]
+    pub fn checkpoint(&self) -> Checkpoint<T> {
+        Checkpoint::from_len(self.len())
+    }
+
+    /// Rolls back to a previous checkpoint, dropping all values
+    /// allocated after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cp` points beyond the current length.
+    pub fn rollback(&mut self, cp: Checkpoint<T>) {
+        let current = *self.published.get_mut();
+        assert!(
+            cp.len() <= current,
+            "checkpoint {} beyond current length {current}",
+            cp.len(),
+        );
+        *self.published.get_mut() = cp.len();
+        for slot in &mut self.slots[cp.len()..current] {
+            // SAFETY: the slot was below the old published length, so it is
+            // initialized, and it is no longer reachable.
+            unsafe { slot.get_mut().assume_init_drop() };
+        }
+    }
+
+    /// Removes all items, running their destructors.
+    pub fn reset(&mut self) {
+        self.rollback(Checkpoint::from_len(0));
+    }
+
+    /// Grows the arena to at least `min_capacity`.
+    ///
+    /// No-op if current capacity is already sufficient. Existing indices
+    /// remain valid.
+    pub fn grow_to(&mut self, min_capacity: usize) {
+        if min_capacity <= self.slots.len() {
+            return;
+        }
+        let mut slots = std::mem::take(&mut self.slots).into_vec();
+        slots.resize_with(min_capacity, || UnsafeCell::new(MaybeUninit::uninit()));
+        self.slots = slots.into_boxed_slice();
+    }
+}
+
+impl<T> Default for SpscArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::ops::Index<Idx<T>> for SpscArena<T> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.get(idx)
+    }
+}
+
+impl<T> std::ops::IndexMut<Idx<T>> for SpscArena<T> {
+    fn index_mut(&mut self, idx: Idx<T>) -> &mut T {
+        self.get_mut(idx)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SpscArena<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Drop for SpscArena<T> {
+    fn drop(&mut self) {
+        self.reset();
+    }
+}
+
+/// Unique writer handle for an [`SpscArena`].
+///
+/// Created by [`SpscArena::writer`]. Can be moved to the producer thread.
+pub struct SpscWriter<'a, T> {
+    arena: &'a SpscArena<T>,
+    /// Next slot to write; equals the arena's published length.
+    next: usize,
+}
+
+// SAFETY: the writer only moves `T` values into the arena and hands out
+// indices; it is the unique writer by construction.
+unsafe impl<T: Send + Sync> Send for SpscWriter<'_, T> {}
+
+impl<T> SpscWriter<'_, T> {
+    /// Allocates a value, returning its stable index.
+    ///
+    /// One plain write and one `Release` store; no read-modify-write.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full.
+    pub fn alloc(&mut self, value: T) -> Idx<T> {
+        let slot = self.next;
+        let cap = self.arena.capacity();
+        assert!(slot < cap, "arena full: slot {slot} >= capacity {cap}");
+        // SAFETY: slot >= published, so no reader can observe it, and this
+        // writer is unique.
+        unsafe { (*self.arena.slots[slot].get()).write(value) };
+        self.next = slot + 1;
+        self.arena.published.store(self.next, Ordering::Release);
+        Idx::from_raw(slot)
+    }
+
+    /// Returns the number of slots still available.
+    #[must_use]
+    pub const fn remaining_capacity(&self) -> usize {
+        self.arena.capacity() - self.next
+    }
+}
+
+impl<T> Drop for SpscWriter<'_, T> {
+    fn drop(&mut self) {
+        self.arena.writer_taken.store(false, Ordering::Release);
+    }
+}
+
+fn new_slots<T>(capacity: usize) -> Box<[UnsafeCell<MaybeUninit<T>>]> {
+    (0..capacity)
+        .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+        .collect()
+}
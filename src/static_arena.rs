@@ -0,0 +1,121 @@
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::{Arena, Idx};
+
+/// [`Arena<T>`] behind an `RwLock`, declarable as a `static`.
+///
+/// [`new`](StaticArena::new) is `const`, so a global arena needs no
+/// `LazyLock` or `once_cell` glue. To start from a preallocated or
+/// prefilled arena, build it inside a `LazyLock` with
+/// [`with_capacity`](StaticArena::with_capacity) or
+/// [`from_arena`](StaticArena::from_arena).
+///
+/// ```
+/// use std::sync::LazyLock;
+/// use fast_bump::StaticArena;
+///
+/// static SYMBOLS: StaticArena<&str> = StaticArena::new();
+/// static TYPES: LazyLock<StaticArena<u32>> =
+///     LazyLock::new(|| StaticArena::with_capacity(1024));
+///
+/// let main = SYMBOLS.alloc("main");
+/// assert_eq!(SYMBOLS.get_cloned(main), "main");
+/// TYPES.alloc(7);
+/// assert_eq!(TYPES.read().as_slice(), [7]);
+/// ```
+///
+/// A panic while the lock is held does not poison the arena: later calls
+/// see the items allocated so far.
+pub struct StaticArena<T> {
+    arena: RwLock<Arena<T>>,
+}
+
+impl<T> StaticArena<T> {
+    /// Creates an empty arena; usable in a `static` initializer.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_arena(Arena::new())
+    }
+
+    /// Creates an arena with pre-allocated capacity for `capacity` items.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_arena(Arena::with_capacity(capacity))
+    }
+
+    /// Wraps an existing arena.
+    #[must_use]
+    pub const fn from_arena(arena: Arena<T>) -> Self {
+        Self {
+            arena: RwLock::new(arena),
+        }
+    }
+
+    /// Allocates `value` under the write lock and returns its index.
+    pub fn alloc(&self, value: T) -> Idx<T> {
+        self.write().alloc(value)
+    }
+
+    /// Returns a clone of the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[must_use]
+    pub fn get_cloned(&self, idx: Idx<T>) -> T
+    where
+        T: Clone,
+    {
+        self.read()[idx].clone()
+    }
+
+    /// Returns the number of allocated items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Returns `true` if no items have been allocated.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Locks the arena for reading, e.g. to borrow several values at once.
+    pub fn read(&self) -> RwLockReadGuard<'_, Arena<T>> {
+        self.arena.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks the arena for writing, e.g. to allocate a batch or roll back.
+    pub fn write(&self) -> RwLockWriteGuard<'_, Arena<T>> {
+        self.arena.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Unwraps the arena.
+    #[must_use]
+    pub fn into_inner(self) -> Arena<T> {
+        self.arena
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Default for StaticArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Arena<T>> for StaticArena<T> {
+    fn from(arena: Arena<T>) -> Self {
+        Self::from_arena(arena)
+    }
+}
+
+impl<T> std::fmt::Debug for StaticArena<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaticArena")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,191 @@
+/// Allocation statistics of an arena.
+///
+/// Returned by [`Arena::stats`](crate::Arena::stats) and
+/// [`FastArena::stats`](crate::FastArena::stats). Counters cover the whole
+/// lifetime of the arena, across resets and rollbacks, which makes them
+/// suitable for sizing `with_capacity` from production data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct ArenaStats {
+    /// Values allocated over the arena's lifetime.
+    pub total_allocs: u64,
+    /// Values currently in the arena.
+    pub len: usize,
+    /// Highest `len` ever reached (high-water mark).
+    pub peak_len: usize,
+    /// Bytes occupied by current values: `len * size_of::<T>()`.
+    pub bytes_used: usize,
+    /// Current capacity in values.
+    pub capacity: usize,
+    /// Heap memory held by the arena, as reported by `memory_usage`.
+    pub heap_bytes: usize,
+    /// Calls to `reset`.
+    pub resets: u64,
+    /// Calls to `rollback`.
+    pub rollbacks: u64,
+    /// Times the backing storage was reallocated to a larger capacity.
+    pub grows: u64,
+    /// Rollback depths and reuse, if enabled with `set_rollback_stats`.
+    pub rollback: Option<RollbackStats>,
+}
+
+/// Number of [`RollbackStats::depths`] buckets: depth 0, then one per
+/// power of two up to `usize::MAX`.
+const DEPTH_BUCKETS: usize = usize::BITS as usize + 1;
+
+/// Distribution of rollback depths and how much of the discarded space was
+/// allocated again.
+///
+/// Part of [`ArenaStats`] once enabled with
+/// [`Arena::set_rollback_stats`](crate::Arena::set_rollback_stats) or
+/// [`FastArena::set_rollback_stats`](crate::FastArena::set_rollback_stats).
+/// Deep rollbacks followed by little reuse suggest a `reset` or
+/// `shrink_to_fit` policy; shallow rollbacks that are refilled right away
+/// suggest keeping the capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RollbackStats {
+    /// Rollbacks by depth (values discarded): `depths[0]` counts empty
+    /// rollbacks and `depths[i]` depths in `2^(i-1)..2^i`.
+    pub depths: [u64; DEPTH_BUCKETS],
+    /// Deepest rollback seen.
+    pub max_depth: usize,
+    /// Values discarded by rollbacks, the sum of all depths.
+    pub discarded: u64,
+    /// Slots discarded by a rollback that were allocated again before the
+    /// next rollback, reset or drain; at most `discarded`.
+    pub reused: u64,
+    /// Lowest length a rollback went back to (low-water mark), or
+    /// `usize::MAX` before the first one.
+    pub min_len: usize,
+}
+
+impl RollbackStats {
+    /// Returns the `depths` bucket counting rollbacks of `depth` values.
+    #[must_use]
+    pub const fn bucket(depth: usize) -> usize {
+        (usize::BITS - depth.leading_zeros()) as usize
+    }
+}
+
+impl Default for RollbackStats {
+    fn default() -> Self {
+        Self {
+            depths: [0; DEPTH_BUCKETS],
+            max_depth: 0,
+            discarded: 0,
+            reused: 0,
+            min_len: usize::MAX,
+        }
+    }
+}
+
+/// Rollback tracking state behind `StatsCounters::rollback_stats`.
+struct RollbackCounters {
+    stats: RollbackStats,
+    /// Lengths before and after the last rollback, until the next shrink
+    /// settles how much of it was reused.
+    pending: Option<(usize, usize)>,
+}
+
+impl RollbackCounters {
+    /// Reuse of the pending rollback if the length is now `len`.
+    fn pending_reuse(&self, len: usize) -> u64 {
+        self.pending
+            .map_or(0, |(from, to)| len.min(from).saturating_sub(to) as u64)
+    }
+}
+
+/// Lifetime counters kept by an arena.
+///
+/// Only updated where the length shrinks or storage grows, so the
+/// allocation fast path stays untouched: `total_allocs` and `peak_len` are
+/// derived from the current length when a snapshot is taken.
+#[derive(Default)]
+pub struct StatsCounters {
+    /// Values removed by rollback, reset, or drain.
+    retired: u64,
+    /// Highest length seen before a shrink.
+    peak_len: usize,
+    resets: u64,
+    rollbacks: u64,
+    grows: u64,
+    /// Boxed so arenas that never enable it pay one word.
+    rollback_stats: Option<Box<RollbackCounters>>,
+}
+
+impl StatsCounters {
+    pub const fn new() -> Self {
+        Self {
+            retired: 0,
+            peak_len: 0,
+            resets: 0,
+            rollbacks: 0,
+            grows: 0,
+            rollback_stats: None,
+        }
+    }
+
+    /// Records the length shrinking from `from` to `to`.
+    pub fn shrink(&mut self, from: usize, to: usize) {
+        self.peak_len = self.peak_len.max(from);
+        self.retired += (from - to) as u64;
+        if let Some(counters) = &mut self.rollback_stats {
+            counters.stats.reused += counters.pending_reuse(from);
+            counters.pending = None;
+        }
+    }
+
+    /// Starts or stops tracking [`RollbackStats`]; stopping discards them.
+    pub fn set_rollback_stats(&mut self, enabled: bool) {
+        if !enabled {
+            self.rollback_stats = None;
+        } else if self.rollback_stats.is_none() {
+            self.rollback_stats = Some(Box::new(RollbackCounters {
+                stats: RollbackStats::default(),
+                pending: None,
+            }));
+        }
+    }
+
+    pub const fn reset(&mut self) {
+        self.resets += 1;
+    }
+
+    /// Records a rollback from `from` to `to`, after its `shrink`.
+    pub fn rollback(&mut self, from: usize, to: usize) {
+        self.rollbacks += 1;
+        if let Some(counters) = &mut self.rollback_stats {
+            let depth = from - to;
+            let stats = &mut counters.stats;
+            stats.depths[RollbackStats::bucket(depth)] += 1;
+            stats.max_depth = stats.max_depth.max(depth);
+            stats.discarded += depth as u64;
+            stats.min_len = stats.min_len.min(to);
+            counters.pending = Some((from, to));
+        }
+    }
+
+    pub const fn grow(&mut self) {
+        self.grows += 1;
+    }
+
+    /// Builds the public statistics for an arena of `T` at `len`.
+    pub fn snapshot<T>(&self, len: usize, capacity: usize, heap_bytes: usize) -> ArenaStats {
+        ArenaStats {
+            total_allocs: self.retired + len as u64,
+            len,
+            peak_len: self.peak_len.max(len),
+            bytes_used: len * size_of::<T>(),
+            capacity,
+            heap_bytes,
+            resets: self.resets,
+            rollbacks: self.rollbacks,
+            grows: self.grows,
+            rollback: self.rollback_stats.as_ref().map(|counters| RollbackStats {
+                reused: counters.stats.reused + counters.pending_reuse(len),
+                ..counters.stats
+            }),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Atomics used by `FastArena`'s publication protocol.
+//!
+//! Built with `RUSTFLAGS="--cfg loom"`, these are loom's model-checked
+//! atomics, so loom can explore every interleaving of `alloc`,
+//! `advance_published` and `get`. Loom atomics cannot hand out `&mut` to
+//! their value, so exclusive access goes through [`Exclusive`] instead of
+//! `get_mut`.
+
+#[cfg(loom)]
+pub use loom::sync::atomic::{AtomicU64, AtomicUsize};
+#[cfg(not(loom))]
+pub use std::sync::atomic::{AtomicU64, AtomicUsize};
+
+pub use std::sync::atomic::Ordering;
+
+/// Unsynchronized access to an atomic through `&mut self`.
+pub trait Exclusive<T> {
+    /// Returns the value; `&mut self` rules out concurrent writers.
+    fn read(&mut self) -> T;
+
+    /// Replaces the value.
+    fn write(&mut self, value: T);
+}
+
+macro_rules! impl_exclusive {
+    ($($atomic:ty => $value:ty),*) => {$(
+        impl Exclusive<$value> for $atomic {
+            #[cfg(not(loom))]
+            fn read(&mut self) -> $value {
+                *self.get_mut()
+            }
+
+            #[cfg(loom)]
+            fn read(&mut self) -> $value {
+                self.with_mut(|value| *value)
+            }
+
+            #[cfg(not(loom))]
+            fn write(&mut self, value: $value) {
+                *self.get_mut() = value;
+            }
+
+            #[cfg(loom)]
+            fn write(&mut self, value: $value) {
+                self.with_mut(|slot| *slot = value);
+            }
+        }
+    )*};
+}
+
+impl_exclusive!(AtomicU64 => u64, AtomicUsize => usize);
+
+/// Gives other threads a chance to run inside a spin-wait loop.
+///
+/// Under loom every wait must yield to the model scheduler, otherwise the
+/// spinning thread is explored forever.
+#[cfg(loom)]
+pub fn spin_wait() {
+    loom::thread::yield_now();
+}
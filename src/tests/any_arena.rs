@@ -0,0 +1,63 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use super::Tracked;
+use crate::AnyArena;
+
+#[test]
+fn alloc_and_get_per_type() {
+    let mut any = AnyArena::new();
+    let a = any.alloc(1u32);
+    let b = any.alloc(String::from("b"));
+    let c = any.alloc(2u32);
+
+    assert_eq!(*any.get(a), 1);
+    assert_eq!(any.get(b), "b");
+    *any.get_mut(c) += 1;
+    assert_eq!(any.arena::<u32>().unwrap().as_slice(), &[1, 3]);
+    assert!(any.arena::<u8>().is_none());
+    assert_eq!(any.len(), 3);
+}
+
+#[test]
+fn checkpoint_spans_all_inner_arenas() {
+    let drops = Rc::new(Cell::new(0));
+    let mut any = AnyArena::new();
+    any.alloc(1u32);
+    let cp = any.checkpoint();
+    any.alloc(2u32);
+    any.alloc(Tracked(Rc::clone(&drops)));
+
+    any.rollback(&cp);
+    assert_eq!(drops.get(), 1);
+    assert_eq!(any.arena::<u32>().unwrap().as_slice(), &[1]);
+    assert!(any.arena::<Tracked>().unwrap().is_empty());
+
+    any.reset();
+    assert!(any.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "index 0 out of bounds for u64")]
+fn get_of_unallocated_type_panics() {
+    let any = AnyArena::new();
+    let _ = any.get(crate::Idx::<u64>::from_raw(0));
+}
+
+#[test]
+fn rollback_checks_every_arena_before_dropping_anything() {
+    let mut any = AnyArena::new();
+    let early = any.checkpoint();
+    any.alloc(1u32);
+    any.alloc(String::from("kept"));
+    let late = any.checkpoint();
+    any.alloc(2u32);
+    any.rollback(&early);
+    any.alloc(String::from("a"));
+    any.alloc(String::from("b"));
+
+    // The `u32` arena is now shorter than `late` recorded.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| any.rollback(&late)));
+    assert!(result.is_err());
+    assert_eq!(any.arena::<String>().unwrap().as_slice(), ["a", "b"]);
+}
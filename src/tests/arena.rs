@@ -364,6 +364,30 @@ fn iter_indexed_exact_size() {
     assert_eq!(iter.len(), 3);
 }
 
+#[test]
+fn iter_indexed_reverses() {
+    let mut arena = Arena::new();
+    let a = arena.alloc(1);
+    let b = arena.alloc(2);
+    let c = arena.alloc(3);
+
+    let mut iter = arena.iter_indexed();
+    assert_eq!(iter.next_back(), Some((c, &3)));
+    let rest = iter.clone();
+    assert_eq!(iter.next(), Some((a, &1)));
+    assert_eq!(iter.next_back(), Some((b, &2)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(rest.len(), 2);
+
+    for (idx, val) in arena.iter_indexed_mut().rev().take(1) {
+        assert_eq!(idx, c);
+        *val = 30;
+    }
+    assert_eq!(arena.iter_indexed().rfind(|(_, v)| **v < 3), Some((b, &2)));
+    assert_eq!(arena[c], 30);
+}
+
 #[test]
 fn shrink_to_fit_reduces_capacity() {
     let mut arena: Arena<u64> = Arena::with_capacity(1000);
@@ -526,3 +550,674 @@ fn into_iter_consuming() {
     let collected: Vec<String> = arena.into_iter().collect();
     assert_eq!(collected, vec!["a", "b", "c"]);
 }
+
+#[test]
+fn as_slice_and_as_mut_slice() {
+    let mut arena: Arena<i32> = (1..=3).collect();
+    assert_eq!(arena.as_slice(), &[1, 2, 3]);
+
+    arena.as_mut_slice()[1] = 20;
+    assert_eq!(arena.as_slice(), &[1, 20, 3]);
+}
+
+#[test]
+fn zip_indexed_parallel_arenas() {
+    let names: Arena<&str> = ["a", "b", "c"].into_iter().collect();
+    let sizes: Arena<u32> = [1, 2, 3].into_iter().collect();
+
+    let rows: Vec<_> = zip_indexed(&names, &sizes)
+        .map(|(idx, n, s)| (idx.into_raw(), *n, *s))
+        .collect();
+    assert_eq!(rows, vec![(0, "a", 1), (1, "b", 2), (2, "c", 3)]);
+}
+
+#[test]
+fn zip_indexed_mixed_arena_types() {
+    let names: Arena<&str> = ["x", "y"].into_iter().collect();
+    let sizes: FastArena<u32> = [7, 8].into_iter().collect();
+
+    assert_eq!(zip_indexed(&names, &sizes).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "zip_indexed length mismatch: 2 vs 1")]
+fn zip_indexed_length_mismatch_panics() {
+    let a: Arena<i32> = [1, 2].into_iter().collect();
+    let b: Arena<i32> = std::iter::once(1).collect();
+    let _ = zip_indexed(&a, &b);
+}
+
+#[test]
+fn find_idx_and_rfind_idx() {
+    let arena: Arena<i32> = [1, 4, 2, 4, 3].into_iter().collect();
+
+    assert_eq!(arena.find_idx(|&v| v == 4), Some(Idx::from_raw(1)));
+    assert_eq!(arena.rfind_idx(|&v| v == 4), Some(Idx::from_raw(3)));
+    assert_eq!(arena.find_idx(|&v| v > 10), None);
+    assert_eq!(arena.rfind_idx(|&v| v > 10), None);
+}
+
+#[test]
+fn contains_and_idx_of_value() {
+    let arena: Arena<&str> = ["http", "ftp", "http"].into_iter().collect();
+    assert!(arena.contains(&"ftp"));
+    assert!(!arena.contains(&"ssh"));
+    assert_eq!(arena.idx_of_value(&"http"), Some(Idx::from_raw(0)));
+    assert_eq!(arena.idx_of_value(&"ssh"), None);
+}
+
+#[test]
+fn idx_of_roundtrips_references() {
+    let arena: Arena<u64> = (0..10).collect();
+    for (idx, value) in arena.iter_indexed() {
+        assert_eq!(arena.idx_of(value), Some(idx));
+    }
+
+    let outside = 3u64;
+    assert_eq!(arena.idx_of(&outside), None);
+}
+
+#[test]
+fn idx_of_zero_sized_is_none() {
+    let arena: Arena<()> = std::iter::repeat_n((), 3).collect();
+    assert_eq!(arena.idx_of(&arena[Idx::from_raw(1)]), None);
+}
+
+#[test]
+fn binary_search_by_key_returns_idx() {
+    let arena: Arena<(u32, &str)> = [(1, "a"), (4, "b"), (9, "c")].into_iter().collect();
+
+    assert_eq!(
+        arena.binary_search_by_key(&4, |&(start, _)| start),
+        Ok(Idx::from_raw(1))
+    );
+    assert_eq!(
+        arena.binary_search_by_key(&5, |&(start, _)| start),
+        Err(Idx::from_raw(2))
+    );
+    assert_eq!(
+        arena.binary_search_by(|probe| probe.0.cmp(&0)),
+        Err(Idx::from_raw(0))
+    );
+}
+
+#[test]
+fn sort_by_returns_permutation() {
+    let mut arena = Arena::new();
+    let c = arena.alloc("c");
+    let a = arena.alloc("a");
+    let d = arena.alloc("d");
+    let b = arena.alloc("b");
+
+    let perm = arena.sort_by(Ord::cmp);
+    assert_eq!(arena.as_slice(), &["a", "b", "c", "d"]);
+    assert_eq!(perm.len(), 4);
+    assert!(!perm.is_identity());
+
+    assert_eq!(arena[perm.remap(a)], "a");
+    assert_eq!(arena[perm.remap(b)], "b");
+    assert_eq!(arena[perm.remap(c)], "c");
+    assert_eq!(arena[perm.remap(d)], "d");
+    assert_eq!(perm.try_remap(Idx::from_raw(4)), None);
+}
+
+#[test]
+fn sort_by_is_stable() {
+    let mut arena: Arena<(u8, char)> = [(2, 'x'), (1, 'y'), (2, 'z'), (1, 'w')]
+        .into_iter()
+        .collect();
+
+    let _ = arena.sort_by(|l, r| l.0.cmp(&r.0));
+    assert_eq!(arena.as_slice(), &[(1, 'y'), (1, 'w'), (2, 'x'), (2, 'z')]);
+}
+
+#[test]
+fn sort_unstable_by_sorted_is_identity() {
+    let mut arena: Arena<i32> = (0..10).collect();
+    let perm = arena.sort_unstable_by(Ord::cmp);
+    assert!(perm.is_identity());
+}
+
+#[test]
+fn retain_and_append_share_idx_remap() {
+    let mut arena: Arena<String> = ["a", "b", "c", "d"].map(String::from).into_iter().collect();
+    let remap = arena.retain(|s| s != "b");
+    assert_eq!(arena.as_slice(), ["a", "c", "d"]);
+    assert_eq!(remap.removed(), 1);
+    assert_eq!(remap.try_remap(Idx::from_raw(1)), None);
+    assert_eq!(remap.try_remap(Idx::from_raw(4)), None);
+    assert_eq!(
+        format!("{remap:?}"),
+        "{0: Some(0), 1: None, 2: Some(1), 3: Some(2)}"
+    );
+
+    let mut ids = [Idx::from_raw(3), Idx::from_raw(0)];
+    remap.remap_slice(&mut ids);
+    assert_eq!(ids.map(|idx| arena[idx].as_str()), ["d", "a"]);
+
+    let mut other: Arena<String> = ["x", "y"].map(String::from).into_iter().collect();
+    let moved = arena.append(&mut other);
+    assert!(other.is_empty());
+    let pairs: Vec<_> = moved
+        .iter()
+        .map(|(old, new)| (old.into_raw(), new.unwrap().into_raw()))
+        .collect();
+    assert_eq!(pairs, [(0, 3), (1, 4)]);
+    assert_eq!(arena[moved.remap(Idx::from_raw(1))], "y");
+}
+
+#[test]
+#[should_panic(expected = "index 1 refers to a removed value")]
+fn remap_panics_on_removed_value() {
+    let mut arena: Arena<i32> = (0..3).collect();
+    let remap = arena.retain(|&v| v != 1);
+    let _ = remap.remap(Idx::from_raw(1));
+}
+
+#[test]
+fn sorted_indices_by_key_leaves_storage_alone() {
+    let arena: Arena<(u8, char)> = [(2, 'x'), (1, 'y'), (2, 'z'), (1, 'w')]
+        .into_iter()
+        .collect();
+
+    let order: Vec<_> = arena
+        .sorted_indices_by_key(|item| item.0)
+        .into_iter()
+        .map(Idx::into_raw)
+        .collect();
+    assert_eq!(order, [1, 3, 0, 2]);
+    let chars: String = arena
+        .iter_sorted_by_key(|item| item.1)
+        .map(|(_, item)| item.1)
+        .collect();
+    assert_eq!(chars, "wxyz");
+    assert_eq!(arena.as_slice()[0], (2, 'x'));
+}
+
+#[test]
+fn next_idx_and_last_idx() {
+    let mut arena = Arena::new();
+    assert_eq!(arena.next_idx(), Idx::from_raw(0));
+    assert_eq!(arena.last_idx(), None);
+
+    let predicted = arena.next_idx();
+    let a = arena.alloc(1);
+    assert_eq!(predicted, a);
+    assert_eq!(arena.last_idx(), Some(a));
+    assert_eq!(arena.next_idx(), Idx::from_raw(1));
+}
+
+#[test]
+fn stats_track_lifetime_counters() {
+    let mut arena = Arena::with_capacity(2);
+    arena.alloc(1u32);
+    arena.alloc(2);
+    arena.alloc(3);
+    let cp = arena.checkpoint();
+    arena.alloc(4);
+    arena.rollback(cp);
+    arena.reset();
+    arena.alloc(5);
+
+    let stats = arena.stats();
+    assert_eq!(stats.total_allocs, 5);
+    assert_eq!(stats.len, 1);
+    assert_eq!(stats.peak_len, 4);
+    assert_eq!(stats.bytes_used, 4);
+    assert_eq!(stats.rollbacks, 1);
+    assert_eq!(stats.resets, 1);
+    assert_eq!(stats.grows, 1);
+    assert_eq!(stats.rollback, None);
+}
+
+#[test]
+fn rollback_stats_record_depths_and_reuse() {
+    let mut arena: Arena<u32> = (0..10).collect();
+    arena.set_rollback_stats(true);
+    arena.truncate(4);
+    arena.extend([1, 2]);
+    arena.truncate(5);
+    arena.alloc(3);
+
+    let stats = arena.stats().rollback.unwrap();
+    assert_eq!(stats.depths[RollbackStats::bucket(6)], 1);
+    assert_eq!(stats.depths[RollbackStats::bucket(1)], 1);
+    assert_eq!(stats.depths[0], 0);
+    assert_eq!(RollbackStats::bucket(6), 3);
+    assert_eq!(stats.max_depth, 6);
+    assert_eq!(stats.discarded, 7);
+    // 2 of the first 6 slots were refilled, and 1 of the last one.
+    assert_eq!(stats.reused, 3);
+    assert_eq!(stats.min_len, 4);
+
+    arena.set_rollback_stats(false);
+    assert_eq!(arena.stats().rollback, None);
+}
+
+#[test]
+fn observer_sees_lifecycle_events() {
+    let recorder = super::Recorder::default();
+    let mut arena = Arena::with_capacity(1);
+    arena.set_observer(recorder.clone());
+
+    arena.alloc(1u32);
+    arena.alloc(2);
+    let cp = arena.checkpoint();
+    arena.alloc_extend([3, 4]);
+    arena.alloc_uninit().write(5);
+    arena.rollback(cp);
+    arena.reset();
+    let events = recorder.take();
+    assert_eq!(events[0], "alloc 0+1");
+    assert!(events[1].starts_with("grow 1->"));
+    assert_eq!(events[2], "alloc 1+1");
+    assert!(events.contains(&String::from("alloc 2+2")));
+    assert!(events.contains(&String::from("alloc 4+1")));
+    assert_eq!(events[events.len() - 2..], ["rollback 5->2", "reset 2"]);
+
+    arena.clear_observer();
+    arena.alloc(6);
+    assert!(recorder.take().is_empty());
+}
+
+#[test]
+fn with_limit_rejects_allocations_past_budget() {
+    let mut arena = Arena::with_limit(ArenaLimit::Bytes(8));
+    assert_eq!(arena.limit(), Some(ArenaLimit::Bytes(8)));
+    assert!(arena.try_alloc(1u32).is_ok());
+    assert!(arena.try_alloc(2).is_ok());
+    assert_eq!(
+        arena.try_alloc(3),
+        Err(Error::Limit {
+            limit: ArenaLimit::Bytes(8)
+        }),
+    );
+    arena.reset();
+    assert_eq!(arena.alloc(4), Idx::from_raw(0));
+}
+
+#[test]
+#[should_panic(expected = "arena limit exceeded: 2 items")]
+fn alloc_extend_past_limit_panics() {
+    let mut arena = Arena::with_limit(ArenaLimit::Items(2));
+    arena.alloc_extend([1, 2, 3]);
+}
+
+#[test]
+fn alloc_extend_reads_one_value_past_limit() {
+    let mut arena = Arena::with_limit(ArenaLimit::Items(2));
+    let mut input = 0..;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arena.alloc_extend(input.by_ref())
+    }));
+    assert!(result.is_err());
+    assert_eq!(input.next(), Some(3));
+    assert!(arena.is_empty());
+}
+
+#[test]
+fn memory_usage_counts_capacity() {
+    let arena: Arena<u64> = Arena::with_capacity(10);
+    assert_eq!(arena.memory_usage(), 80);
+    assert_eq!(arena.stats().heap_bytes, 80);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[cfg_attr(miri, ignore)] // reads through a deliberately stale pointer
+fn rollback_poisons_freed_slots_in_debug() {
+    let mut arena = Arena::with_capacity(4);
+    arena.alloc(1u32);
+    let cp = arena.checkpoint();
+    arena.alloc(2);
+    arena.alloc(3);
+    let stale = arena.as_slice().as_ptr();
+    arena.rollback(cp);
+
+    let poison = u32::from_ne_bytes([crate::scrub::POISON; 4]);
+    // SAFETY: slots 1 and 2 lie within capacity and were just poisoned.
+    assert_eq!(unsafe { stale.add(1).read() }, poison);
+    assert_eq!(unsafe { stale.add(2).read() }, poison);
+}
+
+#[test]
+fn try_with_capacity_reports_overflow() {
+    assert_eq!(
+        Arena::<u64>::try_with_capacity(usize::MAX).err(),
+        Some(ArenaAllocError::CapacityOverflow),
+    );
+    let arena = Arena::<u64>::try_with_capacity(16).unwrap();
+    assert!(arena.capacity() >= 16);
+}
+
+#[test]
+fn try_reserve_leaves_arena_usable_on_error() {
+    let mut arena = Arena::new();
+    arena.alloc(1u64);
+    assert_eq!(
+        arena.try_reserve(usize::MAX),
+        Err(ArenaAllocError::CapacityOverflow)
+    );
+    assert_eq!(
+        ArenaAllocError::CapacityOverflow.to_string(),
+        "capacity overflow"
+    );
+    assert!(arena.try_reserve(10).is_ok());
+    assert!(arena.capacity() >= 11);
+    assert_eq!(arena.as_slice(), &[1]);
+}
+
+#[test]
+fn alloc_mut_returns_the_new_item() {
+    let mut arena = Arena::new();
+    let (a, first) = arena.alloc_mut(vec![1]);
+    first.push(2);
+    let (b, second) = arena.alloc_mut(Vec::new());
+    second.push(3);
+
+    assert_eq!(arena[a], [1, 2]);
+    assert_eq!(arena[b], [3]);
+}
+
+#[test]
+fn get_unchecked_reads_valid_indices() {
+    let mut arena = Arena::new();
+    let a = arena.alloc(1);
+    let b = arena.alloc(2);
+    // SAFETY: both indices were just allocated.
+    unsafe {
+        *arena.get_unchecked_mut(b) += 10;
+        assert_eq!(*arena.get_unchecked(a) + *arena.get_unchecked(b), 13);
+    }
+}
+
+#[test]
+fn vec_round_trip_preserves_indices() {
+    let mut arena = Arena::from(vec![String::from("a"), String::from("b")]);
+    let c = arena.alloc(String::from("c"));
+    assert_eq!(arena[c], "c");
+
+    let fast = FastArena::from(arena);
+    assert_eq!(fast[c], "c");
+    let back = Arena::from(fast);
+    assert_eq!(back.into_vec(), ["a", "b", "c"]);
+}
+
+#[test]
+fn growth_policy_picks_capacity() {
+    let mut arena = Arena::with_capacity(10);
+    arena.set_growth_policy(GrowthPolicy::Increment(5));
+    arena.extend(0..11);
+    assert_eq!(arena.capacity(), 15);
+    arena.reserve(10);
+    assert_eq!(arena.capacity(), 21);
+
+    arena.set_growth_policy(GrowthPolicy::Percent(50));
+    arena.extend(11..22);
+    assert_eq!(arena.capacity(), 31);
+    assert_eq!(arena.growth_policy(), GrowthPolicy::Percent(50));
+}
+
+#[test]
+fn bulk_initialization() {
+    let mut arena = Arena::new();
+    arena.alloc(String::from("head"));
+    let filled = arena.alloc_fill(3, String::from("x"));
+    assert_eq!(filled, IdxRange::from_raw(1, 4));
+    assert_eq!(arena[filled.last().unwrap()], "x");
+    let defaults = arena.alloc_default_n(2);
+    assert_eq!(arena[defaults.start()], "");
+    assert_eq!(arena.len(), 6);
+
+    arena.resize_with(2, || unreachable!());
+    assert_eq!(arena.as_slice(), ["head", "x"]);
+    let mut n = 0;
+    arena.resize_with(4, || {
+        n += 1;
+        n.to_string()
+    });
+    assert_eq!(arena.as_slice(), ["head", "x", "1", "2"]);
+}
+
+#[test]
+#[should_panic(expected = "limit")]
+fn alloc_fill_respects_limit() {
+    let mut arena = Arena::<u8>::with_limit(ArenaLimit::Items(4));
+    arena.alloc_fill(5, 0);
+}
+
+#[test]
+fn truncate_without_checkpoint() {
+    let mut arena: Arena<i32> = (0..5).collect();
+    arena.truncate(10);
+    assert_eq!(arena.len(), 5);
+    arena.truncate(4);
+    assert_eq!(arena.as_slice(), [0, 1, 2, 3]);
+
+    let b = arena.alloc(9);
+    arena.alloc(10);
+    arena.truncate_to(b);
+    assert!(!arena.is_valid(b));
+    assert_eq!(arena.as_slice(), [0, 1, 2, 3]);
+    assert_eq!(arena.stats().rollbacks, 2);
+}
+
+#[test]
+fn rollback_with_visits_discarded_values() {
+    let mut arena: Arena<i32> = (0..5).collect();
+    let mut seen = Vec::new();
+    arena.rollback_with(Checkpoint::from_len(2), |v| seen.push(*v));
+    assert_eq!(seen, [2, 3, 4]);
+    assert_eq!(arena.len(), 2);
+
+    arena.reset_with(|v| seen.push(*v));
+    assert_eq!(seen, [2, 3, 4, 0, 1]);
+    assert!(arena.is_empty());
+}
+
+#[test]
+fn drop_order_is_configurable() {
+    let log = Rc::default();
+    let mut arena = Arena::new();
+    assert_eq!(arena.drop_order(), DropOrder::Forward);
+    arena.extend((0..4).map(|i| DropLog(i, Rc::clone(&log))));
+    arena.rollback(Checkpoint::from_len(2));
+    assert_eq!(*log.borrow(), [2, 3]);
+
+    arena.set_drop_order(DropOrder::Reverse);
+    arena.extend((4..6).map(|i| DropLog(i, Rc::clone(&log))));
+    arena.truncate(3);
+    drop(arena);
+    assert_eq!(*log.borrow(), [2, 3, 5, 4, 1, 0]);
+}
+
+#[test]
+fn retain_drops_removed_items_in_drop_order() {
+    let log = Rc::default();
+    let mut arena: Arena<_> = (0..5).map(|i| DropLog(i, Rc::clone(&log))).collect();
+    arena.set_drop_order(DropOrder::Reverse);
+    arena.retain(|item| item.0 % 2 == 1);
+    assert_eq!(*log.borrow(), [4, 2, 0]);
+    assert_eq!(arena.iter().map(|item| item.0).collect::<Vec<_>>(), [1, 3]);
+}
+
+#[test]
+fn append_past_limit_leaves_other_untouched() {
+    let mut arena = Arena::with_limit(ArenaLimit::Items(3));
+    arena.alloc(0);
+    let mut other: Arena<i32> = (1..4).collect();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arena.append(&mut other);
+    }));
+    assert!(result.is_err());
+    assert_eq!(arena.as_slice(), [0]);
+    assert_eq!(other.as_slice(), [1, 2, 3]);
+}
+
+#[test]
+fn items_may_borrow_locals_dropped_before_the_arena() {
+    // Compiles only while `Arena` lets dropck see through to `T`, as `Vec`
+    // does: `s` is dropped before `arena`.
+    let mut arena = Arena::new();
+    let s = String::from("x");
+    let idx = arena.alloc(&s);
+    assert_eq!(arena[idx], "x");
+}
+
+#[test]
+fn arena_is_unwind_safe() {
+    fn assert_unwind_safe<T: std::panic::RefUnwindSafe + std::panic::UnwindSafe>() {}
+    assert_unwind_safe::<Arena<u32>>();
+}
+
+#[test]
+fn idx_arithmetic() {
+    let mut arena = Arena::new();
+    let first = arena.alloc_extend(["a", "b", "c"]).unwrap();
+    let third = first.checked_add(2).unwrap();
+    assert_eq!(arena[third], "c");
+    assert_eq!(arena[first.successor()], "b");
+    assert_eq!(first.distance(third), 2);
+    assert_eq!(third.distance(first), 2);
+    assert!(!arena.contains_idx(third.successor()));
+    assert_eq!(Idx::<u8>::from_raw(usize::MAX).checked_add(1), None);
+}
+
+#[test]
+fn slice_between_indices() {
+    let mut arena: Arena<i32> = (0..6).collect();
+    let (a, b) = (Idx::from_raw(1), Idx::from_raw(4));
+    assert_eq!(arena.slice(a..b), [1, 2, 3]);
+    arena.slice_mut(a..b).fill(0);
+    let block = arena.alloc_fill(2, 7);
+    assert_eq!(arena[block], [7, 7]);
+    assert_eq!(arena.as_slice(), [0, 0, 0, 0, 4, 5, 7, 7]);
+    assert_eq!(arena.try_slice(b..Idx::from_raw(9)), None);
+    assert_eq!(arena.get_span(b, Idx::from_raw(6)), Some(&[4, 5][..]));
+    assert_eq!(arena.get_span(b, b), Some(&[][..]));
+    assert_eq!(arena.get_span(b, a), None);
+    assert_eq!(arena.get_span(a, Idx::from_raw(9)), None);
+}
+
+#[test]
+fn alloc_try_leaves_arena_unchanged_on_error() {
+    let mut arena: Arena<u32> = Arena::new();
+    let a = arena.alloc_try(|| "7".parse()).unwrap();
+    assert!(arena.alloc_try(|| "x".parse()).is_err());
+    assert_eq!(arena[a], 7);
+
+    let parsed = arena.alloc_extend_try(["1", "2"].map(str::parse));
+    assert_eq!(parsed, Ok(Some(Idx::from_raw(1))));
+    assert!(
+        arena
+            .alloc_extend_try(["3", "x", "4"].map(str::parse))
+            .is_err()
+    );
+    assert_eq!(arena.as_slice(), [7, 1, 2]);
+}
+
+#[test]
+fn split_at_mut_separates_old_and_new_items() {
+    let mut arena: Arena<i32> = (1..=2).collect();
+    let boundary = arena.next_idx();
+    arena.extend([10, 20]);
+    let (old, new) = arena.split_at_mut(boundary);
+    new[0] += old[1];
+    old[0] = 0;
+    assert_eq!(arena.as_slice(), [0, 2, 12, 20]);
+    let (all, none) = arena.split_at_mut(Idx::from_raw(4));
+    assert_eq!((all.len(), none.len()), (4, 0));
+}
+
+#[test]
+fn drain_indexed_keeps_old_indices() {
+    let mut arena = Arena::new();
+    let a = arena.alloc(String::from("a"));
+    let b = arena.alloc(String::from("b"));
+
+    let moved: std::collections::HashMap<_, _> = arena.drain_indexed().collect();
+    assert!(arena.is_empty());
+    assert_eq!(moved[&a], "a");
+    assert_eq!(moved[&b], "b");
+}
+
+#[test]
+fn keys_snapshot_allows_mutation() {
+    let mut arena = Arena::new();
+    arena.alloc(1);
+    arena.alloc(2);
+
+    for idx in arena.keys() {
+        let doubled = arena[idx] * 2;
+        arena.alloc(doubled);
+        arena[idx] = 0;
+    }
+    assert_eq!(arena.as_slice(), &[0, 0, 2, 4]);
+}
+
+#[test]
+fn try_variants_classify_failures() {
+    let mut arena = Arena::new();
+    let a = arena.alloc(1);
+    let cp = arena.checkpoint();
+    let b = arena.alloc(2);
+    let late = arena.checkpoint();
+
+    arena.rollback(cp);
+    assert_eq!(arena.try_index(a), Ok(&1));
+    assert_eq!(
+        arena.try_index(b),
+        Err(Error::StaleIndex { index: 1, len: 1 })
+    );
+    assert_eq!(
+        arena.try_rollback(late),
+        Err(Error::CheckpointBeyondLen {
+            checkpoint: 2,
+            len: 1
+        })
+    );
+    *arena.try_index_mut(a).unwrap() = 5;
+    assert_eq!(arena[a], 5);
+
+    let err = Error::from(ArenaAllocError::CapacityOverflow);
+    assert_eq!(err.to_string(), "capacity overflow");
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn transfer_promotes_scratch_values() {
+    let mut long = Arena::new();
+    long.alloc(String::from("kept"));
+    let mut scratch = Arena::new();
+    let a = scratch.alloc(String::from("a"));
+    let cp = scratch.checkpoint();
+    scratch.alloc(String::from("b"));
+    scratch.alloc(String::from("c"));
+
+    let copy = scratch.transfer(a, &mut long);
+    assert_eq!(long[copy], "a");
+    let copies = scratch.transfer_range(a..Idx::from_raw(2), &mut long);
+    assert_eq!(long.slice(copies), ["a", "b"]);
+
+    let moved = scratch.transfer_tail(cp, &mut long);
+    assert_eq!(long.slice(moved), ["b", "c"]);
+    assert_eq!(moved.start(), Idx::from_raw(4));
+    assert_eq!(scratch.as_slice(), ["a"]);
+    assert_eq!(scratch.stats().rollbacks, 1);
+}
+
+#[test]
+fn take_items_hands_over_storage() {
+    let mut arena = Arena::with_capacity(8);
+    let a = arena.alloc(1);
+    arena.alloc(2);
+    let ptr = arena.as_slice().as_ptr();
+
+    let items = arena.take_items();
+    assert_eq!(items, [1, 2]);
+    assert_eq!(items.as_ptr(), ptr);
+    assert_eq!(items[a.into_raw()], 1);
+    assert!(arena.is_empty());
+    assert_eq!(arena.capacity(), 0);
+    assert_eq!(arena.alloc(3), Idx::from_raw(0));
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+use std::thread;
+
+use crate::{ArenaRef, FastArena, Idx};
+
+#[test]
+fn derefs_to_the_value_and_keeps_the_arena_alive() {
+    let arena = Arc::new(FastArena::with_capacity(4));
+    let handle = ArenaRef::alloc(&arena, String::from("node"));
+    drop(arena);
+
+    let clone = handle.clone();
+    let len = thread::spawn(move || clone.len()).join().unwrap();
+    assert_eq!(len, 4);
+    assert_eq!(*handle, "node");
+    assert_eq!(handle.idx(), Idx::from_raw(0));
+    assert_eq!(Arc::strong_count(handle.arena()), 1);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn new_rejects_unpublished_index() {
+    let arena = Arc::new(FastArena::<u32>::with_capacity(4));
+    let _ = ArenaRef::new(arena, Idx::from_raw(0));
+}
@@ -0,0 +1,50 @@
+use std::thread;
+use std::time::Duration;
+
+use super::*;
+
+#[test]
+fn writer_blocks_until_owner_resets() {
+    let staging = BoundedArena::new(FastArena::with_capacity(2));
+    let writer = staging.writer();
+    writer.alloc(1);
+    writer.alloc(2);
+    assert_eq!(writer.try_alloc(3), Err(3));
+    assert_eq!(writer.alloc_timeout(3, Duration::from_millis(10)), Err(3));
+
+    thread::scope(|s| {
+        let blocked = s.spawn(|| writer.alloc(3));
+        thread::sleep(Duration::from_millis(20));
+        assert!(!blocked.is_finished());
+        assert_eq!(staging.read().as_slice(), [1, 2]);
+        staging.reset();
+        assert_eq!(blocked.join().unwrap().into_raw(), 0);
+    });
+    assert_eq!(staging.read().as_slice(), [3]);
+}
+
+#[test]
+fn rollback_frees_slots_and_into_inner_waits_for_writers() {
+    let staging = BoundedArena::new(FastArena::with_capacity(2));
+    let writer = staging.writer();
+    writer.alloc(1);
+    let cp = staging.checkpoint();
+    writer.alloc(2);
+    staging.rollback(cp);
+    assert!(writer.try_alloc(4).is_ok());
+
+    let staging = staging.into_inner().err().unwrap();
+    drop(writer);
+    let arena = staging.into_inner().ok().unwrap();
+    assert_eq!(arena.as_slice(), [1, 4]);
+}
+
+#[test]
+fn alloc_timeout_accepts_any_timeout() {
+    let staging = BoundedArena::new(FastArena::with_capacity(2));
+    let writer = staging.writer();
+    assert_eq!(
+        writer.alloc_timeout(1, Duration::MAX).map(Idx::into_raw),
+        Ok(0)
+    );
+}
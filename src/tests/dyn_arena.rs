@@ -0,0 +1,41 @@
+use std::fmt::Debug;
+
+use super::*;
+
+#[test]
+fn stores_mixed_types_behind_a_trait() {
+    let mut arena: DynArena<dyn Debug> = DynArena::new();
+    let a = arena.alloc_dyn(1_u8, |v| v);
+    let b = arena.alloc_dyn(String::from("two"), |v| v);
+    let c = arena.alloc_dyn((), |v| v);
+    let big = arena.alloc_dyn([7_u64; 512], |v| v);
+    assert_eq!(format!("{:?}", &arena[a]), "1");
+    assert_eq!(format!("{:?}", &arena[b]), "\"two\"");
+    assert_eq!(format!("{:?}", &arena[c]), "()");
+    assert_eq!(format!("{:?}", &arena[big])[..4], *"[7, ");
+    assert_eq!(arena.len(), 4);
+    assert!(arena.try_get(Idx::from_raw(4)).is_none());
+}
+
+#[test]
+fn drops_values_on_reset_and_drop() {
+    let drops = Rc::new(Cell::new(0));
+    let mut arena: DynArena<dyn std::any::Any> = DynArena::new();
+    for _ in 0..100 {
+        arena.alloc_dyn(Tracked(Rc::clone(&drops)), |v| v);
+    }
+    arena.reset();
+    assert_eq!(drops.get(), 100);
+    assert!(arena.is_empty());
+    let idx = arena.alloc_dyn(Tracked(Rc::clone(&drops)), |v| v);
+    assert!(arena[idx].is::<Tracked>());
+    drop(arena);
+    assert_eq!(drops.get(), 101);
+}
+
+#[test]
+#[should_panic(expected = "must return the value it was given")]
+fn rejects_foreign_reference() {
+    let mut arena: DynArena<dyn Debug> = DynArena::new();
+    arena.alloc_dyn((1_u32, 2_u32), |v| &mut v.1);
+}
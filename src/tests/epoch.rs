@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn reset_invalidates_earlier_epochs() {
+    let mut arena = EpochArena::with_capacity(4);
+    let a = arena.alloc(1);
+    arena[a] += 10;
+    assert_eq!(arena.get(a), &11);
+    arena.reset();
+    assert_eq!(arena.epoch(), 1);
+    let b = arena.alloc(2);
+    assert_eq!(a.idx(), b.idx());
+    assert!(!arena.is_valid(a));
+    assert_eq!(arena.try_get_mut(a), None);
+    assert_eq!(arena.try_get(b), Some(&2));
+}
+
+#[test]
+#[should_panic(expected = "stale epoch")]
+fn get_panics_on_stale_epoch() {
+    let mut arena = EpochArena::new();
+    let a = arena.alloc("a");
+    arena.reset();
+    arena.alloc("b");
+    let _ = arena.get(a);
+}
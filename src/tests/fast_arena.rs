@@ -1,9 +1,13 @@
 use std::sync::Arc;
 use std::thread;
+use std::time::Duration;
 
-use crate::{Checkpoint, FastArena, Idx};
+use crate::{
+    Arena, ArenaAllocError, ArenaLimit, Checkpoint, DropOrder, Error, FastArena, GrowthPolicy, Idx,
+    IdxRange,
+};
 
-use super::Tracked;
+use super::{DropLog, Tracked};
 
 #[test]
 fn alloc_and_get() {
@@ -114,6 +118,42 @@ fn rollback_runs_destructors() {
     assert_eq!(drops.get(), 2);
 }
 
+#[test]
+fn iter_yields_items_published_at_creation() {
+    let arena = FastArena::with_capacity(1024);
+    arena.alloc_extend([0, 1, 2]);
+    let iter = arena.iter();
+    thread::scope(|s| {
+        s.spawn(|| {
+            for i in 3..500 {
+                arena.alloc(i);
+            }
+        });
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.clone().rev().copied().collect::<Vec<_>>(), [2, 1, 0]);
+        assert_eq!(format!("{iter:?}"), "[0, 1, 2]");
+        assert_eq!(iter.sum::<i32>(), 3);
+    });
+    assert_eq!((&arena).into_iter().len(), 500);
+}
+
+#[test]
+fn truncate_to_drops_from_index() {
+    let mut arena = FastArena::with_capacity(8);
+    arena.extend(0..5);
+    arena.truncate(10);
+    assert_eq!(arena.len(), 5);
+    arena.truncate(4);
+    assert_eq!(arena.as_slice(), [0, 1, 2, 3]);
+
+    let b = arena.alloc(9);
+    arena.alloc(10);
+    arena.truncate_to(b);
+    assert!(!arena.is_valid(b));
+    assert_eq!(arena.as_slice(), [0, 1, 2, 3]);
+    assert_eq!(arena.stats().rollbacks, 2);
+}
+
 #[test]
 fn reset() {
     use std::cell::Cell;
@@ -258,17 +298,66 @@ fn as_slice_contiguous() {
 #[test]
 fn alloc_extend() {
     let arena = FastArena::with_capacity(16);
-    let first = arena.alloc_extend(vec![10, 20, 30]);
+    let range = arena.alloc_extend(vec![10, 20, 30]);
 
-    assert_eq!(first, Some(Idx::from_raw(0)));
+    assert_eq!(range.start(), Idx::from_raw(0));
+    assert_eq!(range.len(), 3);
     assert_eq!(arena.as_slice(), &[10, 20, 30]);
 }
 
 #[test]
 fn alloc_extend_empty() {
     let arena = FastArena::<i32>::with_capacity(16);
-    let first = arena.alloc_extend(Vec::new());
-    assert_eq!(first, None);
+    let range = arena.alloc_extend(Vec::new());
+    assert!(range.is_empty());
+    assert_eq!(range.first(), None);
+}
+
+#[test]
+fn alloc_try_leaves_arena_unchanged_on_error() {
+    let arena = FastArena::<u32>::with_capacity(8);
+    assert!(arena.alloc_try(|| "x".parse()).is_err());
+    let a = arena.alloc_try(|| "7".parse()).unwrap();
+    assert_eq!(a, Idx::from_raw(0));
+
+    let block = arena.alloc_extend_try(["1", "2"].map(str::parse)).unwrap();
+    assert_eq!(arena[block], [1, 2]);
+    assert!(arena.alloc_extend_try(["3", "x"].map(str::parse)).is_err());
+    assert_eq!(arena.as_slice(), [7, 1, 2]);
+}
+
+#[test]
+fn alloc_copy_slice_from_threads() {
+    let arena = FastArena::with_capacity(64);
+    arena.alloc(0_u32);
+    let blocks: Vec<_> = thread::scope(|s| {
+        let handles: Vec<_> = (1..=4_u32)
+            .map(|t| {
+                let arena = &arena;
+                s.spawn(move || (t, arena.alloc_copy_slice(&[t; 8])))
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    for (t, block) in blocks {
+        assert_eq!(arena[block], [t; 8]);
+    }
+    assert_eq!(arena.len(), 33);
+    assert!(arena.alloc_copy_slice(&[]).is_empty());
+}
+
+#[test]
+fn alloc_copy_slice_spans_flag_words() {
+    let arena = FastArena::with_capacity(256);
+    let values: Vec<u32> = (0..200).collect();
+    let mut start = 0;
+    for len in [3, 61, 64, 70, 1, 1] {
+        let block = arena.alloc_copy_slice(&values[start..start + len]);
+        assert_eq!(block.start().into_raw(), start);
+        start += len;
+        assert_eq!(arena.len(), start);
+    }
+    assert_eq!(arena.as_slice(), values);
 }
 
 #[test]
@@ -418,3 +507,940 @@ fn capacity() {
     let arena = FastArena::<i32>::with_capacity(128);
     assert_eq!(arena.capacity(), 128);
 }
+
+#[test]
+fn find_idx_and_rfind_idx() {
+    let arena: FastArena<i32> = [5, 6, 5].into_iter().collect();
+
+    assert_eq!(arena.find_idx(|&v| v == 5), Some(Idx::from_raw(0)));
+    assert_eq!(arena.rfind_idx(|&v| v == 5), Some(Idx::from_raw(2)));
+    assert_eq!(arena.find_idx(|&v| v == 7), None);
+}
+
+#[test]
+fn idx_of() {
+    let arena: FastArena<u32> = (0..8).collect();
+    let value = arena.get(Idx::from_raw(5));
+    assert_eq!(arena.idx_of(value), Some(Idx::from_raw(5)));
+
+    let other: FastArena<u32> = (0..8).collect();
+    assert_eq!(arena.idx_of(&other[Idx::from_raw(5)]), None);
+}
+
+#[test]
+fn binary_search() {
+    let arena: FastArena<u32> = [10, 20, 30].into_iter().collect();
+
+    assert_eq!(arena.binary_search_by(|v| v.cmp(&30)), Ok(Idx::from_raw(2)));
+    assert_eq!(
+        arena.binary_search_by_key(&25, |&v| v),
+        Err(Idx::from_raw(2))
+    );
+}
+
+#[test]
+fn next_idx_and_last_idx() {
+    let arena = FastArena::with_capacity(4);
+    assert_eq!(arena.last_idx(), None);
+
+    let predicted = arena.next_idx();
+    let a = arena.alloc(1);
+    assert_eq!(predicted, a);
+    assert_eq!(arena.last_idx(), Some(a));
+    assert_eq!(arena.next_idx(), Idx::from_raw(1));
+}
+
+#[test]
+fn capacity_introspection() {
+    let arena = FastArena::with_capacity(3);
+    assert_eq!(arena.remaining_capacity(), 3);
+    assert_eq!(arena.reserved_len(), 0);
+    assert!(!arena.is_full());
+
+    arena.alloc(1);
+    arena.alloc(2);
+    assert_eq!(arena.remaining_capacity(), 1);
+    assert_eq!(arena.reserved_len(), 2);
+
+    arena.alloc(3);
+    assert!(arena.is_full());
+    assert_eq!(arena.remaining_capacity(), 0);
+}
+
+#[test]
+fn capacity_introspection_after_failed_alloc() {
+    let arena = Arc::new(FastArena::with_capacity(1));
+    arena.alloc(1);
+
+    let clone = Arc::clone(&arena);
+    let result = thread::spawn(move || clone.alloc(2)).join();
+    assert!(result.is_err());
+
+    assert_eq!(arena.reserved_len(), 1);
+    assert!(arena.is_full());
+}
+
+#[test]
+fn concurrent_alloc_extend_is_contiguous() {
+    let arena = Arc::new(FastArena::with_capacity(4 * 100 * 10));
+
+    let ranges: Vec<(IdxRange<u32>, u32)> = (0..4)
+        .map(|t| {
+            let arena = Arc::clone(&arena);
+            thread::spawn(move || {
+                (0..100)
+                    .map(|batch| {
+                        let tag = t * 1000 + batch;
+                        (arena.alloc_extend(std::iter::repeat_n(tag, 10)), tag)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .flat_map(|h| h.join().unwrap())
+        .collect();
+
+    for (range, tag) in ranges {
+        assert_eq!(range.len(), 10);
+        assert!(range.iter().all(|idx| arena[idx] == tag));
+    }
+}
+
+#[test]
+#[should_panic(expected = "arena full")]
+fn alloc_extend_panics_when_block_does_not_fit() {
+    let arena = FastArena::with_capacity(4);
+    arena.alloc(0);
+    let _ = arena.alloc_extend([1, 2, 3, 4]);
+}
+
+#[test]
+fn alloc_extend_reads_one_value_past_capacity() {
+    let arena = FastArena::with_capacity(4);
+    arena.alloc(0);
+    let mut input = 1..;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arena.alloc_extend(input.by_ref())
+    }));
+    assert!(result.is_err());
+    assert_eq!(input.next(), Some(5));
+    assert_eq!(arena.len(), 1);
+}
+
+#[test]
+fn alloc_extend_exact_size_reads_nothing_past_capacity() {
+    let arena = FastArena::with_capacity(4);
+    arena.alloc(0);
+    let taken = std::cell::Cell::new(0);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arena.alloc_extend((1..=4).inspect(|_| taken.set(taken.get() + 1)))
+    }));
+    assert!(result.is_err());
+    assert_eq!(taken.get(), 0);
+    assert_eq!(arena.len(), 1);
+}
+
+/// Claims `len` items but yields only `yields` of them.
+struct ShortIter {
+    len: usize,
+    yields: usize,
+}
+
+impl Iterator for ShortIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        (self.yields > 0).then(|| {
+            self.yields -= 1;
+            self.yields
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+#[test]
+fn alloc_extend_exact_size_handles_short_iterator() {
+    let arena = FastArena::with_capacity(8);
+    let block = arena.alloc_extend(ShortIter { len: 4, yields: 2 });
+    assert_eq!(arena[block], [1, 0]);
+    // The unwritten tail was handed back.
+    assert!(!arena.is_poisoned());
+    assert_eq!(arena.alloc(9).into_raw(), 2);
+}
+
+#[test]
+fn alloc_with_allocates_large_value() {
+    let arena = FastArena::with_capacity(4);
+    let a = arena.alloc_with(|| [7u8; 2048]);
+    assert_eq!(arena[a].len(), 2048);
+    assert!(arena[a].iter().all(|&b| b == 7));
+}
+
+#[test]
+fn alloc_emplace_initializes_slot() {
+    let arena = FastArena::with_capacity(4);
+    let a = unsafe {
+        arena.alloc_emplace(|slot| {
+            slot.write((1u32, String::from("in place")));
+        })
+    };
+    assert_eq!(arena[a], (1, String::from("in place")));
+}
+
+#[test]
+fn alloc_with_panic_returns_slot() {
+    let arena = FastArena::with_capacity(4);
+    arena.alloc(1);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arena.alloc_with(|| -> i32 { panic!("constructor failed") })
+    }));
+    assert!(result.is_err());
+
+    assert_eq!(arena.reserved_len(), 1);
+    assert_eq!(arena.alloc(2), Idx::from_raw(1));
+    assert_eq!(arena.as_slice(), &[1, 2]);
+}
+
+#[test]
+fn rollback_across_flag_words() {
+    let mut arena = FastArena::with_capacity(200);
+    arena.alloc_extend(0..150);
+    arena.rollback(Checkpoint::from_len(70));
+    assert_eq!(arena.len(), 70);
+
+    arena.alloc_extend(1000..1100);
+    arena.grow_to(400);
+    arena.alloc_extend(2000..2200);
+    assert_eq!(arena.len(), 370);
+    assert_eq!(arena[Idx::from_raw(69)], 69);
+    assert_eq!(arena[Idx::from_raw(70)], 1000);
+    assert_eq!(arena[Idx::from_raw(369)], 2199);
+}
+
+#[test]
+fn zero_sized_values() {
+    let mut arena = FastArena::with_capacity(4);
+    let a = arena.alloc(());
+    arena.alloc_extend([(), ()]);
+    arena.grow();
+    arena.alloc(());
+    assert_eq!(arena.len(), 4);
+    assert_eq!(arena[a], ());
+}
+
+#[test]
+fn writer_waits_for_stalled_slot() {
+    let arena = Arc::new(FastArena::with_capacity(4));
+    let guard = arena.alloc_uninit();
+
+    let waiter = {
+        let arena = Arc::clone(&arena);
+        thread::spawn(move || arena.alloc(2))
+    };
+    thread::sleep(Duration::from_millis(20));
+    assert!(arena.is_empty());
+
+    guard.write(1);
+    assert_eq!(waiter.join().unwrap(), Idx::from_raw(1));
+    assert_eq!(arena.as_slice(), &[1, 2]);
+}
+
+#[test]
+fn rollback_clears_poison_and_drops_stranded_values() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let drops = Rc::new(Cell::new(0u32));
+    let mut arena = FastArena::with_capacity(8);
+    arena.alloc(Tracked(Rc::clone(&drops)));
+    let cp = arena.checkpoint();
+    let guard = arena.alloc_uninit();
+    let mut later = arena.reserve_slots(1);
+    later.write(Tracked(Rc::clone(&drops)));
+    drop(guard);
+    let _ = later.finish();
+    assert_eq!(arena.poisoned_at(), Some(Idx::from_raw(1)));
+    assert_eq!(arena.len(), 1);
+
+    arena.rollback(cp);
+    assert!(!arena.is_poisoned());
+    assert_eq!(drops.get(), 1);
+    assert_eq!(arena.alloc(Tracked(Rc::clone(&drops))), Idx::from_raw(1));
+    assert_eq!(arena.len(), 2);
+}
+
+#[test]
+fn get_relaxed_reads_ahead_of_published() {
+    let arena = FastArena::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let mut later = arena.reserve_slots(1);
+    let idx = later.write(5);
+    assert_eq!(arena.try_get_relaxed(idx), None);
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            let _ = later.finish();
+        });
+        while arena.try_get_relaxed(idx).is_none() {
+            thread::yield_now();
+        }
+        assert_eq!(*arena.get_relaxed(idx), 5);
+        assert!(!arena.is_valid(idx));
+        assert_eq!(arena.try_get_relaxed(Idx::from_raw(0)), None);
+        guard.write(1);
+    });
+    assert_eq!(arena.as_slice(), &[1, 5]);
+}
+
+#[test]
+#[should_panic(expected = "slot 9 is not ready")]
+fn get_relaxed_panics_on_unwritten_slot() {
+    let arena = FastArena::<u32>::with_capacity(4);
+    let _ = arena.get_relaxed(Idx::from_raw(9));
+}
+
+#[test]
+fn quiesce_waits_for_in_flight_slots() {
+    let arena = FastArena::with_capacity(4);
+    arena.alloc(1);
+    assert!(arena.try_quiesce(Duration::ZERO));
+
+    let guard = arena.alloc_uninit();
+    assert!(!arena.try_quiesce(Duration::from_millis(5)));
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(Duration::from_millis(10));
+            guard.write(2);
+        });
+        arena.quiesce();
+        assert_eq!(arena.as_slice(), &[1, 2]);
+    });
+}
+
+#[test]
+fn quiesce_ignores_slots_reserved_after_entry() {
+    let arena = FastArena::with_capacity(4);
+    assert!(arena.try_quiesce(Duration::MAX));
+
+    let guard = arena.alloc_uninit();
+    let (done, quiesced) = std::sync::mpsc::channel();
+    let arena = &arena;
+    thread::scope(|s| {
+        s.spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            let later = arena.alloc_uninit();
+            guard.write(1);
+            quiesced.recv().unwrap();
+            later.write(2);
+        });
+        arena.quiesce();
+        done.send(()).unwrap();
+    });
+    assert_eq!(arena.as_slice(), &[1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "cannot quiesce")]
+fn quiesce_panics_when_poisoned() {
+    let arena = FastArena::<u32>::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let _later = arena.alloc_uninit();
+    drop(guard);
+    assert!(!arena.try_quiesce(Duration::from_secs(1)));
+    arena.quiesce();
+}
+
+#[test]
+fn get_wait_blocks_until_published() {
+    let arena = FastArena::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let idx = guard.idx().assume_published();
+    assert_eq!(arena.get_wait_timeout(idx, Duration::from_millis(5)), None);
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(Duration::from_millis(10));
+            guard.write(7);
+        });
+        assert_eq!(*arena.get_wait(idx), 7);
+    });
+    assert_eq!(arena.get_wait_timeout(idx, Duration::ZERO), Some(&7));
+}
+
+#[test]
+fn reserve_one_fulfils_promises_out_of_order() {
+    let arena = FastArena::with_capacity(4);
+    let (first, a) = arena.reserve_one();
+    let (second, b) = arena.reserve_one();
+    assert_eq!((first.into_raw(), second.into_raw()), (0, 1));
+
+    let arena = &arena;
+    thread::scope(|s| {
+        let readers = [first, second].map(|idx| s.spawn(move || arena[arena.resolve_wait(idx)]));
+        let later = s.spawn(move || arena.alloc(30));
+        let b = s.spawn(move || b.write(20));
+        thread::sleep(Duration::from_millis(10));
+        assert_eq!(arena.resolve(second), None);
+        assert_eq!(a.write(10), arena.resolve(first).unwrap());
+        b.join().unwrap();
+        let values = readers.map(|r| r.join().unwrap());
+        assert_eq!(values, [10, 20]);
+        assert_eq!(later.join().unwrap().into_raw(), 2);
+    });
+    assert_eq!(arena.as_slice(), [10, 20, 30]);
+}
+
+#[test]
+fn get_async_resolves_once_published() {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    let arena = FastArena::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let mut future = pin!(arena.get_async(guard.idx().assume_published()));
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+
+    guard.write(3);
+    assert_eq!(future.poll(&mut cx), Poll::Ready(&3));
+}
+
+/// Counts how often it is woken.
+struct CountingWaker(std::sync::atomic::AtomicUsize);
+
+impl std::task::Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn get_async_is_woken_by_publication_only() {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::{Context, Poll, Waker};
+
+    let arena = FastArena::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let later = arena.alloc_uninit();
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(Arc::clone(&counter));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(arena.get_async(later.idx().assume_published()));
+    assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+    assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 0);
+
+    // Publishing an earlier slot does not reach the awaited one.
+    guard.write(1);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 0);
+    later.write(2);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+    assert_eq!(future.poll(&mut cx), Poll::Ready(&2));
+}
+
+#[test]
+fn get_async_is_woken_by_poisoning() {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::{Context, Poll, Waker};
+
+    let arena = FastArena::<u32>::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let later = arena.alloc_uninit();
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(Arc::clone(&counter));
+    let mut future = pin!(arena.get_async(later.idx().assume_published()));
+    assert_eq!(
+        future.as_mut().poll(&mut Context::from_waker(&waker)),
+        Poll::Pending
+    );
+    drop(guard);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn dropped_get_async_withdraws_its_waker() {
+    use std::future::Future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::{Context, Poll, Waker};
+
+    let arena = FastArena::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(Arc::clone(&counter));
+    let mut future = Box::pin(arena.get_async(guard.idx().assume_published()));
+    assert_eq!(
+        future.as_mut().poll(&mut Context::from_waker(&waker)),
+        Poll::Pending
+    );
+    drop(future);
+    // Only `counter` and `waker` are left; the arena holds no clone.
+    assert_eq!(Arc::strong_count(&counter), 2);
+    guard.write(1);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn get_wait_timeout_accepts_any_timeout() {
+    let arena = FastArena::with_capacity(4);
+    let idx = arena.alloc(1);
+    assert_eq!(arena.get_wait_timeout(idx, Duration::MAX), Some(&1));
+}
+
+#[test]
+#[should_panic(expected = "arena poisoned at slot 0")]
+fn get_wait_panics_past_poisoned_slot() {
+    let arena = FastArena::<u32>::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let later = arena.alloc_uninit();
+    let idx = later.idx().assume_published();
+    drop(guard);
+    let _ = arena.get_wait(idx);
+}
+
+#[test]
+fn fast_arena_is_unwind_safe() {
+    fn assert_unwind_safe<T: std::panic::RefUnwindSafe + std::panic::UnwindSafe>() {}
+    assert_unwind_safe::<FastArena<u32>>();
+}
+
+#[test]
+fn publish_hook_reports_new_length() {
+    use std::sync::Mutex;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let mut arena = FastArena::with_capacity(8);
+    let sink = Arc::clone(&seen);
+    arena.set_publish_hook(move |len| sink.lock().unwrap().push(len));
+
+    arena.alloc(1);
+    arena.alloc_extend([2, 3]);
+    assert_eq!(*seen.lock().unwrap(), [1, 3]);
+
+    arena.clear_publish_hook();
+    arena.alloc(4);
+    assert_eq!(seen.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn view_keeps_snapshot_length() {
+    let arena = FastArena::with_capacity(8);
+    let a = arena.alloc(1);
+    let view = arena.view();
+    let b = arena.alloc(2);
+
+    assert_eq!(view.len(), 1);
+    assert_eq!(view[a], 1);
+    assert_eq!(view.try_get(b), None);
+    assert!(!view.is_valid(b));
+    assert_eq!(view.iter().copied().collect::<Vec<_>>(), [1]);
+    assert_eq!(arena.view().as_slice(), &[1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "view length is 0")]
+fn view_panics_past_snapshot() {
+    let arena = FastArena::with_capacity(8);
+    let view = arena.view();
+    let idx = arena.alloc(1);
+    let _ = view.get(idx);
+}
+
+#[test]
+fn stats_track_lifetime_counters() {
+    let mut arena = FastArena::with_capacity(4);
+    arena.alloc_extend([1u64, 2, 3]);
+    let cp = arena.checkpoint();
+    arena.alloc(4);
+    arena.rollback(cp);
+    arena.grow();
+    arena.alloc(5);
+
+    let stats = arena.stats();
+    assert_eq!(stats.total_allocs, 5);
+    assert_eq!(stats.len, 4);
+    assert_eq!(stats.peak_len, 4);
+    assert_eq!(stats.bytes_used, 32);
+    assert_eq!(stats.capacity, 8);
+    assert_eq!(stats.rollbacks, 1);
+    assert_eq!(stats.resets, 0);
+    assert_eq!(stats.grows, 1);
+}
+
+#[test]
+fn observer_sees_lifecycle_events() {
+    let recorder = super::Recorder::default();
+    let mut arena = FastArena::with_capacity(2);
+    arena.set_observer(recorder.clone());
+
+    arena.alloc(1u32);
+    let cp = arena.checkpoint();
+    arena.alloc_extend([2]);
+    arena.grow();
+    arena.alloc_uninit().write(3);
+    arena.rollback(cp);
+    arena.reset();
+    assert_eq!(
+        recorder.take(),
+        [
+            "alloc 0+1",
+            "alloc 1+1",
+            "grow 2->4",
+            "alloc 2+1",
+            "rollback 3->1",
+            "reset 1",
+        ],
+    );
+}
+
+#[test]
+fn with_limit_caps_growth() {
+    let mut arena = FastArena::with_limit(ArenaLimit::Items(100));
+    assert_eq!(arena.capacity(), 64);
+    arena.grow();
+    assert_eq!(arena.capacity(), 100);
+
+    arena.alloc_extend(0..99u32);
+    assert!(arena.try_alloc(99).is_ok());
+    assert_eq!(
+        arena.try_alloc(100),
+        Err(Error::Limit {
+            limit: ArenaLimit::Items(100)
+        }),
+    );
+    assert_eq!(arena.reserved_len(), 100);
+}
+
+#[test]
+fn try_alloc_reports_full_capacity() {
+    let mut arena = FastArena::with_capacity(1);
+    arena.alloc(1);
+    assert_eq!(arena.try_alloc(2), Err(Error::Full { capacity: 1 }));
+    arena.grow();
+    assert_eq!(arena.try_alloc(2), Ok(Idx::from_raw(1)));
+}
+
+#[test]
+#[should_panic(expected = "arena limit exceeded: 0 items")]
+fn alloc_past_limit_panics() {
+    let arena = FastArena::with_limit(ArenaLimit::Items(0));
+    arena.alloc(1);
+}
+
+#[test]
+fn memory_usage_includes_flags() {
+    let arena = FastArena::<u64>::with_capacity(100);
+    // 100 values plus two 64-bit flag words.
+    assert_eq!(arena.memory_usage(), 800 + 16);
+    assert_eq!(arena.stats().heap_bytes, 816);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[cfg_attr(miri, ignore)] // reads through a deliberately stale pointer
+fn reset_poisons_freed_slots_in_debug() {
+    let mut arena = FastArena::with_capacity(4);
+    arena.alloc(1u32);
+    arena.alloc(2);
+    let stale = arena.as_slice().as_ptr();
+    arena.reset();
+
+    let poison = u32::from_ne_bytes([crate::scrub::POISON; 4]);
+    // SAFETY: the storage is still allocated and both slots were poisoned.
+    assert_eq!(unsafe { stale.read() }, poison);
+    assert_eq!(unsafe { stale.add(1).read() }, poison);
+}
+
+#[test]
+fn try_with_capacity_reports_overflow() {
+    assert_eq!(
+        FastArena::<u64>::try_with_capacity(usize::MAX).err(),
+        Some(ArenaAllocError::CapacityOverflow),
+    );
+    let arena = FastArena::<u64>::try_with_capacity(16).unwrap();
+    assert_eq!(arena.capacity(), 16);
+}
+
+#[test]
+fn try_grow_to_and_try_reserve() {
+    let mut arena = FastArena::with_capacity(2);
+    let a = arena.alloc(1u64);
+    assert_eq!(
+        arena.try_grow_to(usize::MAX),
+        Err(ArenaAllocError::CapacityOverflow)
+    );
+    assert_eq!(arena.capacity(), 2);
+
+    arena.try_reserve(5).unwrap();
+    assert_eq!(arena.capacity(), 6);
+    arena.try_grow().unwrap();
+    assert_eq!(arena.capacity(), 12);
+    assert_eq!(arena[a], 1);
+}
+
+#[test]
+fn alloc_ref_returns_the_new_item() {
+    let arena = FastArena::with_capacity(4);
+    let (a, first) = arena.alloc_ref(String::from("first"));
+    let (b, second) = arena.alloc_ref(String::from("second"));
+
+    assert_eq!((first.as_str(), second.as_str()), ("first", "second"));
+    assert!(std::ptr::eq(first, arena.get(a)));
+    assert_eq!(b, Idx::from_raw(1));
+}
+
+#[test]
+fn get_unchecked_reads_published_indices() {
+    let mut arena = FastArena::with_capacity(4);
+    let ids: Vec<_> = (0..4).map(|i| arena.alloc(i)).collect();
+    // SAFETY: all four slots were allocated on this thread.
+    unsafe {
+        *arena.get_unchecked_mut(ids[3]) = 30;
+        let sum: i32 = ids.iter().map(|&idx| *arena.get_unchecked(idx)).sum();
+        assert_eq!(sum, 33);
+    }
+}
+
+#[test]
+fn from_vec_reuses_storage_and_keeps_allocating() {
+    let mut items = Vec::with_capacity(8);
+    items.extend([1u64, 2, 3]);
+    let ptr = items.as_ptr();
+
+    let arena = FastArena::from(items);
+    assert_eq!(arena.capacity(), 8);
+    assert_eq!(arena.as_slice().as_ptr(), ptr);
+    let d = arena.alloc(4);
+    assert_eq!(d, Idx::from_raw(3));
+
+    let items = arena.into_vec();
+    assert_eq!(items, [1, 2, 3, 4]);
+    assert_eq!(items.as_ptr(), ptr);
+    assert_eq!(FastArena::from(Vec::<()>::new()).capacity(), 1);
+}
+
+#[test]
+fn into_arc_slice_keeps_indices() {
+    let arena = FastArena::with_capacity(8);
+    let a = arena.alloc(String::from("a"));
+    let b = arena.alloc(String::from("b"));
+    let shared = arena.into_arc_slice();
+    assert_eq!(shared.len(), 2);
+    assert_eq!(shared[b.into_raw()], "b");
+    assert_eq!(shared[a.into_raw()], "a");
+
+    let mut arena = Arena::with_capacity(16);
+    arena.alloc(1);
+    let boxed = arena.into_boxed_slice();
+    assert_eq!(*boxed, [1]);
+}
+
+#[test]
+fn grow_follows_policy_and_grow_by_is_exact() {
+    let mut arena = FastArena::<u8>::with_capacity(100);
+    arena.set_growth_policy(GrowthPolicy::Percent(10));
+    arena.grow();
+    assert_eq!(arena.capacity(), 110);
+    arena.grow_by(5);
+    assert_eq!(arena.capacity(), 115);
+
+    let mut limited = FastArena::<u8>::with_limit(ArenaLimit::Items(120));
+    limited.set_growth_policy(GrowthPolicy::Increment(1000));
+    limited.grow();
+    assert_eq!(limited.capacity(), 120);
+}
+
+#[test]
+fn ensure_capacity_grows_exactly_as_needed() {
+    let mut arena = FastArena::<u8>::with_capacity(4);
+    arena.alloc_extend([1, 2, 3]);
+    assert_eq!(arena.ensure_capacity(1), 4);
+    assert_eq!(arena.ensure_capacity(10), 13);
+    assert_eq!(arena.ensure_capacity(0), 13);
+
+    let mut limited = FastArena::<u8>::with_limit(ArenaLimit::Items(8));
+    assert_eq!(limited.ensure_capacity(100), 8);
+}
+
+#[test]
+fn aligned_storage_survives_grow() {
+    let mut arena = FastArena::<u8>::with_capacity_aligned(3, 64);
+    assert_eq!(arena.alignment(), 64);
+    arena.extend(0..3);
+    assert_eq!(arena.as_slice().as_ptr() as usize % 64, 0);
+    arena.grow();
+    arena.extend(3..6);
+    assert_eq!(arena.as_slice().as_ptr() as usize % 64, 0);
+    assert_eq!(arena.into_vec(), [0, 1, 2, 3, 4, 5]);
+
+    assert_eq!(FastArena::<u64>::with_capacity_aligned(1, 1).alignment(), 8);
+}
+
+#[test]
+fn rollback_with_visits_discarded_values() {
+    let mut arena: FastArena<i32> = (0..5).collect();
+    let mut seen = Vec::new();
+    arena.rollback_with(Checkpoint::from_len(3), |v| seen.push(*v));
+    assert_eq!(seen, [3, 4]);
+
+    arena.reset_with(|v| {
+        seen.push(*v);
+        *v = -1;
+    });
+    assert_eq!(seen, [3, 4, 0, 1, 2]);
+    assert!(arena.is_empty());
+}
+
+#[test]
+fn drop_order_is_configurable() {
+    let log = std::rc::Rc::default();
+    let mut arena = FastArena::with_capacity(8);
+    assert_eq!(arena.drop_order(), DropOrder::Reverse);
+    arena.extend((0..3).map(|i| DropLog(i, std::rc::Rc::clone(&log))));
+    arena.reset();
+    assert_eq!(*log.borrow(), [2, 1, 0]);
+
+    arena.set_drop_order(DropOrder::Forward);
+    arena.extend((3..6).map(|i| DropLog(i, std::rc::Rc::clone(&log))));
+    drop(arena);
+    assert_eq!(*log.borrow(), [2, 1, 0, 3, 4, 5]);
+}
+
+#[test]
+fn slice_between_indices() {
+    let arena = FastArena::with_capacity(8);
+    arena.alloc(0);
+    let block = arena.alloc_extend([1, 2, 3]);
+    assert_eq!(arena[block], [1, 2, 3]);
+    assert_eq!(arena.slice(block.start()..block.end()), [1, 2, 3]);
+    assert_eq!(arena.try_slice(IdxRange::from_raw(2, 5)), None);
+    assert_eq!(
+        arena.get_span(block.start(), block.end()),
+        Some(&[1, 2, 3][..])
+    );
+    assert_eq!(arena.get_span(block.end(), block.start()), None);
+}
+
+#[test]
+fn split_at_mut_separates_old_and_new_items() {
+    let mut arena = FastArena::with_capacity(8);
+    arena.alloc_extend([1, 2]);
+    let boundary = arena.next_idx();
+    arena.alloc_extend([10, 20]);
+    let (old, new) = arena.split_at_mut(boundary);
+    new[0] += old[1];
+    old[0] = 0;
+    assert_eq!(arena.as_slice(), [0, 2, 12, 20]);
+    let (all, none) = arena.split_at_mut(Idx::from_raw(4));
+    assert_eq!((all.len(), none.len()), (4, 0));
+}
+
+#[test]
+fn checkpoint_quiesced_covers_in_flight_slots() {
+    let mut arena = FastArena::with_capacity(4);
+    arena.alloc(1);
+    let guard = arena.alloc_uninit();
+    assert_eq!(arena.checkpoint().len(), 1);
+    assert_eq!(
+        arena.try_checkpoint_quiesced(Duration::from_millis(5)),
+        None
+    );
+
+    let cp = thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(Duration::from_millis(10));
+            guard.write(2);
+        });
+        arena.checkpoint_quiesced()
+    });
+    assert_eq!(cp.len(), 2);
+    arena.alloc(3);
+    arena.rollback(cp);
+    assert_eq!(arena.as_slice(), &[1, 2]);
+}
+
+#[test]
+fn local_writer_publishes_batches() {
+    let arena = FastArena::with_capacity(10);
+    let mut writer = arena.writer_with_batch(4);
+    let ids: Vec<_> = (0..5).map(|i| writer.alloc(i)).collect();
+    // The first batch was published when the second was reserved.
+    assert_eq!(arena.as_slice(), &[0, 1, 2, 3]);
+    assert_eq!(arena.reserved_len(), 8);
+    assert_eq!(writer.remaining(), 3);
+
+    writer.flush();
+    assert_eq!(arena.reserved_len(), 5);
+    assert_eq!(
+        ids.iter().map(|&i| arena[i]).collect::<Vec<_>>(),
+        [0, 1, 2, 3, 4]
+    );
+
+    // The last batch is cut to the remaining capacity.
+    for i in 5..10 {
+        writer.alloc(i);
+    }
+    drop(writer);
+    assert_eq!(arena.len(), 10);
+}
+
+#[test]
+fn drain_indexed_pairs_values_with_indices() {
+    let mut arena = FastArena::with_capacity(4);
+    let a = arena.alloc(1);
+    let b = arena.alloc(2);
+    let drained: Vec<_> = arena.drain_indexed().rev().collect();
+    assert_eq!(drained, [(b, 2), (a, 1)]);
+    assert!(arena.is_empty());
+}
+
+#[test]
+fn try_with_capacity_rejects_huge_sizes() {
+    // One value past `isize::MAX` bytes is a layout overflow, not a panic.
+    let just_over = isize::MAX.unsigned_abs() / 8 + 1;
+    assert_eq!(
+        FastArena::<u64>::try_with_capacity(just_over).err(),
+        Some(ArenaAllocError::CapacityOverflow),
+    );
+    assert_eq!(
+        FastArena::<u64>::try_with_capacity_aligned(just_over, 64).err(),
+        Some(ArenaAllocError::CapacityOverflow),
+    );
+    // Representable sizes the allocator cannot satisfy, including the
+    // readiness bits of a huge zero-sized arena, fail without aborting.
+    assert!(FastArena::<u8>::try_with_capacity(isize::MAX.unsigned_abs()).is_err());
+    assert!(FastArena::<()>::try_with_capacity(usize::MAX).is_err());
+    let arena = FastArena::<u8>::try_with_capacity_aligned(4, 128).unwrap();
+    arena.alloc(1);
+    assert_eq!(arena.as_slice().as_ptr().addr() % 128, 0);
+}
+
+#[test]
+fn into_iter_drops_unyielded_values_once() {
+    let log = std::rc::Rc::default();
+    let mut arena = FastArena::with_capacity(8);
+    arena.extend((0..5).map(|i| DropLog(i, std::rc::Rc::clone(&log))));
+    let mut iter = arena.into_iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next().map(|v| v.0), Some(0));
+    assert_eq!(iter.next_back().map(|v| v.0), Some(4));
+    assert_eq!(*log.borrow(), [0, 4]);
+    drop(iter);
+    assert_eq!(*log.borrow(), [0, 4, 3, 2, 1]);
+
+    let arena: FastArena<_> = (0..3).collect();
+    assert_eq!(arena.into_iter().rev().collect::<Vec<_>>(), [2, 1, 0]);
+}
@@ -0,0 +1,16 @@
+use super::*;
+
+#[test]
+fn ffi_handles_round_trip() {
+    let arena = FastArena::with_capacity(4);
+    let a = arena.alloc(10_u32);
+    let b = arena.alloc(20);
+
+    assert_eq!(Idx::<u32>::from_ffi(b.to_ffi()), Some(b));
+    let view = arena.as_ffi_slice();
+    assert_eq!(view.len, 2);
+    // SAFETY: the arena is alive and unchanged since the view was taken.
+    assert_eq!(unsafe { *view.get(a.to_ffi()) }, 10);
+    assert!(view.get(2).is_null());
+    assert!(view.get(u64::MAX).is_null());
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use std::thread;
+
+use crate::{Arena, FastArena, FrozenArena, Idx};
+
+#[test]
+fn freeze_arena_keeps_indices() {
+    let mut arena = Arena::with_capacity(16);
+    let a = arena.alloc(String::from("a"));
+    let b = arena.alloc(String::from("b"));
+
+    let frozen = arena.freeze();
+    assert_eq!(frozen.len(), 2);
+    assert_eq!(frozen[a], "a");
+    assert_eq!(frozen.get(b), "b");
+    assert_eq!(frozen.try_get(Idx::from_raw(2)), None);
+    assert_eq!(frozen.idx_of(&frozen[b]), Some(b));
+    assert_eq!(
+        frozen.iter_indexed().next_back(),
+        Some((b, &String::from("b")))
+    );
+}
+
+#[test]
+fn freeze_fast_arena_shares_across_threads() {
+    let arena = FastArena::with_capacity(8);
+    let idx = arena.alloc(10u64);
+    arena.alloc_extend([20, 30]);
+
+    let frozen: Arc<FrozenArena<u64>> = Arc::new(arena.freeze());
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let frozen = Arc::clone(&frozen);
+            thread::spawn(move || frozen[idx] + frozen.iter().sum::<u64>())
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 70);
+    }
+    assert_eq!(&**frozen, &[10, 20, 30]);
+}
+
+#[test]
+fn frozen_arena_drops_values_once() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::Tracked;
+
+    let drops = Rc::new(Cell::new(0u32));
+    let mut arena = Arena::new();
+    arena.alloc(Tracked(Rc::clone(&drops)));
+    arena.alloc(Tracked(Rc::clone(&drops)));
+    let frozen = arena.freeze();
+    assert_eq!(drops.get(), 0);
+    drop(frozen);
+    assert_eq!(drops.get(), 2);
+}
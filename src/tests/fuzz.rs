@@ -0,0 +1,26 @@
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::{ArbitraryArena, Arena, Idx};
+
+#[test]
+fn arbitrary_arena_indices_are_valid() {
+    let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&bytes);
+    for _ in 0..16 {
+        let ArbitraryArena { arena, indices } = ArbitraryArena::<u16>::arbitrary(&mut u).unwrap();
+        assert!(indices.iter().all(|&idx| arena.is_valid(idx)));
+        if arena.is_empty() {
+            assert!(indices.is_empty());
+        }
+    }
+}
+
+#[test]
+fn arbitrary_idx_reads_a_usize() {
+    let bytes = 7usize.to_le_bytes();
+    let idx = Idx::<String>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    assert_eq!(idx, Idx::from_raw(7));
+
+    let arena = Arena::<u8>::arbitrary(&mut Unstructured::new(&[1, 2, 3])).unwrap();
+    assert!(arena.len() <= 3);
+}
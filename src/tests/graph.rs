@@ -0,0 +1,51 @@
+use super::*;
+
+#[test]
+fn idx_set_tracks_membership() {
+    let mut set: IdxSet<u8> = [3, 70, 3].into_iter().map(Idx::from_raw).collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(Idx::from_raw(70)));
+    assert!(!set.contains(Idx::from_raw(4)));
+    assert!(!set.contains(Idx::from_raw(1000)));
+    assert!(set.remove(Idx::from_raw(3)));
+    assert!(!set.remove(Idx::from_raw(3)));
+    assert!(!set.remove(Idx::from_raw(1000)));
+    set.insert(Idx::from_raw(0));
+    let raw: Vec<_> = set.iter().map(Idx::into_raw).collect();
+    assert_eq!(raw, [0, 70]);
+    assert_eq!(format!("{set:?}"), "{Idx(0), Idx(70)}");
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn graph_traversals_visit_reachable_nodes_once() {
+    // a -> b -> d, a -> c -> d, d -> a, e isolated
+    let mut graph = GraphArena::new();
+    let [a, b, c, d, lone] = ["a", "b", "c", "d", "e"].map(|n| graph.add_node(n));
+    let ab = graph.add_edge(a, b, 1);
+    graph.add_edge(a, c, 2);
+    graph.add_edge(b, d, 3);
+    graph.add_edge(c, d, 4);
+    graph.add_edge(d, a, 5);
+
+    assert_eq!(graph.node_count(), 5);
+    assert_eq!(graph.edge_count(), 5);
+    assert_eq!(graph.endpoints(ab), (a, b));
+    *graph.edge_mut(ab) += 10;
+    let out: Vec<_> = graph
+        .edges_from(a)
+        .map(|(_, to, &w)| (graph[to], w))
+        .collect();
+    assert_eq!(out, [("b", 11), ("c", 2)]);
+    assert_eq!(graph.neighbors(lone).count(), 0);
+
+    let names =
+        |it: &mut dyn Iterator<Item = Idx<&'static str>>| it.map(|n| graph[n]).collect::<Vec<_>>();
+    assert_eq!(names(&mut graph.dfs(a)), ["a", "b", "d", "c"]);
+    assert_eq!(names(&mut graph.bfs(a)), ["a", "b", "c", "d"]);
+    assert_eq!(names(&mut graph.dfs(lone)), ["e"]);
+
+    graph.reset();
+    assert_eq!(graph.node_count(), 0);
+}
@@ -0,0 +1,39 @@
+use crate::{Idx, IdxRange};
+
+#[test]
+fn accessors() {
+    let range: IdxRange<u8> = IdxRange::new(Idx::from_raw(2), Idx::from_raw(5));
+    assert_eq!(range.start(), Idx::from_raw(2));
+    assert_eq!(range.end(), Idx::from_raw(5));
+    assert_eq!(range.len(), 3);
+    assert!(!range.is_empty());
+    assert_eq!(range.first(), Some(Idx::from_raw(2)));
+    assert_eq!(range.last(), Some(Idx::from_raw(4)));
+    assert!(range.contains(Idx::from_raw(4)));
+    assert!(!range.contains(Idx::from_raw(5)));
+}
+
+#[test]
+fn iterates_in_both_directions() {
+    let range: IdxRange<u8> = IdxRange::from_raw(0, 3);
+    let forward: Vec<usize> = range.into_iter().map(Idx::into_raw).collect();
+    let backward: Vec<usize> = range.iter().rev().map(Idx::into_raw).collect();
+    assert_eq!(forward, vec![0, 1, 2]);
+    assert_eq!(backward, vec![2, 1, 0]);
+    assert_eq!(range.iter().len(), 3);
+}
+
+#[test]
+fn empty_range() {
+    let range: IdxRange<u8> = IdxRange::from_raw(4, 4);
+    assert!(range.is_empty());
+    assert_eq!(range.first(), None);
+    assert_eq!(range.last(), None);
+    assert_eq!(range.iter().next(), None);
+}
+
+#[test]
+#[should_panic(expected = "range start 3 greater than end 1")]
+fn new_rejects_inverted_range() {
+    let _ = IdxRange::<u8>::new(Idx::from_raw(3), Idx::from_raw(1));
+}
@@ -0,0 +1,96 @@
+use std::hash::{BuildHasher, Hasher};
+
+use super::*;
+
+/// Hashes everything to the same value, forcing collisions.
+#[derive(Default)]
+struct Colliding;
+
+impl BuildHasher for Colliding {
+    type Hasher = ConstHasher;
+
+    fn build_hasher(&self) -> ConstHasher {
+        ConstHasher
+    }
+}
+
+struct ConstHasher;
+
+impl Hasher for ConstHasher {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, _: &[u8]) {}
+}
+
+#[test]
+fn intern_dedups_equal_values() {
+    let mut interner = Interner::new();
+    let a = interner.intern(vec![1, 2]);
+    let b = interner.intern(vec![3]);
+    assert_ne!(a, b);
+    assert_eq!(interner.intern(vec![1, 2]), a);
+    assert_eq!(interner.get(&[3][..]), Some(b));
+    assert_eq!(interner.get(&[4][..]), None);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.into_arena().as_slice(), [vec![1, 2], vec![3]]);
+}
+
+#[test]
+fn intern_walks_hash_collisions() {
+    let mut interner = Interner::with_hasher(Colliding);
+    let ids: Vec<_> = (0..4).map(|i| interner.intern(i)).collect();
+    for (i, &id) in ids.iter().enumerate() {
+        assert_eq!(interner.intern(i32::try_from(i).unwrap()), id);
+        assert_eq!(interner[id], i32::try_from(i).unwrap());
+    }
+    assert_eq!(interner.len(), 4);
+}
+
+#[test]
+fn extend_unique_reuses_existing_indices() {
+    let mut arena = Arena::new();
+    let a = arena.alloc(String::from("a"));
+    let ids = arena.extend_unique(["b", "a", "b", "c"].map(String::from));
+    assert_eq!(ids[1], a);
+    assert_eq!(ids[0], ids[2]);
+    assert_eq!(arena.as_slice(), ["a", "b", "c"]);
+
+    let mut index = ValueIndex::with_hasher(Colliding);
+    let cp = arena.checkpoint();
+    let ids = arena.extend_unique_with(["d", "c"].map(String::from), &mut index);
+    assert_eq!(ids[1].into_raw(), 2);
+    assert_eq!(index.len(), 4);
+
+    // The index notices the rollback and rebuilds.
+    arena.rollback(cp);
+    let ids = arena.extend_unique_with(["d", "a"].map(String::from), &mut index);
+    assert_eq!(ids, [Idx::from_raw(3), a]);
+    assert_eq!(index.len(), 4);
+}
+
+#[test]
+fn value_index_rebuilds_after_edits_that_keep_the_length() {
+    let mut arena: Arena<String> = ["x", "y"].map(String::from).into_iter().collect();
+    let mut index = ValueIndex::new();
+    arena.extend_unique_with([String::from("z")], &mut index);
+
+    // Rolled back and regrown past the indexed length.
+    arena.rollback(Checkpoint::from_len(0));
+    arena.extend(["p", "q", "x", "r"].map(String::from));
+    let ids = arena.extend_unique_with([String::from("x")], &mut index);
+    assert_eq!(ids, [Idx::from_raw(2)]);
+
+    // Reordered in place.
+    arena.sort_by(|a, b| b.cmp(a));
+    let ids = arena.extend_unique_with([String::from("x")], &mut index);
+    assert_eq!(arena[ids[0]], "x");
+    assert_eq!(arena.len(), 4);
+
+    // Overwritten through a mutable slice.
+    arena.as_mut_slice()[0] = String::from("w");
+    let ids = arena.extend_unique_with([String::from("w")], &mut index);
+    assert_eq!(ids, [Idx::from_raw(0)]);
+    assert_eq!(arena.len(), 4);
+}
@@ -0,0 +1,73 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use super::*;
+
+#[test]
+fn keyed_arena_allocates_once_per_key() {
+    let mut arena = KeyedArena::new();
+    let a = arena.get_or_alloc("a".to_string(), || 1);
+    let b = arena.get_or_alloc("b".to_string(), || 2);
+    assert_eq!(arena.get_or_alloc("a".to_string(), || 3), a);
+    assert_eq!(arena.get_idx("b"), Some(b));
+    assert_eq!(arena.get_idx("c"), None);
+    assert_eq!(arena.arena().as_slice(), [1, 2]);
+}
+
+#[test]
+fn keyed_fast_arena_keeps_one_value_per_key_across_threads() {
+    let arena = KeyedFastArena::with_capacity(16);
+    let calls = AtomicUsize::new(0);
+    let results: Vec<Vec<Idx<u32>>> = thread::scope(|s| {
+        let lookup = || {
+            (0..8_u32)
+                .map(|key| {
+                    arena.get_or_alloc(key, || {
+                        calls.fetch_add(1, Ordering::Relaxed);
+                        key * 10
+                    })
+                })
+                .collect()
+        };
+        let handles = [s.spawn(lookup), s.spawn(lookup), s.spawn(lookup)];
+        handles.map(|h| h.join().unwrap()).into()
+    });
+    assert!(results.windows(2).all(|w| w[0] == w[1]));
+    // Racing threads may each build a value, but only one is kept per key.
+    assert!(calls.load(Ordering::Relaxed) >= 8);
+    assert_eq!(arena.arena().len(), 8);
+    for (key, &idx) in (0..8).zip(&results[0]) {
+        assert_eq!(arena[idx], key * 10);
+    }
+}
+
+#[test]
+fn keyed_fast_arena_runs_init_outside_the_lock() {
+    let arena = KeyedFastArena::with_capacity(4);
+    let a = arena.get_or_alloc(1_u32, || {
+        // Would deadlock if `f` ran under the write lock.
+        assert_eq!(arena.get_idx(&1), None);
+        arena.get_or_alloc(2, || 20);
+        10
+    });
+    assert_eq!(arena[a], 10);
+    assert_eq!(arena.get_idx(&2).map(|idx| arena[idx]), Some(20));
+}
+
+#[test]
+fn reset_forgets_keys() {
+    let mut arena = KeyedArena::new();
+    let a = arena.get_or_alloc("a", || 1);
+    arena[a] += 1;
+    assert_eq!(arena[a], 2);
+    arena.reset();
+    assert_eq!(arena.get_idx("a"), None);
+    assert!(arena.is_empty());
+
+    let mut fast = KeyedFastArena::with_capacity(1);
+    fast.get_or_alloc("a", || 1);
+    fast.grow();
+    fast.reset();
+    assert_eq!(fast.get_idx("a"), None);
+    assert_eq!(fast.get_or_alloc("b", || 2).into_raw(), 0);
+}
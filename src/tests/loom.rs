@@ -0,0 +1,80 @@
+//! Model-checked tests of `FastArena`'s publication protocol.
+//!
+//! Run with `RUSTFLAGS="--cfg loom" cargo test --release --lib`.
+
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::FastArena;
+
+#[test]
+fn concurrent_allocs_are_all_published() {
+    loom::model(|| {
+        let arena = Arc::new(FastArena::with_capacity(2));
+        let writer = {
+            let arena = Arc::clone(&arena);
+            thread::spawn(move || arena.alloc(1u64))
+        };
+        let b = arena.alloc(2u64);
+        let a = writer.join().unwrap();
+
+        assert_eq!(arena.len(), 2);
+        assert_eq!(arena[a], 1);
+        assert_eq!(arena[b], 2);
+    });
+}
+
+#[test]
+fn reader_sees_published_value_or_nothing() {
+    loom::model(|| {
+        let arena = Arc::new(FastArena::with_capacity(1));
+        let writer = {
+            let arena = Arc::clone(&arena);
+            thread::spawn(move || {
+                arena.alloc(7u64);
+            })
+        };
+        if let Some(&value) = arena.try_get(crate::Idx::from_raw(0)) {
+            assert_eq!(value, 7);
+        }
+        writer.join().unwrap();
+        assert_eq!(arena.as_slice(), &[7]);
+    });
+}
+
+#[test]
+fn published_prefix_is_consistent() {
+    loom::model(|| {
+        let arena = Arc::new(FastArena::with_capacity(2));
+        let writer = {
+            let arena = Arc::clone(&arena);
+            thread::spawn(move || {
+                arena.alloc(1u64);
+                arena.alloc(2u64);
+            })
+        };
+        let seen = arena.as_slice();
+        assert_eq!(seen, &[1, 2][..seen.len()]);
+        writer.join().unwrap();
+        assert_eq!(arena.as_slice(), &[1, 2]);
+    });
+}
+
+#[test]
+fn overflowing_block_never_leaves_slots_stranded() {
+    loom::model(|| {
+        let arena = Arc::new(FastArena::<u64>::with_capacity(2));
+        let other = {
+            let arena = Arc::clone(&arena);
+            thread::spawn(move || arena.try_reserve_block(3))
+        };
+        assert_eq!(arena.try_reserve_block(3).map_err(|_| ()), Err(()));
+        assert_eq!(other.join().unwrap().map_err(|_| ()), Err(()));
+
+        // A block straddling capacity whose return raced with the other
+        // reservation must poison the slots it cannot give back.
+        if arena.reserved_len() > 0 {
+            assert_eq!(arena.poisoned_at(), Some(crate::Idx::from_raw(0)));
+        }
+    });
+}
@@ -11,5 +11,86 @@ impl Drop for Tracked {
     }
 }
 
+/// Pushes its id onto a shared log when dropped.
+struct DropLog(u32, Rc<std::cell::RefCell<Vec<u32>>>);
+
+impl Drop for DropLog {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+/// Observer that records every event as a string.
+#[derive(Clone, Default)]
+struct Recorder(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+impl Recorder {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+
+    fn push(&self, event: String) {
+        self.0.lock().unwrap().push(event);
+    }
+}
+
+impl ArenaObserver for Recorder {
+    fn on_alloc(&self, start: usize, count: usize) {
+        self.push(format!("alloc {start}+{count}"));
+    }
+
+    fn on_rollback(&self, from_len: usize, to_len: usize) {
+        self.push(format!("rollback {from_len}->{to_len}"));
+    }
+
+    fn on_reset(&self, len: usize) {
+        self.push(format!("reset {len}"));
+    }
+
+    fn on_grow(&self, old_capacity: usize, new_capacity: usize) {
+        self.push(format!("grow {old_capacity}->{new_capacity}"));
+    }
+}
+
+mod any_arena;
 mod arena;
+mod arena_ref;
+mod bounded;
+mod dyn_arena;
+mod epoch;
 mod fast_arena;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod frozen;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+mod graph;
+mod idx_range;
+mod interner;
+mod keyed;
+mod option_arena;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "rcu")]
+mod rcu;
+#[cfg(feature = "registry")]
+mod registry;
+mod ring_arena;
+mod row_arena;
+mod savepoints;
+mod sharded;
+mod slot;
+mod small_arena;
+#[cfg(feature = "derive")]
+mod soa;
+mod split;
+mod spsc_arena;
+mod static_arena;
+#[cfg(feature = "tracing")]
+mod trace;
+mod tree;
+mod world;
+#[cfg(feature = "zeroize")]
+mod zeroize;
@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn entries_fill_sparse_slots() {
+    let mut table: OptionArena<Vec<u32>> = OptionArena::new();
+    let (a, b) = (Idx::from_raw(5), Idx::from_raw(2));
+    table.entry(a).or_default().push(1);
+    table.entry(a).or_default().push(2);
+    let entry = table.entry(b).and_modify(|_| unreachable!());
+    assert!(!entry.is_filled());
+    entry.or_insert(vec![9]);
+
+    assert_eq!(table.len(), 2);
+    assert_eq!(table[a], [1, 2]);
+    assert!(!table.contains(Idx::from_raw(3)));
+    let filled: Vec<_> = table.iter_indexed().map(|(idx, _)| idx).collect();
+    assert_eq!(filled, [b, a]);
+
+    assert_eq!(table.insert(b, vec![]), Some(vec![9]));
+    assert_eq!(table.remove(a), Some(vec![1, 2]));
+    assert_eq!(table.remove(a), None);
+    assert_eq!(table.remove(Idx::from_raw(100)), None);
+    assert_eq!(table.len(), 1);
+}
+
+#[test]
+fn panicking_or_insert_with_leaves_len_unchanged() {
+    let mut table: OptionArena<u32> = OptionArena::new();
+    let idx = Idx::from_raw(1);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        table.entry(idx).or_insert_with(|| panic!("no value"));
+    }));
+    assert!(result.is_err());
+    assert_eq!(table.len(), 0);
+    assert!(table.is_empty());
+}
@@ -0,0 +1,34 @@
+use rayon::prelude::*;
+
+use super::*;
+
+#[test]
+fn par_iter_indexed_writes_through_indices() {
+    let mut arena: Arena<u64> = (0..1000).collect();
+    arena
+        .par_iter_indexed_mut()
+        .for_each(|(idx, v)| *v += idx.into_raw() as u64);
+    let doubled: Vec<_> = arena.par_iter_indexed().map(|(idx, &v)| (idx, v)).collect();
+    assert_eq!(doubled[999], (Idx::from_raw(999), 1998));
+
+    let fast = FastArena::from(arena.into_vec());
+    assert_eq!(fast.par_iter().sum::<u64>(), 999 * 1000);
+    let (idx, max) = fast.par_iter_indexed().max_by_key(|(_, v)| **v).unwrap();
+    assert_eq!((idx, *max), (Idx::from_raw(999), 1998));
+}
+
+#[test]
+fn from_par_iter_keeps_iterator_order() {
+    let (arena, range) =
+        FastArena::from_par_iter((0..10_000_u32).into_par_iter().map(|i| i.to_string()));
+    assert_eq!(range.into_raw(), 0..10_000);
+    assert!(
+        range
+            .iter()
+            .all(|idx| arena[idx] == idx.into_raw().to_string())
+    );
+
+    let (empty, range) = FastArena::<u8>::from_par_iter(Vec::new());
+    assert!(range.is_empty() && empty.is_empty());
+    assert!(empty.try_alloc(1).is_ok());
+}
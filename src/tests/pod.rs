@@ -0,0 +1,91 @@
+use super::*;
+
+#[test]
+fn bytes_round_trip() {
+    let mut arena = Arena::new();
+    let a = arena.alloc(0x0102_0304_u32);
+    arena.alloc(7);
+    assert_eq!(arena.as_bytes().len(), 8);
+    arena.as_bytes_mut()[4..].copy_from_slice(&9_u32.to_ne_bytes());
+
+    // Misaligned input is copied, not rejected.
+    let mut buf = vec![0_u8];
+    buf.extend_from_slice(arena.as_bytes());
+    let copy = Arena::<u32>::from_bytes(&buf[1..]).unwrap();
+    assert_eq!(copy[a], 0x0102_0304);
+    assert_eq!(copy.as_slice(), &[0x0102_0304, 9]);
+
+    let fast = FastArena::<u32>::from_bytes(copy.as_bytes()).unwrap();
+    assert_eq!(fast.as_bytes(), copy.as_bytes());
+}
+
+#[test]
+fn from_bytes_rejects_slop() {
+    assert!(Arena::<u32>::from_bytes(&[1, 2, 3]).is_err());
+    assert!(FastArena::<()>::from_bytes(&[]).is_err());
+}
+
+#[test]
+fn snapshot_round_trip() {
+    let mut arena = Arena::new();
+    let ids: Vec<_> = [3_u64, 1, 4, 1, 5]
+        .into_iter()
+        .map(|v| arena.alloc(v))
+        .collect();
+    let mut buf = Vec::new();
+    arena.write_snapshot(&mut buf).unwrap();
+
+    let restored = Arena::<u64>::read_snapshot(&mut buf.as_slice()).unwrap();
+    for &id in &ids {
+        assert_eq!(restored[id], arena[id]);
+    }
+    assert_eq!(restored.len(), 5);
+
+    let wrong_type = Arena::<u32>::read_snapshot(&mut buf.as_slice());
+    assert_eq!(
+        wrong_type.err().map(|err| err.kind()),
+        Some(std::io::ErrorKind::InvalidData),
+    );
+    let truncated = Arena::<u64>::read_snapshot(&mut &buf[..30]);
+    assert_eq!(
+        truncated.err().map(|err| err.kind()),
+        Some(std::io::ErrorKind::UnexpectedEof),
+    );
+}
+
+#[test]
+fn snapshot_length_is_not_trusted() {
+    let mut header = Vec::new();
+    Arena::<u64>::new().write_snapshot(&mut header).unwrap();
+    header[16..].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    // Values are read as they arrive, so the claimed length allocates
+    // nothing up front.
+    let mut input = header.clone();
+    input.extend_from_slice(&7_u64.to_ne_bytes());
+    let lying = Arena::<u64>::read_snapshot(&mut input.as_slice());
+    assert_eq!(
+        lying.err().map(|err| err.kind()),
+        Some(std::io::ErrorKind::UnexpectedEof),
+    );
+
+    header[12..16].copy_from_slice(&0_u32.to_le_bytes());
+    let units = Arena::<()>::read_snapshot(&mut header.as_slice()).unwrap();
+    assert_eq!(units.len(), usize::MAX);
+}
+
+#[test]
+fn with_len_zeroed_publishes_zeros() {
+    let mut arena = Arena::<[u32; 4]>::with_len_zeroed(1000);
+    assert_eq!(arena.len(), 1000);
+    assert!(arena.iter().all(|v| *v == [0; 4]));
+    let idx = arena.alloc([1; 4]);
+    assert_eq!(idx, Idx::from_raw(1000));
+
+    let mut fast = FastArena::<u64>::with_len_zeroed(3);
+    assert_eq!(fast.as_slice(), &[0, 0, 0]);
+    assert!(fast.is_full());
+    fast.grow();
+    assert_eq!(fast.alloc(7), Idx::from_raw(3));
+    assert_eq!(FastArena::<()>::with_len_zeroed(2).len(), 2);
+}
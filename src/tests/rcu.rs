@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use std::thread;
+
+use crate::RcuArena;
+
+#[test]
+fn reset_deferred_starts_new_generation() {
+    let arena = RcuArena::with_capacity(8);
+    let idx = arena.alloc(1);
+    let old = arena.load();
+
+    arena.reset_deferred();
+    assert_eq!(arena.generation(), 1);
+    assert!(arena.load().is_empty());
+    assert_eq!(arena.load().capacity(), 8);
+
+    // The old generation stays readable through the held handle.
+    assert_eq!(old[idx], 1);
+    assert_eq!(arena.alloc(2), idx);
+    assert_eq!(arena.load()[idx], 2);
+}
+
+#[test]
+fn reset_deferred_reclaims_after_last_reader() {
+    let marker = Arc::new(());
+    let arena = RcuArena::with_capacity(4);
+    arena.alloc(Arc::clone(&marker));
+
+    let reader = arena.load();
+    arena.reset_deferred();
+    assert_eq!(Arc::strong_count(&marker), 2);
+    drop(reader);
+    assert_eq!(Arc::strong_count(&marker), 1);
+}
+
+#[test]
+fn concurrent_readers_survive_reset() {
+    let arena = Arc::new(RcuArena::with_capacity(1024));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let arena = Arc::clone(&arena);
+            thread::spawn(move || {
+                for i in 0..100u32 {
+                    let generation = arena.load();
+                    let idx = generation.alloc(i);
+                    assert_eq!(generation[idx], i);
+                }
+            })
+        })
+        .collect();
+    for _ in 0..10 {
+        arena.reset_deferred();
+    }
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(arena.generation(), 10);
+}
+
+/// Not `Clone`: the kept prefix stays where it is.
+struct Token(Arc<()>);
+
+#[test]
+fn rollback_deferred_waits_for_readers() {
+    let marker = Arc::new(());
+    let arena = RcuArena::with_capacity(8);
+    let a = arena.alloc(Token(Arc::clone(&marker)));
+    let cp = arena.checkpoint();
+    let b = arena.alloc(Token(Arc::clone(&marker)));
+    let old = arena.load();
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(std::time::Duration::from_millis(20));
+            // The reader still sees the tail until it lets go, and can load
+            // and allocate while the rollback waits.
+            assert!(Arc::ptr_eq(&old[b].0, &marker));
+            let again = arena.load();
+            assert_eq!(arena.checkpoint().len(), 2);
+            let late = arena.alloc(Token(Arc::clone(&marker)));
+            assert!(Arc::ptr_eq(&again[late].0, &marker));
+            drop(again);
+            drop(old);
+        });
+        arena.rollback_deferred(cp);
+    });
+    assert_eq!(arena.generation(), 1);
+    assert_eq!(Arc::strong_count(&marker), 2);
+    let current = arena.load();
+    assert_eq!(current.len(), 1);
+    assert!(Arc::ptr_eq(&current[a].0, &marker));
+    drop(current);
+    assert_eq!(arena.alloc(Token(Arc::new(()))), b);
+}
+
+#[test]
+#[should_panic(expected = "checkpoint 1 beyond current length 0")]
+fn rollback_deferred_rejects_future_checkpoint() {
+    let arena = RcuArena::<u32>::with_capacity(8);
+    arena.alloc(1);
+    let cp = arena.checkpoint();
+    arena.reset_deferred();
+    arena.rollback_deferred(cp);
+}
+
+#[test]
+fn rollback_deferred_after_reset_is_a_no_op() {
+    let arena = RcuArena::with_capacity(8);
+    arena.alloc(1);
+    let cp = arena.checkpoint();
+    arena.alloc(2);
+    let old = arena.load();
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(std::time::Duration::from_millis(20));
+            arena.reset_deferred();
+            arena.alloc(3);
+            drop(old);
+        });
+        arena.rollback_deferred(cp);
+    });
+    assert_eq!(arena.generation(), 1);
+    assert_eq!(arena.load().len(), 1);
+}
@@ -0,0 +1,56 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{Arena, FastArena, registry};
+
+#[test]
+fn dump_reports_live_arenas() {
+    let fast = Arc::new(FastArena::<u32>::with_capacity(8));
+    let single = Arc::new(Mutex::new(Arena::<String>::new()));
+    registry::register_named("registry-test-fast", &fast);
+    registry::register_named("registry-test-single", &single);
+    fast.alloc(1);
+    fast.alloc(2);
+    single.lock().unwrap().alloc(String::from("a"));
+
+    let report = registry::dump();
+    let find = |name: &str| {
+        report
+            .iter()
+            .find(|entry| entry.name.as_deref() == Some(name))
+            .cloned()
+    };
+    let fast_entry = find("registry-test-fast").unwrap();
+    assert_eq!(fast_entry.type_name, "u32");
+    assert_eq!(fast_entry.stats.len, 2);
+    assert_eq!(find("registry-test-single").unwrap().stats.len, 1);
+
+    drop(fast);
+    let report = registry::dump();
+    assert!(
+        !report
+            .iter()
+            .any(|entry| entry.name.as_deref() == Some("registry-test-fast"))
+    );
+}
+
+#[test]
+fn dump_queries_arenas_outside_the_registry_lock() {
+    let single = Arc::new(Mutex::new(Arena::<u32>::new()));
+    registry::register_named("registry-test-locked", &single);
+    let held = single.lock().unwrap();
+    std::thread::scope(|s| {
+        let dumper = s.spawn(registry::dump);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        // The dump now waits on `single`; registering must not wait on it.
+        let other = Arc::new(FastArena::<u8>::with_capacity(1));
+        registry::register(&other);
+        drop(held);
+        assert!(
+            dumper
+                .join()
+                .unwrap()
+                .iter()
+                .any(|entry| entry.name.as_deref() == Some("registry-test-locked"))
+        );
+    });
+}
@@ -0,0 +1,53 @@
+use std::sync::{Arc, Mutex};
+
+use super::*;
+
+#[test]
+fn ring_evicts_oldest_through_hook() {
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let mut ring = RingArena::new(3);
+    let log = Arc::clone(&evicted);
+    ring.set_evict_hook(move |idx, value: &mut String| {
+        log.lock().unwrap().push((idx.into_raw(), value.clone()));
+    });
+
+    let ids: Vec<_> = (0..5).map(|i| ring.alloc(i.to_string())).collect();
+    assert_eq!(*evicted.lock().unwrap(), [(0, "0".into()), (1, "1".into())]);
+    assert_eq!(ring.try_get(ids[1]), None);
+    assert_eq!(ring[ids[4]], "4");
+    assert_eq!(ring.first_idx(), Some(ids[2]));
+    assert_eq!(ring.iter().collect::<Vec<_>>(), ["2", "3", "4"]);
+
+    ring.reset();
+    assert!(!ring.is_valid(ids[4]));
+    assert_eq!(ring.alloc("5".into()).into_raw(), 5);
+    assert_eq!(evicted.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn ring_drops_evicted_values_and_honors_byte_limit() {
+    let drops = Rc::new(Cell::new(0));
+    let mut ring = RingArena::with_limit(ArenaLimit::Bytes(2 * size_of::<Tracked>()));
+    assert_eq!(ring.capacity(), 2);
+    for _ in 0..5 {
+        ring.alloc(Tracked(Rc::clone(&drops)));
+    }
+    assert_eq!(drops.get(), 3);
+    assert_eq!(ring.evicted(), 3);
+    drop(ring);
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn ring_index_mut_updates_in_window() {
+    let mut ring = RingArena::new(2);
+    let a = ring.alloc(1);
+    let b = ring.alloc(2);
+    ring[b] += 10;
+    *ring.get_mut(b) += 100;
+    assert_eq!(*ring.get(b), 112);
+    ring.alloc(3);
+    assert_eq!(ring.try_get_mut(a), None);
+    let evicted = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| ring[a] += 1));
+    assert!(evicted.is_err());
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn rows_keep_their_values_contiguous() {
+    let mut rows = RowArena::with_capacity(3, 4);
+    let a = rows.push_row(vec![String::from("a"), String::from("b")]);
+    let empty = rows.push_row(Vec::new());
+    let c = rows.push_row((0..2).map(|i| i.to_string()));
+
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[a], ["a", "b"]);
+    assert!(rows.get_row(empty).is_empty());
+    assert_eq!(rows.row_range(c).into_raw(), 2..4);
+    assert_eq!(rows.try_get_row(RowIdx::from_raw(3)), None);
+
+    rows[c][1].push('!');
+    let all: Vec<_> = rows.rows().map(<[String]>::len).collect();
+    assert_eq!(all, [2, 0, 2]);
+    assert_eq!(rows.values().concat(), "ab01!");
+    assert_eq!(format!("{rows:?}"), r#"[["a", "b"], [], ["0", "1!"]]"#);
+
+    rows.reset();
+    assert!(rows.is_empty());
+    assert!(rows.values().is_empty());
+}
+
+#[test]
+fn rows_store_csr_adjacency() {
+    let edges = [(0, 1), (0, 2), (2, 0), (2, 1)];
+    let mut graph = RowArena::new();
+    let nodes: Vec<_> = (0..3)
+        .map(|n| graph.push_row(edges.iter().filter(|e| e.0 == n).map(|e| e.1)))
+        .collect();
+    assert_eq!(graph[nodes[0]], [1, 2]);
+    assert!(graph[nodes[1]].is_empty());
+    assert_eq!(graph[nodes[2]], [0, 1]);
+}
@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn rollback_to_discards_inner_savepoints() {
+    let mut arena = FastArena::with_capacity(8);
+    let mut saves = Savepoints::new();
+    saves.push("stmt", arena.checkpoint());
+    arena.alloc(1);
+    saves.push("expr", arena.checkpoint());
+    arena.alloc(2);
+    saves.push("expr", arena.checkpoint());
+    arena.alloc(3);
+
+    // The most recent "expr" wins.
+    arena.rollback(saves.rollback_to("expr").unwrap());
+    assert_eq!(arena.as_slice(), &[1, 2]);
+    assert_eq!(saves.len(), 3);
+
+    arena.rollback(saves.rollback_to("stmt").unwrap());
+    assert!(arena.is_empty());
+    assert_eq!(saves.len(), 1);
+    assert_eq!(saves.get("expr"), None);
+    assert_eq!(saves.rollback_to("missing"), None);
+
+    assert_eq!(saves.pop_commit(), Some(("stmt", Checkpoint::from_len(0))));
+    assert_eq!(saves.top(), None);
+}
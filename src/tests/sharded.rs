@@ -0,0 +1,45 @@
+use std::thread;
+
+use super::*;
+
+#[test]
+fn threads_allocate_in_separate_shards() {
+    let arena = ShardedArena::new(3, 64);
+    let mut ids = vec![Vec::new(); 3];
+    thread::scope(|s| {
+        for (t, ids) in ids.iter_mut().enumerate() {
+            let arena = &arena;
+            s.spawn(move || ids.extend((0..10).map(|i| arena.alloc(t * 100 + i))));
+        }
+    });
+    assert_eq!(arena.len(), 30);
+    for (t, ids) in ids.iter().enumerate() {
+        for (i, &idx) in ids.iter().enumerate() {
+            assert_eq!(arena[idx], t * 100 + i);
+        }
+    }
+    let mut indexed: Vec<_> = arena.iter_indexed().map(|(idx, &v)| (idx, v)).collect();
+    indexed.sort_unstable();
+    let mut all: Vec<_> = ids
+        .concat()
+        .into_iter()
+        .map(|idx| (idx, arena[idx]))
+        .collect();
+    all.sort_unstable();
+    assert_eq!(indexed, all);
+}
+
+#[test]
+fn grow_and_reset_cover_every_shard() {
+    let mut arena = ShardedArena::new(2, 1);
+    let a = arena.alloc(1);
+    assert!(arena.try_alloc(2).is_err());
+    arena.grow();
+    let b = arena.alloc(2);
+    assert_eq!(arena.get(b), &2);
+    *arena.get_mut(a) = 10;
+    assert_eq!(arena.iter().count(), 2);
+    arena.reset();
+    assert!(arena.is_empty());
+    assert_eq!(arena.try_get(a), None);
+}
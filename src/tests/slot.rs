@@ -0,0 +1,166 @@
+use std::sync::Arc;
+use std::thread;
+
+use crate::{Arena, FastArena, Idx};
+
+#[test]
+fn slot_writer_publishes_on_finish() {
+    let arena = FastArena::with_capacity(8);
+    let mut writer = arena.reserve_slots(3);
+    assert_eq!(writer.remaining(), 3);
+
+    let a = writer.write(10);
+    let b = writer.write(20);
+    let c = writer.write(30);
+    assert_eq!(writer.remaining(), 0);
+    assert!(arena.is_empty());
+
+    let range = writer.finish();
+    assert_eq!(range.len(), 3);
+    assert_eq!((arena[a], arena[b], arena[c]), (10, 20, 30));
+}
+
+#[test]
+fn slot_writer_publishes_on_drop() {
+    let arena = FastArena::with_capacity(8);
+    {
+        let mut writer = arena.reserve_slots(2);
+        writer.write(1);
+        writer.write(2);
+    }
+    assert_eq!(arena.as_slice(), &[1, 2]);
+}
+
+#[test]
+fn slot_writer_returns_unwritten_tail() {
+    let arena = FastArena::with_capacity(8);
+    let mut writer = arena.reserve_slots(4);
+    writer.write(1);
+    let range = writer.finish();
+
+    assert_eq!(range.len(), 1);
+    assert_eq!(arena.reserved_len(), 1);
+    assert_eq!(arena.alloc(2), Idx::from_raw(1));
+}
+
+#[test]
+fn slot_writer_poisons_when_gap_cannot_be_returned() {
+    let arena = FastArena::with_capacity(8);
+    let mut writer = arena.reserve_slots(2);
+    writer.write(1);
+    let mut later = arena.reserve_slots(1);
+    drop(writer);
+    assert_eq!(arena.poisoned_at(), Some(Idx::from_raw(1)));
+    assert_eq!(arena.len(), 1);
+
+    later.write(3);
+    let _ = later.finish();
+    assert_eq!(arena.len(), 1);
+    assert_eq!(arena[Idx::from_raw(0)], 1);
+}
+
+#[test]
+#[should_panic(expected = "slot writer full")]
+fn slot_writer_rejects_extra_writes() {
+    let arena = FastArena::with_capacity(8);
+    let mut writer = arena.reserve_slots(1);
+    writer.write(1);
+    writer.write(2);
+}
+
+#[test]
+fn concurrent_slot_writers() {
+    let arena = Arc::new(FastArena::with_capacity(4 * 256));
+
+    let handles: Vec<_> = (0..4u32)
+        .map(|t| {
+            let arena = Arc::clone(&arena);
+            thread::spawn(move || {
+                for chunk in 0..4 {
+                    let mut writer = arena.reserve_slots(64);
+                    for i in 0..64 {
+                        writer.write(t * 1000 + chunk * 64 + i);
+                    }
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    assert_eq!(arena.len(), 4 * 256);
+    for block in arena.as_slice().chunks(64) {
+        assert!(block.windows(2).all(|w| w[1] == w[0] + 1));
+    }
+}
+
+#[test]
+fn uninit_slot_commits_on_assume_init() {
+    let mut arena = Arena::new();
+    arena.alloc(1u64);
+
+    let mut slot = arena.alloc_uninit();
+    assert_eq!(slot.idx(), Idx::from_raw(1));
+    slot.as_uninit().write(42);
+    let idx = unsafe { slot.assume_init() };
+
+    assert_eq!(arena[idx], 42);
+    assert_eq!(arena.len(), 2);
+}
+
+#[test]
+fn uninit_slot_dropped_allocates_nothing() {
+    let mut arena: Arena<String> = Arena::new();
+    let _ = arena.alloc_uninit();
+    assert!(arena.is_empty());
+
+    let idx = arena.alloc_uninit().write(String::from("x"));
+    assert_eq!(arena[idx], "x");
+}
+
+#[test]
+fn slot_guard_publishes() {
+    let arena = FastArena::with_capacity(4);
+    let mut guard = arena.alloc_uninit();
+    let reserved = guard.idx();
+    guard.as_uninit().write(7);
+    assert_eq!(arena.resolve(reserved), None);
+
+    let idx = unsafe { guard.publish() };
+    assert_eq!(arena.resolve(reserved), Some(idx));
+    assert_eq!(arena[idx], 7);
+}
+
+#[test]
+fn slot_guard_dropped_returns_slot() {
+    let arena = FastArena::<u8>::with_capacity(4);
+    drop(arena.alloc_uninit());
+    assert_eq!(arena.reserved_len(), 0);
+    assert_eq!(arena.alloc_uninit().write(1), Idx::from_raw(0));
+}
+
+#[test]
+fn slot_guard_gap_poisons() {
+    let arena = FastArena::<u8>::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let later = arena.alloc_uninit();
+    drop(guard);
+    assert!(arena.is_poisoned());
+    assert_eq!(arena.poisoned_at(), Some(Idx::from_raw(0)));
+
+    let idx = later.write(7);
+    assert_eq!(arena.len(), 0);
+    assert!(!arena.is_valid(idx));
+}
+
+#[test]
+#[should_panic(expected = "arena poisoned at slot 0: index 1 is unreachable")]
+fn poisoned_arena_get_names_the_gap() {
+    let arena = FastArena::<u8>::with_capacity(4);
+    let guard = arena.alloc_uninit();
+    let later = arena.alloc_uninit();
+    drop(guard);
+    let idx = later.write(1);
+    let _ = arena.get(idx);
+}
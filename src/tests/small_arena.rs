@@ -0,0 +1,47 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use super::*;
+
+struct Counted(Rc<Cell<u32>>);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn small_arena_spills_past_inline_capacity() {
+    let mut arena = SmallArena::<String, 2>::new();
+    let a = arena.alloc(String::from("a"));
+    arena.alloc(String::from("b"));
+    assert!(!arena.spilled());
+
+    let c = arena.alloc(String::from("c"));
+    assert!(arena.spilled());
+    assert_eq!((arena[a].as_str(), arena[c].as_str()), ("a", "c"));
+    arena[a].push('!');
+    assert_eq!(arena.as_slice(), ["a!", "b", "c"]);
+    assert_eq!(arena.try_get(Idx::from_raw(3)), None);
+
+    let cp = arena.checkpoint();
+    arena.extend([String::from("d")]);
+    arena.rollback(cp);
+    assert_eq!(arena.len(), 3);
+    assert_eq!(arena.into_arena().as_slice(), ["a!", "b", "c"]);
+}
+
+#[test]
+fn small_arena_drops_inline_values() {
+    let drops = Rc::new(Cell::new(0));
+    let mut arena = SmallArena::<Counted, 4>::new();
+    let cp = arena.checkpoint();
+    arena.extend((0..3).map(|_| Counted(Rc::clone(&drops))));
+    arena.rollback(cp);
+    assert_eq!(drops.get(), 3);
+    arena.extend((0..2).map(|_| Counted(Rc::clone(&drops))));
+    assert!(arena.try_rollback(Checkpoint::from_len(5)).is_err());
+    drop(arena);
+    assert_eq!(drops.get(), 5);
+}
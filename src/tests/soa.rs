@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use crate::{Soa, SoaArena};
+
+#[derive(Soa)]
+struct Particle {
+    x: i32,
+    v: i32,
+}
+
+#[derive(Soa)]
+struct Named<T> {
+    name: String,
+    payload: T,
+}
+
+#[test]
+fn columns_hold_fields_in_allocation_order() {
+    let mut arena = SoaArena::<Particle>::with_capacity(4);
+    let a = arena.alloc(Particle { x: 10, v: 5 });
+    let b = arena.alloc(Particle { x: 20, v: -5 });
+
+    assert_eq!(arena.len(), 2);
+    assert_eq!(arena.x(), &[10, 20]);
+    assert_eq!(arena.v()[b.into_raw()], -5);
+    for (x, v) in arena.columns_mut().x_mut().iter_mut().zip([5, -5]) {
+        *x += v;
+    }
+    assert_eq!(arena.x()[a.into_raw()], 15);
+}
+
+#[test]
+fn rollback_drops_fields_of_every_column() {
+    let tracker = Rc::new(());
+    let mut arena = SoaArena::<Named<Rc<()>>>::new();
+    arena.alloc(Named {
+        name: "kept".into(),
+        payload: Rc::clone(&tracker),
+    });
+    let cp = arena.checkpoint();
+    arena.extend((0..3).map(|i| Named {
+        name: i.to_string(),
+        payload: Rc::clone(&tracker),
+    }));
+    assert_eq!(Rc::strong_count(&tracker), 5);
+
+    arena.rollback(cp);
+    assert_eq!(Rc::strong_count(&tracker), 2);
+    assert_eq!(arena.name(), &["kept"]);
+    arena.reset();
+    assert!(arena.is_empty() && arena.payload().is_empty());
+}
@@ -0,0 +1,36 @@
+use std::thread;
+
+use super::*;
+
+#[test]
+fn writers_and_readers_share_the_arena() {
+    let (writer, reader) = FastArena::with_capacity(64).split();
+    thread::scope(|s| {
+        for t in 0..4 {
+            let writer = writer.clone();
+            s.spawn(move || {
+                for i in 0..8 {
+                    writer.alloc(t * 8 + i);
+                }
+            });
+        }
+    });
+    let second = writer.reader();
+    assert_eq!(reader.len(), 32);
+    assert_eq!(second.as_slice().iter().sum::<i32>(), (0..32).sum());
+
+    let Err((writer, reader)) = FastArena::join(writer, reader) else {
+        panic!("a second reader is still alive");
+    };
+    drop(second);
+    let mut arena = FastArena::join(writer, reader).unwrap();
+    arena.reset();
+    assert!(arena.is_empty());
+}
+
+#[test]
+fn join_rejects_handles_of_different_arenas() {
+    let (writer, _) = FastArena::<u8>::with_capacity(1).split();
+    let (_, reader) = FastArena::<u8>::with_capacity(1).split();
+    assert!(FastArena::join(writer, reader).is_err());
+}
@@ -0,0 +1,101 @@
+use std::thread;
+
+use crate::{Checkpoint, Idx, SpscArena};
+
+use super::Tracked;
+
+#[test]
+fn alloc_and_get() {
+    let arena = SpscArena::with_capacity(4);
+    let mut writer = arena.writer().unwrap();
+    let a = writer.alloc(10);
+    let b = writer.alloc(20);
+    drop(writer);
+
+    assert_eq!(arena[a], 10);
+    assert_eq!(arena[b], 20);
+    assert_eq!(arena.as_slice(), &[10, 20]);
+}
+
+#[test]
+fn single_writer_at_a_time() {
+    let arena = SpscArena::<i32>::with_capacity(4);
+    let writer = arena.writer();
+    assert!(writer.is_some());
+    assert!(arena.writer().is_none());
+
+    drop(writer);
+    assert!(arena.writer().is_some());
+}
+
+#[test]
+fn writer_resumes_after_previous_one() {
+    let arena = SpscArena::with_capacity(4);
+    arena.writer().unwrap().alloc(1);
+    let idx = arena.writer().unwrap().alloc(2);
+    assert_eq!(idx, Idx::from_raw(1));
+    assert_eq!(arena.as_slice(), &[1, 2]);
+}
+
+#[test]
+fn concurrent_reader_sees_prefix() {
+    let arena = SpscArena::with_capacity(10_000);
+    thread::scope(|s| {
+        let mut writer = arena.writer().unwrap();
+        s.spawn(move || {
+            for i in 0..10_000 {
+                writer.alloc(i);
+            }
+        });
+        s.spawn(|| {
+            while arena.len() < 10_000 {
+                let slice = arena.as_slice();
+                assert!(slice.iter().enumerate().all(|(i, &v)| v == i));
+            }
+        });
+    });
+}
+
+#[test]
+fn rollback_reset_and_drop_run_destructors() {
+    let drop_count = std::rc::Rc::new(std::cell::Cell::new(0u32));
+    let mut arena = SpscArena::with_capacity(4);
+    let mut writer = arena.writer().unwrap();
+    for _ in 0..4 {
+        writer.alloc(Tracked(std::rc::Rc::clone(&drop_count)));
+    }
+    drop(writer);
+
+    arena.rollback(Checkpoint::from_len(3));
+    assert_eq!(drop_count.get(), 1);
+    arena.reset();
+    assert_eq!(drop_count.get(), 4);
+
+    arena
+        .writer()
+        .unwrap()
+        .alloc(Tracked(std::rc::Rc::clone(&drop_count)));
+    drop(arena);
+    assert_eq!(drop_count.get(), 5);
+}
+
+#[test]
+fn grow_to_keeps_items() {
+    let mut arena = SpscArena::with_capacity(1);
+    arena.writer().unwrap().alloc(String::from("a"));
+    arena.grow_to(8);
+    let b = arena.writer().unwrap().alloc(String::from("b"));
+
+    assert_eq!(arena.capacity(), 8);
+    assert_eq!(arena[b], "b");
+    assert_eq!(arena.as_slice(), &["a", "b"]);
+}
+
+#[test]
+#[should_panic(expected = "arena full")]
+fn panics_when_full() {
+    let arena = SpscArena::with_capacity(1);
+    let mut writer = arena.writer().unwrap();
+    writer.alloc(1);
+    writer.alloc(2);
+}
@@ -0,0 +1,40 @@
+use std::sync::LazyLock;
+use std::thread;
+
+use super::*;
+
+static NAMES: StaticArena<String> = StaticArena::new();
+
+#[test]
+fn static_arena_allocates_from_threads() {
+    thread::scope(|s| {
+        for i in 0..4 {
+            s.spawn(move || {
+                let id = NAMES.alloc(format!("t{i}"));
+                assert_eq!(NAMES.get_cloned(id), format!("t{i}"));
+            });
+        }
+    });
+    assert_eq!(NAMES.len(), 4);
+}
+
+#[test]
+fn static_arena_lazy_with_capacity() {
+    static PRIMES: LazyLock<StaticArena<u32>> = LazyLock::new(|| {
+        let arena = StaticArena::with_capacity(8);
+        arena.write().extend([2, 3, 5]);
+        arena
+    });
+    assert!(PRIMES.read().capacity() >= 8);
+    let seven = PRIMES.alloc(7);
+    assert_eq!(PRIMES.read()[seven], 7);
+    assert_eq!(PRIMES.len(), 4);
+}
+
+#[test]
+fn static_arena_into_inner() {
+    let arena = StaticArena::from(Arena::from_iter([1, 2]));
+    arena.alloc(3);
+    assert!(!arena.is_empty());
+    assert_eq!(arena.into_inner().as_slice(), [1, 2, 3]);
+}
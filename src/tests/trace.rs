@@ -0,0 +1,72 @@
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+use crate::FastArena;
+
+/// Subscriber that records the message of every event.
+#[derive(Clone, Default)]
+struct Messages(Arc<Mutex<Vec<String>>>);
+
+struct MessageVisitor<'a>(&'a mut String);
+
+impl Visit for MessageVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            *self.0 = format!("{value:?}");
+        }
+    }
+}
+
+impl Subscriber for Messages {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut message = String::new();
+        event.record(&mut MessageVisitor(&mut message));
+        self.0.lock().unwrap().push(message);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn lifecycle_events_are_traced() {
+    let messages = Messages::default();
+    tracing::subscriber::with_default(messages.clone(), || {
+        let mut arena = FastArena::with_capacity(1);
+        arena.alloc(1u8);
+        arena.grow();
+        let cp = arena.checkpoint();
+        arena.alloc(2);
+        arena.rollback(cp);
+        arena.reset();
+        let full = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            arena.alloc_extend([1, 2, 3]);
+        }));
+        assert!(full.is_err());
+    });
+    assert_eq!(
+        *messages.0.lock().unwrap(),
+        [
+            "arena grew",
+            "arena rolled back",
+            "arena reset",
+            "arena full"
+        ],
+    );
+}
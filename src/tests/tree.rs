@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn traversals_stay_within_the_subtree() {
+    //        r
+    //      / | \
+    //     a  b  c
+    //    / \     \
+    //   d   e     f
+    let mut tree = TreeArena::with_capacity(8);
+    let root = tree.add_root('r');
+    let [a, b, c] = ['a', 'b', 'c'].map(|v| tree.add_child(root, v));
+    let [_, e_node] = ['d', 'e'].map(|v| tree.add_child(a, v));
+    let leaf = tree.add_child(c, 'f');
+    let other = tree.add_root('x');
+    tree.add_child(other, 'y');
+
+    let chars = |it: &mut dyn Iterator<Item = Idx<char>>| it.map(|n| tree[n]).collect::<String>();
+    assert_eq!(chars(&mut tree.preorder(root)), "radebcf");
+    assert_eq!(chars(&mut tree.postorder(root)), "deabfcr");
+    assert_eq!(chars(&mut tree.preorder(a)), "ade");
+    assert_eq!(chars(&mut tree.postorder(a)), "dea");
+    assert_eq!(chars(&mut tree.preorder(b)), "b");
+    assert_eq!(chars(&mut tree.children(root)), "abc");
+    assert_eq!(chars(&mut tree.ancestors(e_node)), "ar");
+    assert_eq!(chars(&mut tree.preorder(other)), "xy");
+
+    assert_eq!(tree.parent(leaf), Some(c));
+    assert_eq!(tree.parent(root), None);
+    assert_eq!(tree.children(e_node).count(), 0);
+    assert_eq!(tree.len(), 9);
+
+    tree[leaf] = 'F';
+    assert_eq!(tree.values().as_slice()[leaf.into_raw()], 'F');
+    tree.reset();
+    assert!(tree.is_empty());
+}
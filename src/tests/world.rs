@@ -0,0 +1,51 @@
+crate::define_world! {
+    struct Ast {
+        nodes: Node as NodeId,
+        idents: &'static str as IdentId,
+    }
+    struct AstCheckpoint;
+}
+
+enum Node {
+    Ident(IdentId),
+    Call(NodeId, Vec<NodeId>),
+}
+
+#[test]
+fn world_checkpoints_every_arena() {
+    let mut ast = Ast::default();
+    let f = ast.alloc("f");
+    let callee = ast.alloc(Node::Ident(f));
+    let cp = ast.checkpoint_all();
+
+    let x = ast.alloc("x");
+    let arg = ast.alloc(Node::Ident(x));
+    let call = ast.alloc(Node::Call(callee, vec![arg]));
+    assert!(matches!(&ast[call], Node::Call(c, args) if *c == callee && args.len() == 1));
+    assert_eq!(format!("{ast:?}"), "Ast { nodes: 3, idents: 2 }");
+
+    ast.rollback_all(cp);
+    assert_eq!((ast.nodes.len(), ast.idents.len()), (1, 1));
+    assert!(matches!(ast[callee], Node::Ident(id) if ast[id] == "f"));
+    assert_eq!(crate::Idx::from(callee), callee.idx());
+
+    ast.reset_all();
+    assert!(ast.nodes.is_empty() && ast.idents.is_empty());
+}
+
+#[test]
+fn rollback_all_checks_every_arena_before_dropping_anything() {
+    let mut ast = Ast::new();
+    let f = ast.alloc("f");
+    ast.alloc(Node::Ident(f));
+    let cp = ast.checkpoint_all();
+    ast.alloc(Node::Call(
+        NodeId::from(crate::Idx::from_raw(0)),
+        Vec::new(),
+    ));
+    ast.idents.reset();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| ast.rollback_all(cp)));
+    assert!(result.is_err());
+    assert_eq!(ast.nodes.len(), 2);
+}
@@ -0,0 +1,97 @@
+use crate::{Arena, FastArena};
+
+/// Reads the first `cap` slots behind `ptr` as raw bytes, including spare
+/// capacity.
+fn slot_bytes<T>(ptr: *const T, cap: usize) -> Vec<u8> {
+    // SAFETY: the caller's arena owns `cap` slots behind `ptr`, and every
+    // slot read was either written or zeroed, so its bytes are initialized.
+    unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), cap * size_of::<T>()) }.to_vec()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // reads through a deliberately stale pointer
+fn arena_rollback_zeroes_freed_slots() {
+    let mut arena = Arena::with_capacity_zeroizing(4);
+    arena.alloc(u64::MAX);
+    let cp = arena.checkpoint();
+    arena.alloc(u64::MAX);
+    arena.alloc(u64::MAX);
+    arena.rollback(cp);
+
+    let bytes = slot_bytes(arena.as_slice().as_ptr(), 3);
+    assert!(bytes[..8].iter().all(|&b| b == 0xFF));
+    assert!(bytes[8..].iter().all(|&b| b == 0));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // reads through a deliberately stale pointer
+fn arena_drain_zeroes_vacated_slots() {
+    let mut arena = Arena::with_capacity_zeroizing(4);
+    arena.extend([u64::MAX; 3]);
+    let ptr = arena.as_slice().as_ptr();
+    let mut drain = arena.drain();
+    assert_eq!(drain.next(), Some(u64::MAX));
+    drop(drain);
+
+    assert!(arena.is_empty());
+    assert!(slot_bytes(ptr, 4).iter().all(|&b| b == 0));
+}
+
+#[test]
+fn arena_zeroizing_hands_out_fresh_storage() {
+    let mut arena = Arena::with_capacity_zeroizing(8);
+    arena.extend(["a", "b"].map(String::from));
+    let ptr = arena.as_slice().as_ptr();
+    let items = arena.take_items();
+    assert_ne!(items.as_ptr(), ptr);
+    assert_eq!(items, ["a", "b"]);
+
+    arena.extend(["c", "d"].map(String::from));
+    let mut iter = arena.into_iter();
+    assert_eq!(iter.next().as_deref(), Some("c"));
+    drop(iter);
+}
+
+#[test]
+fn zeroizing_arena_items_may_borrow_shorter_lived_locals() {
+    let mut arena = Arena::with_capacity_zeroizing(1);
+    let s = String::from("x");
+    let idx = arena.alloc(&s);
+    assert_eq!(arena[idx], "x");
+}
+
+#[test]
+fn arena_zeroizing_keeps_values_across_growth() {
+    let mut arena = Arena::with_capacity_zeroizing(1);
+    let ids: Vec<_> = (0..100u64).map(|i| arena.alloc(i.to_string())).collect();
+    arena.extend((100..110u64).map(|i| i.to_string()));
+    arena.shrink_to_fit();
+
+    assert_eq!(arena.len(), 110);
+    assert_eq!(arena[ids[42]], "42");
+    arena.reset();
+    assert!(arena.is_empty());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // reads through a deliberately stale pointer
+fn fast_arena_reset_zeroes_freed_slots() {
+    let mut arena = FastArena::with_capacity_zeroizing(4);
+    arena.alloc(u64::MAX);
+    arena.alloc(u64::MAX);
+    let ptr = arena.as_slice().as_ptr();
+    arena.reset();
+
+    assert!(slot_bytes(ptr, 2).iter().all(|&b| b == 0));
+}
+
+#[test]
+fn fast_arena_zeroizing_keeps_values_across_grow() {
+    let mut arena = FastArena::with_capacity_zeroizing(2);
+    let a = arena.alloc(String::from("kept"));
+    arena.grow_to(8);
+    let b = arena.alloc(String::from("new"));
+
+    assert_eq!(arena[a], "kept");
+    assert_eq!(arena[b], "new");
+}
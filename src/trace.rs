@@ -0,0 +1,67 @@
+//! Lifecycle events emitted through `tracing` when the `tracing` feature is
+//! enabled. Without the feature every function compiles to nothing.
+//!
+//! Events carry the arena's address (`arena`) and element type (`ty`) to
+//! tell arenas apart.
+#![cfg_attr(
+    not(feature = "tracing"),
+    allow(clippy::extra_unused_type_parameters, clippy::missing_const_for_fn)
+)]
+
+/// Storage grew from `old_capacity` to `new_capacity` values.
+pub fn grow<T>(arena: usize, old_capacity: usize, new_capacity: usize) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        arena,
+        ty = std::any::type_name::<T>(),
+        old_capacity,
+        new_capacity,
+        "arena grew",
+    );
+    #[cfg(not(feature = "tracing"))]
+    let _ = (arena, old_capacity, new_capacity);
+}
+
+/// A reset removed `len` values.
+pub fn reset<T>(arena: usize, len: usize, capacity: usize) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        arena,
+        ty = std::any::type_name::<T>(),
+        len,
+        capacity,
+        "arena reset",
+    );
+    #[cfg(not(feature = "tracing"))]
+    let _ = (arena, len, capacity);
+}
+
+/// A rollback shrank the arena from `from_len` to `to_len`.
+pub fn rollback<T>(arena: usize, from_len: usize, to_len: usize) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        arena,
+        ty = std::any::type_name::<T>(),
+        from_len,
+        to_len,
+        "arena rolled back",
+    );
+    #[cfg(not(feature = "tracing"))]
+    let _ = (arena, from_len, to_len);
+}
+
+/// An allocation of `n` values at `start` is about to panic because it
+/// exceeds `capacity`.
+pub fn full<T>(arena: usize, start: usize, n: usize, capacity: usize) {
+    #[cfg(feature = "tracing")]
+    tracing::error!(
+        arena,
+        ty = std::any::type_name::<T>(),
+        start,
+        requested = n,
+        capacity,
+        "arena full",
+    );
+    #[cfg(not(feature = "tracing"))]
+    let _ = (arena, start, n, capacity);
+}
@@ -0,0 +1,310 @@
+use crate::{Arena, Idx};
+
+/// End of a parent, child or sibling link.
+const NONE: usize = usize::MAX;
+
+/// Structure of one node, stored alongside its value.
+#[derive(Clone, Copy)]
+struct Links {
+    parent: usize,
+    first_child: usize,
+    last_child: usize,
+    next_sibling: usize,
+}
+
+/// Arena of trees: every value knows its parent, children and siblings.
+///
+/// Values live in an [`Arena<T>`]; a parallel table records the links, so a
+/// node's structure costs four words and no allocation. Children keep
+/// insertion order. Preorder and postorder traversals follow the links
+/// without an explicit stack.
+///
+/// ```
+/// use fast_bump::TreeArena;
+///
+/// let mut ast = TreeArena::new();
+/// let add = ast.add_root("+");
+/// let one = ast.add_child(add, "1");
+/// let mul = ast.add_child(add, "*");
+/// let two = ast.add_child(mul, "2");
+/// ast.add_child(mul, "3");
+///
+/// assert_eq!(ast.children(add).collect::<Vec<_>>(), [one, mul]);
+/// assert_eq!(ast.ancestors(two).collect::<Vec<_>>(), [mul, add]);
+/// let pre: Vec<_> = ast.preorder(add).map(|n| ast[n]).collect();
+/// assert_eq!(pre, ["+", "1", "*", "2", "3"]);
+/// let post: Vec<_> = ast.postorder(add).map(|n| ast[n]).collect();
+/// assert_eq!(post, ["1", "2", "3", "*", "+"]);
+/// ```
+pub struct TreeArena<T> {
+    values: Arena<T>,
+    links: Vec<Links>,
+}
+
+impl<T> TreeArena<T> {
+    /// Creates an empty arena.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            values: Arena::new(),
+            links: Vec::new(),
+        }
+    }
+
+    /// Creates an empty arena with room for `capacity` nodes.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Arena::with_capacity(capacity),
+            links: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a node without a parent, returning its index.
+    pub fn add_root(&mut self, value: T) -> Idx<T> {
+        self.push(NONE, value)
+    }
+
+    /// Adds `value` as the last child of `parent`, returning its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is out of bounds.
+    pub fn add_child(&mut self, parent: Idx<T>, value: T) -> Idx<T> {
+        let p = parent.into_raw();
+        let child = self.links.len();
+        let last = self.links[p].last_child;
+        if last == NONE {
+            self.links[p].first_child = child;
+        } else {
+            self.links[last].next_sibling = child;
+        }
+        self.links[p].last_child = child;
+        self.push(p, value)
+    }
+
+    fn push(&mut self, parent: usize, value: T) -> Idx<T> {
+        self.links.push(Links {
+            parent,
+            first_child: NONE,
+            last_child: NONE,
+            next_sibling: NONE,
+        });
+        self.values.alloc(value)
+    }
+
+    /// Returns the value of `node`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    #[must_use]
+    pub fn get(&self, node: Idx<T>) -> &T {
+        self.values.get(node)
+    }
+
+    /// Returns the value of `node`, mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, node: Idx<T>) -> &mut T {
+        self.values.get_mut(node)
+    }
+
+    /// Returns the parent of `node`, or `None` for a root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    #[must_use]
+    pub fn parent(&self, node: Idx<T>) -> Option<Idx<T>> {
+        Self::link(self.links[node.into_raw()].parent)
+    }
+
+    /// Returns an iterator over the children of `node`, in insertion order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    pub fn children(&self, node: Idx<T>) -> impl Iterator<Item = Idx<T>> + '_ {
+        let first = self.links[node.into_raw()].first_child;
+        std::iter::successors(Self::link(first), |&child| {
+            Self::link(self.links[child.into_raw()].next_sibling)
+        })
+    }
+
+    /// Returns an iterator over the ancestors of `node`, nearest first,
+    /// excluding `node` itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    pub fn ancestors(&self, node: Idx<T>) -> impl Iterator<Item = Idx<T>> + '_ {
+        std::iter::successors(self.parent(node), |&p| self.parent(p))
+    }
+
+    /// Returns an iterator over the subtree rooted at `root`, each node
+    /// before its children.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `root` is out of bounds.
+    #[must_use]
+    pub fn preorder(&self, root: Idx<T>) -> Preorder<'_, T> {
+        assert!(
+            root.into_raw() < self.len(),
+            "root {} out of bounds for {} nodes",
+            root.into_raw(),
+            self.len(),
+        );
+        Preorder {
+            tree: self,
+            root: root.into_raw(),
+            next: root.into_raw(),
+        }
+    }
+
+    /// Returns an iterator over the subtree rooted at `root`, each node
+    /// after its children.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `root` is out of bounds.
+    #[must_use]
+    pub fn postorder(&self, root: Idx<T>) -> Postorder<'_, T> {
+        Postorder {
+            tree: self,
+            root: root.into_raw(),
+            next: self.leftmost_leaf(root.into_raw()),
+        }
+    }
+
+    /// Returns the number of nodes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no nodes.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the arena holding the values.
+    #[must_use]
+    pub const fn values(&self) -> &Arena<T> {
+        &self.values
+    }
+
+    /// Removes every node, keeping the storage.
+    pub fn reset(&mut self) {
+        self.values.reset();
+        self.links.clear();
+    }
+
+    fn leftmost_leaf(&self, mut node: usize) -> usize {
+        loop {
+            let first = self.links[node].first_child;
+            if first == NONE {
+                return node;
+            }
+            node = first;
+        }
+    }
+
+    const fn link(raw: usize) -> Option<Idx<T>> {
+        if raw == NONE {
+            None
+        } else {
+            Some(Idx::from_raw(raw))
+        }
+    }
+}
+
+impl<T> Default for TreeArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::ops::Index<Idx<T>> for TreeArena<T> {
+    type Output = T;
+
+    fn index(&self, node: Idx<T>) -> &T {
+        self.get(node)
+    }
+}
+
+impl<T> std::ops::IndexMut<Idx<T>> for TreeArena<T> {
+    fn index_mut(&mut self, node: Idx<T>) -> &mut T {
+        self.get_mut(node)
+    }
+}
+
+impl<T> std::fmt::Debug for TreeArena<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TreeArena")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Preorder traversal of a subtree of a [`TreeArena`].
+///
+/// Created by [`TreeArena::preorder`].
+pub struct Preorder<'a, T> {
+    tree: &'a TreeArena<T>,
+    root: usize,
+    next: usize,
+}
+
+impl<T> Iterator for Preorder<'_, T> {
+    type Item = Idx<T>;
+
+    fn next(&mut self) -> Option<Idx<T>> {
+        let node = TreeArena::<T>::link(self.next)?;
+        let links = &self.tree.links;
+        let mut at = node.into_raw();
+        self.next = links[at].first_child;
+        // Climb until a sibling is found, without leaving the subtree.
+        while self.next == NONE && at != self.root {
+            self.next = links[at].next_sibling;
+            at = links[at].parent;
+        }
+        Some(node)
+    }
+}
+
+impl<T> std::iter::FusedIterator for Preorder<'_, T> {}
+
+/// Postorder traversal of a subtree of a [`TreeArena`].
+///
+/// Created by [`TreeArena::postorder`].
+pub struct Postorder<'a, T> {
+    tree: &'a TreeArena<T>,
+    root: usize,
+    next: usize,
+}
+
+impl<T> Iterator for Postorder<'_, T> {
+    type Item = Idx<T>;
+
+    fn next(&mut self) -> Option<Idx<T>> {
+        let node = TreeArena::<T>::link(self.next)?;
+        let at = node.into_raw();
+        let links = &self.tree.links[at];
+        self.next = if at == self.root {
+            NONE
+        } else if links.next_sibling == NONE {
+            links.parent
+        } else {
+            self.tree.leftmost_leaf(links.next_sibling)
+        };
+        Some(node)
+    }
+}
+
+impl<T> std::iter::FusedIterator for Postorder<'_, T> {}
@@ -0,0 +1,123 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, RandomState};
+
+use crate::{Arena, Idx};
+
+/// End of a hash chain in [`ValueIndex::next`].
+const NONE: usize = usize::MAX;
+
+/// Hash index over the values of an [`Arena<T>`], kept by the caller
+/// between calls to [`Arena::extend_unique_with`].
+///
+/// The index stores only hashes and positions, so `T` need not be `Clone`.
+/// It covers a prefix of the arena and catches up on values appended since
+/// the last call. If the indexed values may have changed since — the arena
+/// was rolled back, reset, sorted, retained, drained or borrowed mutably —
+/// it is rebuilt. An index belongs to one arena; use a fresh one for
+/// another.
+pub struct ValueIndex<T, S = RandomState> {
+    /// Most recently indexed position for each hash.
+    heads: HashMap<u64, usize>,
+    /// Per position, the previous position with the same hash, or `NONE`.
+    next: Vec<usize>,
+    /// The arena's edit stamp when the index was last synced.
+    edits: usize,
+    hasher: S,
+    _marker: std::marker::PhantomData<fn(&T)>,
+}
+
+impl<T> ValueIndex<T> {
+    /// Creates an empty index.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<T, S> ValueIndex<T, S> {
+    /// Creates an empty index hashing values with `hasher`.
+    #[must_use]
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            heads: HashMap::new(),
+            next: Vec::new(),
+            edits: 0,
+            hasher,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of arena values covered by the index.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.next.len()
+    }
+
+    /// Returns `true` if the index covers no values.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.next.is_empty()
+    }
+
+    /// Forgets every value, keeping the storage.
+    pub fn clear(&mut self) {
+        self.heads.clear();
+        self.next.clear();
+    }
+}
+
+impl<T: Hash, S: BuildHasher> ValueIndex<T, S> {
+    pub(crate) fn hash<Q: Hash + ?Sized>(&self, value: &Q) -> u64 {
+        self.hasher.hash_one(value)
+    }
+
+    /// Records that position `len()` holds a value hashing to `hash`.
+    pub(crate) fn push(&mut self, hash: u64) {
+        let prev = self.heads.insert(hash, self.next.len());
+        self.next.push(prev.unwrap_or(NONE));
+    }
+
+    /// Returns the position in `items` of the value equal to `value`, among
+    /// the indexed ones.
+    pub(crate) fn find<Q>(&self, items: &[T], hash: u64, value: &Q) -> Option<Idx<T>>
+    where
+        T: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let mut at = *self.heads.get(&hash)?;
+        while at != NONE {
+            if items[at].borrow() == value {
+                return Some(Idx::from_raw(at));
+            }
+            at = self.next[at];
+        }
+        None
+    }
+
+    /// Brings the index up to date with `arena`.
+    pub(crate) fn sync(&mut self, arena: &Arena<T>) {
+        if self.edits != arena.edits() || self.len() > arena.len() {
+            self.clear();
+            self.edits = arena.edits();
+        }
+        for value in &arena.as_slice()[self.len()..] {
+            let hash = self.hash(value);
+            self.push(hash);
+        }
+    }
+}
+
+impl<T> Default for ValueIndex<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S> std::fmt::Debug for ValueIndex<T, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValueIndex")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Idx, IterIndexed};
+
+/// Read snapshot of a [`FastArena`](crate::FastArena) at a fixed length.
+///
+/// Created by [`FastArena::view`](crate::FastArena::view). Captures
+/// `published` once, so every access during a pass sees the same prefix
+/// even while writers keep allocating. Indices allocated after the snapshot
+/// are out of bounds for the view.
+pub struct ArenaView<'a, T> {
+    items: &'a [T],
+}
+
+impl<'a, T> ArenaView<'a, T> {
+    pub(crate) const fn new(items: &'a [T]) -> Self {
+        Self { items }
+    }
+
+    /// Returns a reference to the value at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is beyond the snapshot.
+    #[must_use]
+    pub fn get(&self, idx: Idx<T>) -> &'a T {
+        let i = idx.into_raw();
+        let len = self.items.len();
+        assert!(
+            i < len,
+            "index out of bounds: index is {i} but view length is {len}",
+        );
+        &self.items[i]
+    }
+
+    /// Returns a reference to the value at `idx`, or `None` if it is beyond
+    /// the snapshot.
+    #[must_use]
+    pub fn try_get(&self, idx: Idx<T>) -> Option<&'a T> {
+        self.items.get(idx.into_raw())
+    }
+
+    /// Returns `true` if `idx` lies within the snapshot.
+    #[must_use]
+    pub const fn is_valid(&self, idx: Idx<T>) -> bool {
+        idx.into_raw() < self.items.len()
+    }
+
+    /// Returns the number of items in the snapshot.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the snapshot contains no items.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the snapshot as a slice.
+    #[must_use]
+    pub const fn as_slice(&self) -> &'a [T] {
+        self.items
+    }
+
+    /// Returns an iterator over the snapshot.
+    pub fn iter(&self) -> std::slice::Iter<'a, T> {
+        self.items.iter()
+    }
+
+    /// Returns an iterator yielding `(Idx<T>, &T)` pairs over the snapshot.
+    #[must_use]
+    pub fn iter_indexed(&self) -> IterIndexed<'a, T> {
+        IterIndexed::new(self.items.iter().enumerate())
+    }
+}
+
+impl<T> Clone for ArenaView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ArenaView<'_, T> {}
+
+impl<T> std::ops::Index<Idx<T>> for ArenaView<'_, T> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        self.get(idx)
+    }
+}
+
+impl<T> AsRef<[T]> for ArenaView<'_, T> {
+    fn as_ref(&self) -> &[T] {
+        self.items
+    }
+}
+
+impl<'a, T> IntoIterator for ArenaView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &ArenaView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
@@ -0,0 +1,157 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Mutex, PoisonError};
+use std::task::{Context, Poll, Waker};
+
+use crate::sync::{AtomicUsize, Ordering};
+use crate::{FastArena, Idx};
+
+/// Future returned by [`FastArena::get_async`].
+///
+/// Resolves to the value at an index once it is published. While the slot
+/// is pending, the task's waker is registered with the arena and woken by
+/// the writer that publishes the slot, or that poisons the arena before it.
+#[must_use = "futures do nothing unless polled"]
+pub struct GetAsync<'a, T> {
+    arena: &'a FastArena<T>,
+    idx: Idx<T>,
+    /// Waker last registered with the arena, withdrawn on drop.
+    registered: Option<Waker>,
+}
+
+impl<'a, T> GetAsync<'a, T> {
+    pub(crate) const fn new(arena: &'a FastArena<T>, idx: Idx<T>) -> Self {
+        Self {
+            arena,
+            idx,
+            registered: None,
+        }
+    }
+
+    /// Returns the value if it is published; panics like `get` if it never
+    /// can be.
+    fn resolve(&self) -> Option<&'a T> {
+        let arena = self.arena;
+        if let Some(value) = arena.try_get(self.idx) {
+            return Some(value);
+        }
+        let unreachable = self.idx.into_raw() >= arena.capacity()
+            || arena.poisoned_at().is_some_and(|p| p <= self.idx);
+        // The slot can never be published; panics like `get`.
+        unreachable.then(|| arena.get(self.idx))
+    }
+}
+
+impl<'a, T> Future for GetAsync<'a, T> {
+    type Output = &'a T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<&'a T> {
+        let slot = self.idx.into_raw();
+        loop {
+            if let Some(value) = self.resolve() {
+                return Poll::Ready(value);
+            }
+            let pending = self.arena.register_waker(slot, cx.waker());
+            if let Some(old) = self.registered.replace(cx.waker().clone())
+                && !old.will_wake(cx.waker())
+            {
+                // The task moved to another waker; only the new one counts.
+                self.arena.deregister_waker(slot, &old);
+            }
+            if pending {
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+// Holds no pinned state; `Idx<T>` alone would tie this to `T: Unpin`.
+impl<T> Unpin for GetAsync<'_, T> {}
+
+impl<T> Drop for GetAsync<'_, T> {
+    fn drop(&mut self) {
+        if let Some(waker) = self.registered.take() {
+            self.arena.deregister_waker(self.idx.into_raw(), &waker);
+        }
+    }
+}
+
+/// Wakers of pending [`GetAsync`] futures, with the slot each waits for.
+pub struct Waiters {
+    /// Number of registered wakers, so publication skips the lock while
+    /// nobody waits.
+    len: AtomicUsize,
+    wakers: Mutex<Vec<(usize, Waker)>>,
+}
+
+impl Waiters {
+    #[allow(clippy::missing_const_for_fn)] // loom's atomics are not const
+    pub fn new() -> Self {
+        Self {
+            len: AtomicUsize::new(0),
+            wakers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Registers `waker` to be woken once `slot` is published.
+    ///
+    /// The caller must check the slot again afterwards with a `SeqCst`
+    /// load: either that load sees the slot published, or the publisher's
+    /// `SeqCst` load in [`wake`](Waiters::wake) sees the waker.
+    pub fn register(&self, slot: usize, waker: &Waker) {
+        let mut wakers = self.wakers.lock().unwrap_or_else(PoisonError::into_inner);
+        if !wakers
+            .iter()
+            .any(|(waiting, known)| *waiting == slot && known.will_wake(waker))
+        {
+            wakers.push((slot, waker.clone()));
+        }
+        self.len.store(wakers.len(), Ordering::SeqCst);
+    }
+
+    /// Withdraws `waker`'s registration for `slot`, if it is still there.
+    pub fn deregister(&self, slot: usize, waker: &Waker) {
+        if self.len.load(Ordering::SeqCst) == 0 {
+            return;
+        }
+        let mut wakers = self.wakers.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(i) = wakers
+            .iter()
+            .position(|(waiting, known)| *waiting == slot && known.will_wake(waker))
+        {
+            wakers.swap_remove(i);
+        }
+        self.len.store(wakers.len(), Ordering::SeqCst);
+    }
+
+    /// Wakes the wakers of slots below `published`. One load when nobody
+    /// waits.
+    pub fn wake(&self, published: usize) {
+        if self.len.load(Ordering::SeqCst) != 0 {
+            self.wake_slow(published);
+        }
+    }
+
+    #[cold]
+    fn wake_slow(&self, published: usize) {
+        let woken: Vec<Waker> = {
+            let mut wakers = self.wakers.lock().unwrap_or_else(PoisonError::into_inner);
+            let woken = wakers
+                .extract_if(.., |(slot, _)| *slot < published)
+                .map(|(_, waker)| waker)
+                .collect();
+            self.len.store(wakers.len(), Ordering::SeqCst);
+            woken
+        };
+        woken.into_iter().for_each(Waker::wake);
+    }
+
+    /// Forgets every waker; `&mut self` means no future is pending.
+    pub fn clear(&mut self) {
+        self.wakers
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        self.len.store(0, Ordering::Relaxed);
+    }
+}
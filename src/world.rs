@@ -0,0 +1,203 @@
+use crate::{Arena, Idx};
+
+/// One arena of a world declared with [`define_world!`](crate::define_world).
+///
+/// Implemented by the macro for every field, keyed by the element type, so
+/// generic code can reach the arena holding `T` and its id type.
+pub trait WorldArena<T> {
+    /// Typed id generated for this arena.
+    type Id: Copy + From<Idx<T>> + Into<Idx<T>>;
+
+    /// Returns the arena holding `T`.
+    fn arena(&self) -> &Arena<T>;
+
+    /// Returns the arena holding `T`, mutably.
+    fn arena_mut(&mut self) -> &mut Arena<T>;
+}
+
+/// Declares a struct of named [`Arena`]s, one typed id per arena, and a
+/// checkpoint covering all of them.
+///
+/// Each field is written `name: Type as Id`. The macro generates:
+///
+/// - `Id`, a `Copy` newtype around `Idx<Type>` with `From` conversions in
+///   both directions and `idx()`;
+/// - the world struct, with `new`, `Default`, a length-only `Debug`,
+///   `alloc(value)` returning the matching id, and `Index`/`IndexMut` by
+///   every id;
+/// - the checkpoint struct, and `checkpoint_all`, `rollback_all` and
+///   `reset_all` on the world.
+///
+/// Element types must be distinct, because `alloc` dispatches on them
+/// through [`WorldArena`].
+///
+/// ```
+/// fast_bump::define_world! {
+///     /// Arenas of a small expression language.
+///     pub struct Ir {
+///         pub exprs: Expr as ExprId,
+///         pub names: String as NameId,
+///     }
+///     /// Lengths of every `Ir` arena.
+///     pub struct IrCheckpoint;
+/// }
+///
+/// pub enum Expr {
+///     Var(NameId),
+///     Add(ExprId, ExprId),
+/// }
+///
+/// let mut ir = Ir::new();
+/// let x = ir.alloc(String::from("x"));
+/// let var = ir.alloc(Expr::Var(x));
+/// let cp = ir.checkpoint_all();
+/// ir.alloc(Expr::Add(var, var));
+/// ir.rollback_all(cp);
+///
+/// assert_eq!(ir.exprs.len(), 1);
+/// assert_eq!(ir[x], "x");
+/// ```
+#[macro_export]
+macro_rules! define_world {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $world:ident {
+            $($fvis:vis $field:ident : $ty:ty as $id:ident),+ $(,)?
+        }
+        $(#[$cp_meta:meta])*
+        $cp_vis:vis struct $cp:ident;
+    ) => {
+        $(
+            #[doc = concat!("Typed id of a `", stringify!($ty), "` in [`", stringify!($world), "`].")]
+            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+            $fvis struct $id($crate::Idx<$ty>);
+
+            #[allow(dead_code)]
+            impl $id {
+                /// Returns the underlying arena index.
+                #[must_use]
+                pub const fn idx(self) -> $crate::Idx<$ty> {
+                    self.0
+                }
+            }
+
+            impl ::core::convert::From<$crate::Idx<$ty>> for $id {
+                fn from(idx: $crate::Idx<$ty>) -> Self {
+                    Self(idx)
+                }
+            }
+
+            impl ::core::convert::From<$id> for $crate::Idx<$ty> {
+                fn from(id: $id) -> Self {
+                    id.0
+                }
+            }
+
+            impl $crate::WorldArena<$ty> for $world {
+                type Id = $id;
+
+                fn arena(&self) -> &$crate::Arena<$ty> {
+                    &self.$field
+                }
+
+                fn arena_mut(&mut self) -> &mut $crate::Arena<$ty> {
+                    &mut self.$field
+                }
+            }
+
+            impl ::core::ops::Index<$id> for $world {
+                type Output = $ty;
+
+                fn index(&self, id: $id) -> &$ty {
+                    &self.$field[id.0]
+                }
+            }
+
+            impl ::core::ops::IndexMut<$id> for $world {
+                fn index_mut(&mut self, id: $id) -> &mut $ty {
+                    &mut self.$field[id.0]
+                }
+            }
+        )+
+
+        $(#[$meta])*
+        $vis struct $world {
+            $(
+                #[doc = concat!("Arena of `", stringify!($ty), "` values.")]
+                $fvis $field: $crate::Arena<$ty>,
+            )+
+        }
+
+        $(#[$cp_meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        $cp_vis struct $cp {
+            $($field: $crate::Checkpoint<$ty>,)+
+        }
+
+        #[allow(dead_code)]
+        impl $world {
+            /// Creates a world with every arena empty.
+            #[must_use]
+            pub const fn new() -> Self {
+                Self {
+                    $($field: $crate::Arena::new(),)+
+                }
+            }
+
+            /// Allocates `value` in the arena for its type, returning its
+            /// typed id.
+            pub fn alloc<T>(&mut self, value: T) -> <Self as $crate::WorldArena<T>>::Id
+            where
+                Self: $crate::WorldArena<T>,
+            {
+                $crate::WorldArena::<T>::arena_mut(self).alloc(value).into()
+            }
+
+            /// Saves the length of every arena.
+            #[must_use]
+            pub const fn checkpoint_all(&self) -> $cp {
+                $cp {
+                    $($field: self.$field.checkpoint(),)+
+                }
+            }
+
+            /// Rolls every arena back to `cp`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any arena is shorter than recorded in `cp`; no
+            /// arena is rolled back then.
+            pub fn rollback_all(&mut self, cp: $cp) {
+                $(
+                    if cp.$field.len() > self.$field.len() {
+                        let err = $crate::Error::CheckpointBeyondLen {
+                            checkpoint: cp.$field.len(),
+                            len: self.$field.len(),
+                        };
+                        panic!("{err}");
+                    }
+                )+
+                $(self.$field.rollback(cp.$field);)+
+            }
+
+            /// Removes every value from every arena.
+            pub fn reset_all(&mut self) {
+                $(self.$field.reset();)+
+            }
+        }
+
+        impl ::core::default::Default for $world {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl ::core::fmt::Debug for $world {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!($world))
+                    $(.field(stringify!($field), &self.$field.len()))+
+                    .finish()
+            }
+        }
+    };
+}